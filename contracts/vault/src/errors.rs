@@ -6,6 +6,8 @@ use soroban_sdk::contracterror;
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(u32)]
 pub enum VaultError {
+    /// Vault is already initialized, or a policy import or direct pool
+    /// withdrawal opt-in targets a vault that already has proposals
     AlreadyInitialized = 1,
     NotInitialized = 2,
     NoSigners = 3,
@@ -13,22 +15,33 @@ pub enum VaultError {
     ThresholdTooHigh = 5,
     QuorumTooHigh = 6,
     QuorumNotReached = 7,
+    /// Also returned when a committed transfer is executed without a matching
+    /// reveal
     Unauthorized = 10,
     NotASigner = 11,
     InsufficientRole = 12,
     VoterNotInSnapshot = 13,
+    /// Also returned for unknown stream, escrow and subscription IDs, and when
+    /// proposing to an address book label that does not exist
     ProposalNotFound = 20,
     ProposalNotPending = 21,
     ProposalNotApproved = 22,
     ProposalAlreadyExecuted = 23,
     ProposalExpired = 24,
+    /// Also returned when a stream or subscription is no longer active
     ProposalAlreadyCancelled = 25,
     VotingDeadlinePassed = 26,
+    /// Also returned for a duplicate tag, whether already on the proposal or
+    /// repeated in `ProposalOptions::tags`
     AlreadyApproved = 30,
+    /// Also returned when a proposal's token address is not a token contract
     InvalidAmount = 40,
+    /// Also returned when an author has used up their comment quota on a proposal
     ExceedsProposalLimit = 41,
     ExceedsDailyLimit = 42,
+    /// Weekly limit, or a spend category's monthly budget, would be exceeded
     ExceedsWeeklyLimit = 43,
+    /// Also returned when an author comments again before the minimum gap
     VelocityLimitExceeded = 50,
     TimelockNotExpired = 60,
     SchedulingError = 61,
@@ -36,18 +49,24 @@ pub enum VaultError {
     TransferFailed = 71,
     SignerAlreadyExists = 80,
     SignerNotFound = 81,
-    CannotRemoveSigner = 82,
     RecipientNotWhitelisted = 90,
     RecipientBlacklisted = 91,
+    /// Address, memo or address book label is already listed, or a proposal is
+    /// already in a bundle
     AddressAlreadyOnList = 92,
+    /// Address, memo or address book label is not listed, or an address has
+    /// no live signer invite
     AddressNotOnList = 93,
     InsuranceInsufficient = 110,
     GasLimitExceeded = 120,
+    /// Too many items in one call, or a dependency graph too large or too deep
     BatchTooLarge = 130,
     ConditionsNotMet = 140,
     IntervalTooShort = 150,
     DexError = 160,
     RetryError = 168,
+    /// A stream claim or refund would release more than its remaining escrow
+    StreamAccountingError = 170,
     TemplateNotFound = 210,
     TemplateInactive = 211,
     TemplateValidationFailed = 212,
@@ -58,7 +77,7 @@ pub enum VaultError {
     TooManyAttachments = 231,
     /// Proposal has reached the maximum number of tags
     TooManyTags = 232,
-    /// Metadata value is empty or exceeds the maximum allowed length
+    /// Metadata value is empty or too long, or a memo is not on the allowed list
     MetadataValueInvalid = 233,
 }
 
@@ -2,8 +2,10 @@
 //!
 //! Standardized events for proposal lifecycle and admin actions.
 
-use crate::types::ProposalAmendment;
-use soroban_sdk::{Address, Env, Symbol};
+use crate::types::{
+    Incident, ListMode, Proposal, ProposalAmendment, SlashDestination, VaultMetrics,
+};
+use soroban_sdk::{Address, BytesN, Env, String, Symbol, Vec};
 
 /// Emit when contract is initialized
 pub fn emit_initialized(env: &Env, admin: &Address, threshold: u32) {
@@ -13,24 +15,27 @@ pub fn emit_initialized(env: &Env, admin: &Address, threshold: u32) {
     );
 }
 
-/// Emit when a new proposal is created (enhanced: includes token and insurance)
-pub fn emit_proposal_created(
-    env: &Env,
-    proposal_id: u64,
-    proposer: &Address,
-    recipient: &Address,
-    token: &Address,
-    amount: i128,
-    insurance_amount: i128,
-) {
+/// Emit when a new proposal is created.
+///
+/// Payload: proposer, recipient, token, amount, insurance, priority, tags,
+/// voting deadline, expiry ledger, dependency count, condition count and
+/// whether it is a swap. New fields are only ever appended.
+pub fn emit_proposal_created(env: &Env, proposal: &Proposal) {
     env.events().publish(
-        (Symbol::new(env, "proposal_created"), proposal_id),
+        (Symbol::new(env, "proposal_created"), proposal.id),
         (
-            proposer.clone(),
-            recipient.clone(),
-            token.clone(),
-            amount,
-            insurance_amount,
+            proposal.proposer.clone(),
+            proposal.recipient.clone(),
+            proposal.token.clone(),
+            proposal.amount,
+            proposal.insurance_amount,
+            proposal.priority.clone() as u32,
+            proposal.tags.clone(),
+            proposal.voting_deadline,
+            proposal.expires_at,
+            proposal.depends_on.len(),
+            proposal.conditions.len(),
+            proposal.is_swap,
         ),
     );
 }
@@ -49,6 +54,36 @@ pub fn emit_proposal_approved(
     );
 }
 
+/// Emit when proposals are grouped into a bundle
+pub fn emit_bundle_created(env: &Env, bundle_id: u64, proposer: &Address, proposal_ids: &Vec<u64>) {
+    env.events().publish(
+        (Symbol::new(env, "bundle_created"), bundle_id),
+        (proposer.clone(), proposal_ids.clone()),
+    );
+}
+
+/// Emit when a signer approves a bundle, with the members approved and skipped
+pub fn emit_bundle_approved(
+    env: &Env,
+    bundle_id: u64,
+    signer: &Address,
+    approved: &Vec<u64>,
+    skipped: &Vec<u64>,
+) {
+    env.events().publish(
+        (Symbol::new(env, "bundle_approved"), bundle_id),
+        (signer.clone(), approved.clone(), skipped.clone()),
+    );
+}
+
+/// Emit the justification attached to an approval
+pub fn emit_approval_note(env: &Env, proposal_id: u64, approver: &Address, note: &Symbol) {
+    env.events().publish(
+        (Symbol::new(env, "approval_note"), proposal_id),
+        (approver.clone(), note.clone()),
+    );
+}
+
 /// Emit when a signer explicitly abstains from a proposal.
 ///
 /// # Arguments
@@ -69,15 +104,57 @@ pub fn emit_proposal_abstained(
     );
 }
 
+/// Emit when a signer withdraws an earlier abstention
+pub fn emit_abstention_withdrawn(env: &Env, proposal_id: u64, signer: &Address, quorum_votes: u32) {
+    env.events().publish(
+        (Symbol::new(env, "abstention_withdrawn"), proposal_id),
+        (signer.clone(), quorum_votes),
+    );
+}
+
 /// Emit when a proposal reaches threshold and is ready for execution
-pub fn emit_proposal_ready(env: &Env, proposal_id: u64, unlock_ledger: u64) {
+///
+/// Carries the reputation-adjusted limits frozen at approval and the last
+/// ledger the proposal may execute at (0 = until expiry).
+pub fn emit_proposal_ready(
+    env: &Env,
+    proposal_id: u64,
+    unlock_ledger: u64,
+    rep_boosted: bool,
+    limits: (i128, i128, i128),
+    execute_by: u64,
+) {
     env.events().publish(
         (Symbol::new(env, "proposal_ready"), proposal_id),
-        unlock_ledger,
+        (
+            unlock_ledger,
+            rep_boosted,
+            limits.0,
+            limits.1,
+            limits.2,
+            execute_by,
+        ),
+    );
+}
+
+/// Emit when a Critical proposal's timelock is cleared by its approvers
+pub fn emit_timelock_bypassed(env: &Env, proposal_id: u64, approvers: &Vec<Address>) {
+    env.events().publish(
+        (Symbol::new(env, "timelock_bypassed"), proposal_id),
+        approvers.clone(),
+    );
+}
+
+/// Emit when a signer gives a large proposal its post-timelock second look
+pub fn emit_second_look_confirmed(env: &Env, proposal_id: u64, signer: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "second_look_confirmed"), proposal_id),
+        signer.clone(),
     );
 }
 
-/// Emit when a proposal is executed (enhanced: includes token and ledger)
+/// Emit when a proposal is executed (enhanced: includes token, ledger, executor tip
+/// and the token's lifetime outflow)
 pub fn emit_proposal_executed(
     env: &Env,
     proposal_id: u64,
@@ -86,6 +163,8 @@ pub fn emit_proposal_executed(
     token: &Address,
     amount: i128,
     ledger: u64,
+    executor_tip: i128,
+    total_outflow: i128,
 ) {
     env.events().publish(
         (Symbol::new(env, "proposal_executed"), proposal_id),
@@ -95,10 +174,89 @@ pub fn emit_proposal_executed(
             token.clone(),
             amount,
             ledger,
+            executor_tip,
+            total_outflow,
         ),
     );
 }
 
+/// Emit when a contribution is pulled into the vault
+pub fn emit_contribution_pulled(
+    env: &Env,
+    from: &Address,
+    caller: &Address,
+    token: &Address,
+    amount: i128,
+    total: i128,
+) {
+    env.events().publish(
+        (Symbol::new(env, "contribution_pulled"), from.clone()),
+        (caller.clone(), token.clone(), amount, total),
+    );
+}
+
+/// Emit when a keeper executes a recurring payment
+pub fn emit_recurring_executed(
+    env: &Env,
+    payment_id: u64,
+    executor: &Address,
+    amount: i128,
+    payment_count: u32,
+    next_payment_ledger: u64,
+    total_outflow: i128,
+) {
+    env.events().publish(
+        (Symbol::new(env, "recurring_executed"), payment_id),
+        (
+            executor.clone(),
+            amount,
+            payment_count,
+            next_payment_ledger,
+            total_outflow,
+        ),
+    );
+}
+
+/// Emit when a scheduled payment is stopped and recorded as an incident
+pub fn emit_incident_flagged(env: &Env, incident: &Incident) {
+    env.events().publish(
+        (Symbol::new(env, "incident_flagged"), incident.id),
+        (
+            incident.kind.clone(),
+            incident.target_id,
+            incident.flagged_by.clone(),
+            incident.amount_paid,
+            incident.note.clone(),
+        ),
+    );
+}
+
+/// Emit when a payment incident is closed
+pub fn emit_incident_resolved(
+    env: &Env,
+    incident_id: u64,
+    resolver: &Address,
+    resolution: &Symbol,
+) {
+    env.events().publish(
+        (Symbol::new(env, "incident_resolved"), incident_id),
+        (resolver.clone(), resolution.clone()),
+    );
+}
+
+/// Emit when a recurring payment period is skipped without paying
+pub fn emit_recurring_skipped(
+    env: &Env,
+    payment_id: u64,
+    reason: Symbol,
+    next_payment_ledger: u64,
+) {
+    env.events().publish(
+        (Symbol::new(env, "recurring_skipped"), payment_id),
+        (reason, next_payment_ledger),
+    );
+}
+
 pub fn emit_proposal_expired(env: &Env, proposal_id: u64, expires_at: u64) {
     env.events().publish(
         (Symbol::new(env, "proposal_expired"), proposal_id),
@@ -106,6 +264,13 @@ pub fn emit_proposal_expired(env: &Env, proposal_id: u64, expires_at: u64) {
     );
 }
 
+pub fn emit_proposal_expiring(env: &Env, proposal_id: u64, expires_at: u64, ledgers_left: u64) {
+    env.events().publish(
+        (Symbol::new(env, "proposal_expiring"), proposal_id),
+        (expires_at, ledgers_left),
+    );
+}
+
 pub fn emit_proposal_deadline_rejected(env: &Env, proposal_id: u64, voting_deadline: u64) {
     env.events().publish(
         (Symbol::new(env, "proposal_deadline_rejected"), proposal_id),
@@ -145,6 +310,14 @@ pub fn emit_proposal_rejected(env: &Env, proposal_id: u64, rejector: &Address, p
     );
 }
 
+/// Emit when an Admin invalidates a proposal whose vote snapshot is unsafe
+pub fn emit_proposal_invalidated(env: &Env, proposal_id: u64, admin: &Address, reason: &Symbol) {
+    env.events().publish(
+        (Symbol::new(env, "proposal_invalidated"), proposal_id),
+        (admin.clone(), reason.clone()),
+    );
+}
+
 /// Emit when a proposal is cancelled with a refund
 pub fn emit_proposal_cancelled(
     env: &Env,
@@ -205,6 +378,35 @@ pub fn emit_config_updated(env: &Env, updater: &Address) {
         .publish((Symbol::new(env, "config_updated"),), updater.clone());
 }
 
+/// Emit when a vault metadata key is set (`Some`) or removed (`None`)
+pub fn emit_vault_metadata_updated(
+    env: &Env,
+    admin: &Address,
+    key: &Symbol,
+    value: &Option<String>,
+) {
+    env.events().publish(
+        (Symbol::new(env, "vault_metadata_updated"), key.clone()),
+        (admin.clone(), value.clone()),
+    );
+}
+
+/// Emit when the threshold strategy is replaced.
+///
+/// Carries the number of amount tiers (0 for non-tiered strategies) and a
+/// SHA-256 of the strategy's XDR so indexers can detect tier changes.
+pub fn emit_threshold_strategy_updated(
+    env: &Env,
+    admin: &Address,
+    tier_count: u32,
+    strategy_hash: &BytesN<32>,
+) {
+    env.events().publish(
+        (Symbol::new(env, "threshold_strategy_updated"),),
+        (admin.clone(), tier_count, strategy_hash.clone()),
+    );
+}
+
 // ============================================================================
 // Oracle Events (feature/oracle-integration)
 // ============================================================================
@@ -249,6 +451,30 @@ pub fn emit_signer_removed(env: &Env, signer: &Address, total_signers: u32) {
     );
 }
 
+/// Emit when an admin invites an address to become a signer
+pub fn emit_signer_invited(env: &Env, admin: &Address, invitee: &Address, expires_at: u64) {
+    env.events().publish(
+        (Symbol::new(env, "signer_invited"), invitee.clone()),
+        (admin.clone(), expires_at),
+    );
+}
+
+/// Emit when a signer approves a pending invite
+pub fn emit_signer_invite_approved(env: &Env, signer: &Address, invitee: &Address, approvals: u32) {
+    env.events().publish(
+        (Symbol::new(env, "signer_invite_approved"), invitee.clone()),
+        (signer.clone(), approvals),
+    );
+}
+
+/// Emit when a pending signer invite is withdrawn
+pub fn emit_signer_invite_revoked(env: &Env, admin: &Address, invitee: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "signer_invite_revoked"), invitee.clone()),
+        admin.clone(),
+    );
+}
+
 // ============================================================================
 // Insurance Events (feature/proposal-insurance)
 // ============================================================================
@@ -274,10 +500,16 @@ pub fn emit_insurance_slashed(
     proposer: &Address,
     slashed_amount: i128,
     returned_amount: i128,
+    destination: &SlashDestination,
 ) {
     env.events().publish(
         (Symbol::new(env, "insurance_slashed"), proposal_id),
-        (proposer.clone(), slashed_amount, returned_amount),
+        (
+            proposer.clone(),
+            slashed_amount,
+            returned_amount,
+            destination.clone(),
+        ),
     );
 }
 
@@ -346,6 +578,20 @@ pub fn emit_reputation_updated(
     );
 }
 
+/// Emit when a reputation record moves to a rotated address
+pub fn emit_reputation_migrated(
+    env: &Env,
+    old_addr: &Address,
+    new_addr: &Address,
+    new_score: u32,
+    merged: bool,
+) {
+    env.events().publish(
+        (Symbol::new(env, "reputation_migrated"),),
+        (old_addr.clone(), new_addr.clone(), new_score, merged),
+    );
+}
+
 // ============================================================================
 // Batch Execution Events (feature/batch-optimization)
 // ============================================================================
@@ -368,12 +614,37 @@ pub fn emit_notification_prefs_updated(env: &Env, addr: &Address) {
         .publish((Symbol::new(env, "notif_prefs_updated"),), addr.clone());
 }
 
+/// Emit a per-user notification. The topic carries the user's routing
+/// address (the user itself unless they set `route_to`).
+pub fn emit_notification(env: &Env, route: &Address, addr: &Address, kind: Symbol) {
+    env.events().publish(
+        (Symbol::new(env, "notification"), route.clone()),
+        (addr.clone(), kind),
+    );
+}
+
 /// Emit when insurance config is updated by admin
 pub fn emit_insurance_config_updated(env: &Env, admin: &Address) {
     env.events()
         .publish((Symbol::new(env, "insurance_cfg_updated"),), admin.clone());
 }
 
+/// Emit when a temporary whitelist/blacklist entry is added
+pub fn emit_list_entry_added(env: &Env, mode: &ListMode, addr: &Address, expires_at: u64) {
+    env.events().publish(
+        (Symbol::new(env, "list_entry_added"), addr.clone()),
+        (mode.clone(), expires_at),
+    );
+}
+
+/// Emit when an expired list entry is pruned
+pub fn emit_list_entry_pruned(env: &Env, mode: &ListMode, addr: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "list_entry_pruned"), addr.clone()),
+        mode.clone(),
+    );
+}
+
 /// Emit when a comment is added
 pub fn emit_comment_added(env: &Env, comment_id: u64, proposal_id: u64, author: &Address) {
     env.events().publish(
@@ -382,6 +653,21 @@ pub fn emit_comment_added(env: &Env, comment_id: u64, proposal_id: u64, author:
     );
 }
 
+/// Emit when a comment @-mentions an address
+pub fn emit_mentioned(
+    env: &Env,
+    route: &Address,
+    mentioned: &Address,
+    comment_id: u64,
+    proposal_id: u64,
+    author: &Address,
+) {
+    env.events().publish(
+        (Symbol::new(env, "mentioned"), route.clone()),
+        (comment_id, proposal_id, author.clone(), mentioned.clone()),
+    );
+}
+
 /// Emit when a comment is edited
 pub fn emit_comment_edited(env: &Env, comment_id: u64, author: &Address) {
     env.events().publish(
@@ -390,6 +676,28 @@ pub fn emit_comment_edited(env: &Env, comment_id: u64, author: &Address) {
     );
 }
 
+/// Emit when a comment is deleted (text tombstoned)
+pub fn emit_comment_deleted(env: &Env, comment_id: u64, deleted_by: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "comment_deleted"), comment_id),
+        deleted_by.clone(),
+    );
+}
+
+/// Emit when an address reacts to a comment
+pub fn emit_comment_reaction(
+    env: &Env,
+    comment_id: u64,
+    addr: &Address,
+    reaction: &Symbol,
+    count: u32,
+) {
+    env.events().publish(
+        (Symbol::new(env, "comment_reaction"), comment_id),
+        (addr.clone(), reaction.clone(), count),
+    );
+}
+
 /// Emit when a hook is registered
 pub fn emit_hook_registered(env: &Env, hook: &Address, is_pre: bool) {
     env.events().publish(
@@ -412,6 +720,28 @@ pub fn emit_hook_executed(env: &Env, hook: &Address, proposal_id: u64, is_pre: b
     );
 }
 
+/// Emit when a token swap is executed
+pub fn emit_swap_executed(
+    env: &Env,
+    proposal_id: u64,
+    dex: &Address,
+    amount_in: i128,
+    amount_out: i128,
+) {
+    env.events().publish(
+        (Symbol::new(env, "swap_executed"), proposal_id),
+        (dex.clone(), amount_in, amount_out),
+    );
+}
+
+/// Emit when liquidity is added
+pub fn emit_liquidity_added(env: &Env, proposal_id: u64, dex: &Address, lp_tokens: i128) {
+    env.events().publish(
+        (Symbol::new(env, "liquidity_added"), proposal_id),
+        (dex.clone(), lp_tokens),
+    );
+}
+
 /// Emit when liquidity is removed
 pub fn emit_liquidity_removed(env: &Env, proposal_id: u64, dex: &Address, lp_tokens: i128) {
     env.events().publish(
@@ -428,6 +758,14 @@ pub fn emit_lp_staked(env: &Env, proposal_id: u64, farm: &Address, amount: i128)
     );
 }
 
+/// Emit when LP tokens are unstaked
+pub fn emit_lp_unstaked(env: &Env, proposal_id: u64, farm: &Address, amount: i128) {
+    env.events().publish(
+        (Symbol::new(env, "lp_unstaked"), proposal_id),
+        (farm.clone(), amount),
+    );
+}
+
 /// Emit when rewards are claimed
 pub fn emit_rewards_claimed(env: &Env, proposal_id: u64, farm: &Address, amount: i128) {
     env.events().publish(
@@ -461,10 +799,11 @@ pub fn emit_execution_fee_estimated(
     base_fee: u64,
     resource_fee: u64,
     total_fee: u64,
+    protocol_fee: i128,
 ) {
     env.events().publish(
         (Symbol::new(env, "exec_fee_estimated"), proposal_id),
-        (base_fee, resource_fee, total_fee),
+        (base_fee, resource_fee, total_fee, protocol_fee),
     );
 }
 
@@ -479,16 +818,46 @@ pub fn emit_execution_fee_used(env: &Env, proposal_id: u64, total_fee: u64) {
 // ============================================================================
 
 /// Emit when vault-wide metrics are updated
-pub fn emit_metrics_updated(
+pub fn emit_metrics_updated(env: &Env, metrics: &VaultMetrics) {
+    env.events().publish(
+        (Symbol::new(env, "metrics_updated"),),
+        (
+            metrics.executed_count,
+            metrics.rejected_count,
+            metrics.expired_count,
+            metrics.success_rate_bps(),
+            metrics.cancelled_count,
+            metrics.retry_scheduled_count,
+            metrics.retry_succeeded_count,
+            metrics.disputes_filed,
+            metrics.disputes_upheld,
+            metrics.avg_approval_time(),
+            metrics.avg_execution_delay(),
+        ),
+    );
+}
+
+/// Emit a periodic treasury checkpoint for off-chain accounting
+///
+/// `outflow` lists each tracked token with the amount paid out since the
+/// previous checkpoint.
+pub fn emit_treasury_checkpoint(
     env: &Env,
-    executed: u64,
-    rejected: u64,
-    expired: u64,
-    success_rate_bps: u32,
+    period: u64,
+    metrics: &VaultMetrics,
+    outflow: &Vec<(Address, i128)>,
+    pending: u64,
+    approved: u64,
 ) {
     env.events().publish(
-        (Symbol::new(env, "metrics_updated"),),
-        (executed, rejected, expired, success_rate_bps),
+        (Symbol::new(env, "treasury_checkpoint"), period),
+        (
+            metrics.executed_count,
+            outflow.clone(),
+            metrics.clone(),
+            pending,
+            approved,
+        ),
     );
 }
 
@@ -606,41 +975,75 @@ pub fn emit_retries_exhausted(env: &Env, proposal_id: u64, total_attempts: u32)
     );
 }
 
+/// Emit when a scheduled retry is abandoned because the proposal's approvals
+/// no longer satisfy the current threshold or quorum
+pub fn emit_requirements_invalidated(
+    env: &Env,
+    proposal_id: u64,
+    retry_count: u32,
+    error_code: u32,
+) {
+    env.events().publish(
+        (Symbol::new(env, "requirements_invalidated"), proposal_id),
+        (retry_count, error_code),
+    );
+}
+
+/// Emit when repeated execution failures freeze the vault.
+///
+/// Carries the failure count in the window and the error codes of the
+/// failures that tripped the breaker, oldest first.
+pub fn emit_auto_freeze_triggered(env: &Env, failure_count: u32, reasons: &Vec<u32>) {
+    env.events().publish(
+        (Symbol::new(env, "auto_freeze_triggered"),),
+        (failure_count, reasons.clone()),
+    );
+}
+
+/// Emit when an admin lifts a freeze, with their acknowledgment reason
+pub fn emit_vault_unpaused(env: &Env, admin: &Address, reason: &Symbol) {
+    env.events().publish(
+        (Symbol::new(env, "vault_unpaused"), admin.clone()),
+        reason.clone(),
+    );
+}
+
 // ============================================================================
 // Subscription Events (feature/subscription-system)
 // ============================================================================
 
-/// Emit when a new subscription is created
-#[allow(dead_code)]
+/// Emit when a new subscription is created (with its `SubscriptionFundingSource`)
 pub fn emit_subscription_created(
     env: &Env,
     subscription_id: u64,
     subscriber: &Address,
     tier: u32,
     amount: i128,
+    funding_source: u32,
 ) {
     env.events().publish(
         (Symbol::new(env, "subscription_created"), subscription_id),
-        (subscriber.clone(), tier, amount),
+        (subscriber.clone(), tier, amount, funding_source),
     );
 }
 
-/// Emit when a subscription is renewed
-#[allow(dead_code)]
+/// Emit when a subscription is renewed (includes the provider's running revenue
+/// and the funding source)
 pub fn emit_subscription_renewed(
     env: &Env,
     subscription_id: u64,
     payment_number: u32,
     amount: i128,
+    provider_revenue: i128,
+    funding_source: u32,
 ) {
     env.events().publish(
         (Symbol::new(env, "subscription_renewed"), subscription_id),
-        (payment_number, amount),
+        (payment_number, amount, provider_revenue, funding_source),
     );
 }
 
 /// Emit when a subscription is cancelled
-#[allow(dead_code)]
 pub fn emit_subscription_cancelled(env: &Env, subscription_id: u64, cancelled_by: &Address) {
     env.events().publish(
         (Symbol::new(env, "subscription_cancelled"), subscription_id),
@@ -697,10 +1100,29 @@ pub fn emit_escrow_created(
 }
 
 /// Emit when a milestone is completed
-pub fn emit_milestone_completed(env: &Env, escrow_id: u64, milestone_id: u64, completer: &Address) {
+pub fn emit_milestone_completed(
+    env: &Env,
+    escrow_id: u64,
+    milestone_id: u64,
+    completer: &Address,
+    evidence_count: u32,
+) {
     env.events().publish(
         (Symbol::new(env, "milestone_complete"), escrow_id),
-        (milestone_id, completer.clone()),
+        (milestone_id, completer.clone(), evidence_count),
+    );
+}
+
+/// Emit when the recipient attaches deliverable evidence to a milestone
+pub fn emit_milestone_evidence_attached(
+    env: &Env,
+    escrow_id: u64,
+    milestone_id: u64,
+    evidence: &String,
+) {
+    env.events().publish(
+        (Symbol::new(env, "milestone_evidence"), escrow_id),
+        (milestone_id, evidence.clone()),
     );
 }
 
@@ -718,6 +1140,14 @@ pub fn emit_escrow_released(
     );
 }
 
+/// Emit after a keeper pass over due escrows
+pub fn emit_escrows_processed(env: &Env, released_count: u32, checked_at: u64) {
+    env.events().publish(
+        (Symbol::new(env, "escrows_processed"),),
+        (released_count, checked_at),
+    );
+}
+
 /// Emit when an escrow is disputed
 pub fn emit_escrow_disputed(env: &Env, escrow_id: u64, disputer: &Address, reason: &Symbol) {
     env.events().publish(
@@ -980,7 +1410,6 @@ pub fn emit_stream_created(
 }
 
 /// Emit when a stream status is updated (paused, resumed, or cancelled)
-#[allow(dead_code)]
 pub fn emit_stream_status_updated(env: &Env, stream_id: u64, status: u32, updated_by: &Address) {
     env.events().publish(
         (Symbol::new(env, "stream_status"), stream_id),
@@ -989,7 +1418,6 @@ pub fn emit_stream_status_updated(env: &Env, stream_id: u64, status: u32, update
 }
 
 /// Emit when tokens are claimed from a stream
-#[allow(dead_code)]
 pub fn emit_stream_claimed(env: &Env, stream_id: u64, recipient: &Address, amount: i128) {
     env.events().publish(
         (Symbol::new(env, "stream_claimed"), stream_id),
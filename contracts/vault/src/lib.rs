@@ -16,23 +16,34 @@
 mod bridge;
 mod errors;
 mod events;
+mod math;
 mod storage;
 mod token;
 mod types;
 
 use errors::VaultError;
-use soroban_sdk::{contract, contractimpl, Address, Env, IntoVal, Map, String, Symbol, Vec};
+use soroban_sdk::{
+    contract, contractimpl, xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal, Map, String, Symbol,
+    Vec,
+};
 use types::{
-    AuditAction, AuditEntry, BatchExecutionResult, BatchOperation, BatchStatus, BatchTransaction,
-    CancellationRecord, Comment, Condition, ConditionLogic, Config, DexConfig, Escrow,
-    EscrowStatus, ExecutionFeeEstimate, FundingMilestone, FundingMilestoneStatus, FundingRound,
-    FundingRoundConfig, FundingRoundStatus, GasConfig, InitConfig, InsuranceConfig, ListMode,
-    Milestone, NotificationPreferences, OptionalVaultOracleConfig, Priority, Proposal,
-    ProposalAmendment, ProposalStatus, ProposalTemplate, RecoveryConfig, RecoveryProposal,
-    RecoveryStatus, RecurringPayment, Reputation, RetryConfig, RetryState, Role, RoleAssignment,
-    StreamStatus, StreamingPayment, Subscription, SubscriptionPayment, SubscriptionStatus,
-    SubscriptionTier, SwapProposal, SwapResult, TemplateOverrides, ThresholdStrategy,
-    TransferDetails, VaultMetrics, VaultOracleConfig, VaultPriceData, VotingStrategy,
+    AddressBookEntry, ApprovalLimits, AuditAction, AuditEntry, AutoFreezeConfig, AutoTagRule,
+    BatchExecutionResult, BatchOperation, BatchStatus, BatchTransaction, BundleApprovalMode,
+    CancellationRecord, Comment, CommentQuota, Condition, ConditionLogic, Config, DailyLedger,
+    DexConfig, Escrow, EscrowStatus, ExecutionFeeEstimate, ExecutionProof, FlowSummary,
+    FundingMilestone, FundingMilestoneStatus, FundingRound, FundingRoundConfig, FundingRoundStatus,
+    GasConfig, HealthBucket, Incident, IncidentKind, InitConfig, InsuranceConfig,
+    InsuranceOracleFallback, InsuranceUsdThreshold, InvariantKind, InvariantViolation, ListMode,
+    Milestone, MilestoneMode, NotificationDigest, NotificationPreferences,
+    OptionalVaultOracleConfig, PolicyBundle, PoolKind, PoolReport, PrecheckResult, Priority,
+    Proposal, ProposalAmendment, ProposalBundle, ProposalOptions, ProposalStatus, ProposalTemplate,
+    ProposalTimestamps, RecordKind, RecoveryConfig, RecoveryProposal, RecoveryStatus,
+    RecurringPayment, Reputation, RetryConfig, RetryState, RiskAssessment, Role, RoleAssignment,
+    SignerChangePreview, SignerInvite, SignerKind, SignerVote, SlashDestination, StatusTransition,
+    StreamStatus, StreamingPayment, Subscription, SubscriptionFundingSource, SubscriptionPayment,
+    SubscriptionStatus, SubscriptionTier, SwapProposal, SwapResult, TemplateBatchEntry,
+    TemplateOverrides, ThresholdStrategy, TimelockBypassConfig, TransferDetails, VaultHealth,
+    VaultMetrics, VaultOracleConfig, VaultPriceData, VelocityAction, VotingStrategy,
 };
 
 /// The main contract structure for VaultDAO.
@@ -52,6 +63,27 @@ const LEDGER_INTERVAL_SECONDS: u64 = 5;
 /// Maximum proposals that can be batch-executed in one call (gas limit)
 const MAX_BATCH_SIZE: u32 = 10;
 
+/// Maximum recurring payments a keeper can execute in one call
+const MAX_DUE_PAYMENTS_PER_CALL: u32 = 20;
+
+/// Maximum subscriptions a keeper can renew in one call
+const MAX_DUE_SUBSCRIPTIONS_PER_CALL: u32 = 20;
+
+/// Maximum expiry warnings a keeper can emit in one call
+const MAX_EXPIRY_WARNINGS_PER_CALL: u32 = 20;
+
+/// Maximum recipients in a single split-transfer proposal
+const MAX_SPLIT_RECIPIENTS: u32 = 20;
+
+/// Maximum keepers on a proposal's executor allow-list
+const MAX_ALLOWED_EXECUTORS: u32 = 10;
+
+/// Sample IDs reported per category by `get_vault_health`
+const MAX_HEALTH_SAMPLES: u32 = 10;
+
+/// Entries `get_vault_health` inspects per index before giving up
+const MAX_HEALTH_SCAN: u32 = 100;
+
 /// Maximum metadata entries stored per proposal
 const MAX_METADATA_ENTRIES: u32 = 16;
 
@@ -61,21 +93,121 @@ const MAX_METADATA_VALUE_LEN: u32 = 256;
 /// Maximum number of tags per proposal
 const MAX_TAGS: u32 = 10;
 
+/// Maximum nesting depth of a comment thread (top-level comment = depth 1)
+const MAX_COMMENT_DEPTH: u32 = 3;
+
+/// Maximum number of comments returned in a single page
+const MAX_COMMENT_PAGE_SIZE: u32 = 50;
+
+/// Maximum approved proposals examined by a single executable-proposals query
+const MAX_EXECUTABLE_PAGE_SIZE: u32 = 50;
+
+/// Maximum escrows returned by a single detailed escrow query
+const MAX_ESCROW_PAGE_SIZE: u32 = 50;
+
+/// Maximum escrows released by a single keeper call
+const MAX_DUE_ESCROWS_PER_CALL: u32 = 20;
+
+/// Maximum proposals rejected by a single `enforce_deadlines` call
+const MAX_DEADLINE_REJECTIONS_PER_CALL: u32 = 20;
+
+/// Maximum entries in the allowed memo list
+const MAX_ALLOWED_MEMOS: u32 = 50;
+
+/// Maximum unresolved payment incidents
+const MAX_OPEN_INCIDENTS: u32 = 50;
+
+/// Maximum labeled entries in the address book
+const MAX_ADDRESS_BOOK_ENTRIES: u32 = 50;
+
+/// Maximum proposals grouped into one bundle
+const MAX_BUNDLE_SIZE: u32 = 10;
+
+/// Maximum direct dependencies a proposal may declare
+const MAX_DEPENDENCIES: u32 = 5;
+
+/// Maximum length of a dependency chain below a proposal
+const MAX_DEPENDENCY_DEPTH: u32 = 8;
+
+/// Maximum size of a proposal's flattened (transitive) dependency set
+const MAX_TRANSITIVE_DEPENDENCIES: u32 = 32;
+
+/// Maximum number of distinct reaction kinds per comment
+const MAX_REACTION_KINDS: u32 = 8;
+
+/// Maximum addresses a single comment may @-mention
+const MAX_COMMENT_MENTIONS: u32 = 5;
+
+/// Maximum number of reactions recorded per comment
+const MAX_COMMENT_REACTIONS: u32 = 100;
+
 /// Maximum number of attachments per proposal
 const MAX_ATTACHMENTS: u32 = 10;
 
+/// Maximum signer stages in a proposal's approval order
+const MAX_APPROVAL_STAGES: u32 = 5;
+
+/// Maximum evidence CIDs per escrow milestone
+const MAX_MILESTONE_EVIDENCE: u32 = 5;
+
+/// Maximum signers an invite may grow the signer set to
+const MAX_SIGNERS: u32 = 20;
+
 /// Minimum length for an attachment CID (CIDv0 = 46 chars, CIDv1 base32 = 59+ chars)
 const MIN_ATTACHMENT_LEN: u32 = 46;
 
 /// Maximum length for an attachment CID
 const MAX_ATTACHMENT_LEN: u32 = 128;
 
+/// Minimum length for a base32 CIDv1 (`bafy...` sha2-256 CIDs are 59 chars)
+const CIDV1_MIN_LEN: u32 = 59;
+
 /// Reputation adjustments
 const REP_EXEC_PROPOSER: u32 = 10;
 const REP_EXEC_APPROVER: u32 = 5;
 const REP_REJECTION_PENALTY: u32 = 20;
 const REP_APPROVAL_BONUS: u32 = 2;
 
+/// Risk assessment weights (sum to 100) and thresholds
+const RISK_WEIGHT_SPENDING_LIMIT: u32 = 20;
+const RISK_WEIGHT_BALANCE: u32 = 20;
+const RISK_WEIGHT_NEW_RECIPIENT: u32 = 15;
+const RISK_WEIGHT_REPUTATION: u32 = 15;
+const RISK_WEIGHT_UNDOCUMENTED: u32 = 10;
+const RISK_WEIGHT_EXPIRY: u32 = 10;
+const RISK_WEIGHT_WHITELIST: u32 = 10;
+const RISK_SPENDING_LIMIT_PCT: u32 = 80;
+const RISK_BALANCE_PCT: u32 = 50;
+const RISK_NEUTRAL_REPUTATION: u32 = 500;
+const RISK_RECENT_LEDGERS: u64 = storage::DAY_IN_LEDGERS as u64;
+
+/// Validate an IPFS content identifier.
+///
+/// Accepts CIDv0 (46 chars, `Qm` prefix, base58btc alphabet) and CIDv1 in the
+/// default base32 multibase encoding (`bafy` prefix, lowercase `a-z2-7`, bounded
+/// length). Anything else is rejected.
+fn is_valid_ipfs_cid(cid: &String) -> bool {
+    let len = cid.len();
+    if !(MIN_ATTACHMENT_LEN..=MAX_ATTACHMENT_LEN).contains(&len) {
+        return false;
+    }
+    let mut buf = [0u8; MAX_ATTACHMENT_LEN as usize];
+    let bytes = &mut buf[..len as usize];
+    cid.copy_into_slice(bytes);
+
+    if bytes.starts_with(b"Qm") {
+        // base58btc omits 0, O, I and l
+        len == 46
+            && bytes.iter().all(|b| {
+                matches!(b, b'1'..=b'9' | b'A'..=b'H' | b'J'..=b'N' | b'P'..=b'Z' | b'a'..=b'k' | b'm'..=b'z')
+            })
+    } else if bytes.starts_with(b"bafy") {
+        len >= CIDV1_MIN_LEN && bytes.iter().all(|b| matches!(b, b'a'..=b'z' | b'2'..=b'7'))
+    } else {
+        false
+    }
+}
+
 fn calculate_expiration_ledger(config: &Config, priority: &Priority, current_ledger: u64) -> u64 {
     let multiplier = match priority {
         Priority::Low => 2,
@@ -136,6 +268,8 @@ impl VaultDAO {
         if config.spending_limit <= 0 || config.daily_limit <= 0 || config.weekly_limit <= 0 {
             return Err(VaultError::InvalidAmount);
         }
+        Self::validate_threshold_strategy(&config.threshold_strategy, config.signers.len())?;
+        Self::validate_staking_config(&config.staking_config)?;
 
         // Admin must authorize initialization
         admin.require_auth();
@@ -160,6 +294,9 @@ impl VaultDAO {
             retry_config: config.retry_config,
             recovery_config: config.recovery_config.clone(),
             staking_config: config.staking_config,
+            max_pending_per_proposer: 0,
+            restrict_metrics: false,
+            enforce_memo_whitelist: false,
         };
 
         // Store state
@@ -216,44 +353,24 @@ impl VaultDAO {
         condition_logic: ConditionLogic,
         insurance_amount: i128,
     ) -> Result<u64, VaultError> {
-        let empty_dependencies = Vec::new(&env);
-        Self::propose_transfer_internal(
-            env,
-            proposer,
-            recipient,
-            token_addr,
-            amount,
-            memo,
-            priority,
-            conditions,
-            condition_logic,
-            insurance_amount,
-            empty_dependencies,
-            None,
-        )
+        let mut options = ProposalOptions::default(&env);
+        options.priority = priority;
+        options.conditions = conditions;
+        options.condition_logic = condition_logic;
+        options.insurance_amount = insurance_amount;
+        Self::propose_transfer_v2(env, proposer, recipient, token_addr, amount, memo, options)
     }
 
-    /// Propose a scheduled transfer with delayed execution.
-    ///
-    /// # Arguments
-    /// * `proposer` - The address initiating the proposal (must authorize).
-    /// * `recipient` - The destination address for the funds.
-    /// * `token_addr` - The contract ID of the Stellar Asset Contract (SAC) or custom token.
-    /// * `amount` - The transaction amount (in stroops/smallest unit).
-    /// * `memo` - A descriptive symbol for the transaction.
-    /// * `priority` - Urgency level (Low/Normal/High/Critical).
-    /// * `conditions` - Optional execution conditions.
-    /// * `condition_logic` - And/Or logic for combining conditions.
-    /// * `insurance_amount` - Tokens staked by proposer as guarantee (0 = none).
-    /// * `execution_time` - Scheduled execution ledger.
+    /// Propose a transfer to the address book entry recorded under `label`.
     ///
-    /// # Returns
-    /// The unique ID of the newly created proposal.
+    /// Behaves exactly like `propose_transfer` once the label is resolved.
+    /// An unknown label fails with `ProposalNotFound`, the contract's generic
+    /// not-found error; `AddressNotOnList` stays reserved for list management.
     #[allow(clippy::too_many_arguments)]
-    pub fn propose_scheduled_transfer(
+    pub fn propose_transfer_to_labeled(
         env: Env,
         proposer: Address,
-        recipient: Address,
+        label: Symbol,
         token_addr: Address,
         amount: i128,
         memo: Symbol,
@@ -261,10 +378,10 @@ impl VaultDAO {
         conditions: Vec<Condition>,
         condition_logic: ConditionLogic,
         insurance_amount: i128,
-        execution_time: u64,
     ) -> Result<u64, VaultError> {
-        let empty_dependencies = Vec::new(&env);
-        Self::propose_transfer_internal(
+        let recipient =
+            storage::resolve_address_label(&env, &label).ok_or(VaultError::ProposalNotFound)?;
+        Self::propose_transfer(
             env,
             proposer,
             recipient,
@@ -275,1420 +392,3580 @@ impl VaultDAO {
             conditions,
             condition_logic,
             insurance_amount,
-            empty_dependencies,
-            Some(execution_time),
         )
     }
 
-    /// Propose a new transfer with prerequisite proposal dependencies.
+    /// Propose a transfer whose recipient and amount stay hidden until execution.
     ///
-    /// The proposal is blocked from execution until all `depends_on` proposals are executed.
-    /// Dependencies are validated at creation time for existence and circular references.
-    #[allow(clippy::too_many_arguments)]
-    pub fn propose_transfer_with_deps(
+    /// `commitment` is `sha256(xdr(recipient) || amount || salt)`, with the
+    /// amount as 16 big-endian bytes and a 32-byte salt. Limits, timelock and
+    /// the minimum insurance are applied to `max_amount`, which stays reserved
+    /// until the transfer is revealed or its commitment expires. Signers approve
+    /// the commitment as they would any proposal; only `reveal_and_execute`
+    /// can execute it.
+    pub fn propose_committed_transfer(
         env: Env,
         proposer: Address,
-        recipient: Address,
+        commitment: BytesN<32>,
         token_addr: Address,
-        amount: i128,
-        memo: Symbol,
-        priority: Priority,
-        conditions: Vec<Condition>,
-        condition_logic: ConditionLogic,
-        insurance_amount: i128,
-        depends_on: Vec<u64>,
+        max_amount: i128,
     ) -> Result<u64, VaultError> {
         Self::propose_transfer_internal(
-            env,
+            env.clone(),
             proposer,
-            recipient,
+            // Placeholder until the reveal sets the real recipient
+            env.current_contract_address(),
             token_addr,
-            amount,
-            memo,
-            priority,
-            conditions,
-            condition_logic,
-            insurance_amount,
-            depends_on,
+            max_amount,
+            Symbol::new(&env, "committed"),
+            Priority::Normal,
+            Vec::new(&env),
+            ConditionLogic::And,
+            0,
+            Vec::new(&env),
+            None,
             None,
+            Some(commitment),
         )
     }
 
-    #[allow(clippy::too_many_arguments)]
-    fn propose_transfer_internal(
+    /// Reveal a committed transfer and execute it in the same call.
+    ///
+    /// Fails with `Unauthorized` if the revealed values do not hash to
+    /// the commitment and with `ExceedsProposalLimit` if `amount` is above the
+    /// approved maximum. The recipient lists are checked as for any proposal,
+    /// and the unused part of the reservation is released.
+    pub fn reveal_and_execute(
         env: Env,
-        proposer: Address,
+        executor: Address,
+        proposal_id: u64,
         recipient: Address,
-        token_addr: Address,
         amount: i128,
-        memo: Symbol,
-        priority: Priority,
-        conditions: Vec<Condition>,
-        condition_logic: ConditionLogic,
-        insurance_amount: i128,
-        depends_on: Vec<u64>,
-        execution_time: Option<u64>,
-    ) -> Result<u64, VaultError> {
-        // 1. Verify identity
-        proposer.require_auth();
-
-        // 2. Check initialization and load config (single read — gas optimization)
-        let config = storage::get_config(&env)?;
-
-        // 3. Check permission
-        let role = storage::get_role(&env, &proposer);
-        if role != Role::Treasurer && role != Role::Admin {
-            return Err(VaultError::InsufficientRole);
-        }
-
-        // 4. Validate recipient against lists
-        Self::validate_recipient(&env, &recipient)?;
+        salt: BytesN<32>,
+    ) -> Result<(), VaultError> {
+        let commitment =
+            storage::get_commitment(&env, proposal_id).ok_or(VaultError::ProposalNotFound)?;
+        let mut proposal = storage::get_proposal(&env, proposal_id)?;
 
-        // 5. Velocity Limit Check (Sliding Window)
-        if !storage::check_and_update_velocity(&env, &proposer, &config.velocity_limit) {
-            return Err(VaultError::VelocityLimitExceeded);
+        let mut preimage = recipient.clone().to_xdr(&env);
+        preimage.append(&Bytes::from_array(&env, &amount.to_be_bytes()));
+        preimage.append(&Bytes::from_array(&env, &salt.to_array()));
+        if env.crypto().sha256(&preimage).to_bytes() != commitment {
+            return Err(VaultError::Unauthorized);
         }
-
-        // 6. Validate amount
         if amount <= 0 {
             return Err(VaultError::InvalidAmount);
         }
-
-        // 7. Check per-proposal spending limit with reputation boost
-        // High reputation (800+) gets 2x limit, very high (900+) gets 3x
-        let rep = storage::get_reputation(&env, &proposer);
-        storage::apply_reputation_decay(&env, &mut rep.clone());
-        let adjusted_spending_limit = if rep.score >= 900 {
-            config.spending_limit * 3
-        } else if rep.score >= 800 {
-            config.spending_limit * 2
-        } else {
-            config.spending_limit
-        };
-        if amount > adjusted_spending_limit {
+        if amount > proposal.amount {
             return Err(VaultError::ExceedsProposalLimit);
         }
+        Self::validate_recipient(&env, &recipient)?;
+        Self::validate_transfer_target(&env, &recipient, &proposal.token)?;
 
-        // 8. Check daily aggregate limit with reputation boost
-        // Higher reputation gives higher daily limits (up to 1.5x)
-        let adjusted_daily_limit = if rep.score >= 750 {
-            (config.daily_limit * 3) / 2 // 1.5x for 750+
-        } else {
-            config.daily_limit
-        };
-        let today = storage::get_day_number(&env);
-        let spent_today = storage::get_daily_spent(&env, today);
-        if spent_today + amount > adjusted_daily_limit {
-            return Err(VaultError::ExceedsDailyLimit);
-        }
+        storage::refund_spending_limits(&env, proposal.amount - amount);
+        proposal.recipient = recipient;
+        proposal.amount = amount;
+        storage::set_proposal(&env, &proposal);
+        storage::remove_commitment(&env, proposal_id);
 
-        // 9. Check weekly aggregate limit with reputation boost
-        // Higher reputation gives higher weekly limits (up to 1.5x)
-        let adjusted_weekly_limit = if rep.score >= 750 {
-            (config.weekly_limit * 3) / 2 // 1.5x for 750+
-        } else {
-            config.weekly_limit
-        };
-        let week = storage::get_week_number(&env);
-        let spent_week = storage::get_weekly_spent(&env, week);
-        if spent_week + amount > adjusted_weekly_limit {
-            return Err(VaultError::ExceedsWeeklyLimit);
+        Self::execute_proposal(env, executor, proposal_id)
+    }
+
+    /// Expire a committed transfer that was not revealed before its expiry,
+    /// releasing its reserved spending and deposits. Callable by anyone.
+    pub fn expire_commitment(env: Env, proposal_id: u64) -> Result<(), VaultError> {
+        Self::ensure_initialized(&env)?;
+        if storage::get_commitment(&env, proposal_id).is_none() {
+            return Err(VaultError::ProposalNotFound);
+        }
+        let mut proposal = storage::get_proposal(&env, proposal_id)?;
+        if env.ledger().sequence() as u64 <= proposal.expires_at {
+            return Err(VaultError::ConditionsNotMet);
+        }
+        match proposal.status {
+            ProposalStatus::Pending | ProposalStatus::Approved => {
+                Self::transition_status(
+                    &env,
+                    &mut proposal,
+                    ProposalStatus::Expired,
+                    &env.current_contract_address(),
+                    "expired",
+                );
+                storage::set_proposal(&env, &proposal);
+                storage::metrics_on_expiry(&env);
+                events::emit_proposal_expired(&env, proposal_id, proposal.expires_at);
+            }
+            // Already marked expired by a late vote; the reservation is still held
+            ProposalStatus::Expired => {}
+            _ => return Err(VaultError::ProposalNotPending),
         }
 
-        // 10. Insurance check and locking
-        let insurance_config = storage::get_insurance_config(&env);
-        let mut actual_insurance = insurance_amount;
-        if insurance_config.enabled && amount >= insurance_config.min_amount {
-            // Calculate minimum required insurance
-            let mut min_required = amount * insurance_config.min_insurance_bps as i128 / 10_000;
+        storage::remove_commitment(&env, proposal_id);
+        storage::remove_from_priority_queue(&env, proposal.priority.clone() as u32, proposal_id);
+        storage::refund_spending_limits(&env, proposal.amount);
+        Self::refund_proposal_deposits(&env, &proposal);
+        storage::extend_instance_ttl(&env);
 
-            // Reputation discount: score >= 750 gets 50% off insurance requirement
-            if rep.score >= 750 {
-                min_required /= 2;
-            }
+        Ok(())
+    }
+
+    /// Commitment of a transfer that has not been revealed yet.
+    pub fn get_commitment(env: Env, proposal_id: u64) -> Option<BytesN<32>> {
+        storage::get_commitment(&env, proposal_id)
+    }
 
-            if actual_insurance < min_required {
-                return Err(VaultError::InsuranceInsufficient);
+    /// Propose a new transfer, with optional settings bundled in `options`.
+    ///
+    /// Goes through the same checks as `propose_transfer`; tags, metadata, a
+    /// voting period override and an executor allow-list are applied to the new
+    /// proposal in the same call.
+    ///
+    /// A tag listed twice in `options.tags` fails with `AlreadyApproved`, the
+    /// same error `add_proposal_tag` returns for a duplicate tag; more than
+    /// `MAX_TAGS` fails with `TooManyTags`.
+    ///
+    /// # Arguments
+    /// * `options` - Creation settings; see `ProposalOptions::default` for defaults.
+    ///
+    /// # Returns
+    /// The unique ID of the newly created proposal.
+    pub fn propose_transfer_v2(
+        env: Env,
+        proposer: Address,
+        recipient: Address,
+        token_addr: Address,
+        amount: i128,
+        memo: Symbol,
+        options: ProposalOptions,
+    ) -> Result<u64, VaultError> {
+        if options.tags.len() > MAX_TAGS {
+            return Err(VaultError::TooManyTags);
+        }
+        if options.metadata.len() > MAX_METADATA_ENTRIES {
+            return Err(VaultError::ExceedsProposalLimit);
+        }
+        for value in options.metadata.values().iter() {
+            if value.is_empty() || value.len() > MAX_METADATA_VALUE_LEN {
+                return Err(VaultError::MetadataValueInvalid);
             }
-        } else {
-            // Insurance not required; use 0 unless caller explicitly provided some
-            actual_insurance = if insurance_amount > 0 {
-                insurance_amount
-            } else {
-                0
-            };
         }
-
-        // Lock insurance tokens in vault
-        if actual_insurance > 0 {
-            token::transfer_to_vault(&env, &token_addr, &proposer, actual_insurance);
+        if options.allowed_executors.len() > MAX_ALLOWED_EXECUTORS {
+            return Err(VaultError::BatchTooLarge);
         }
-
-        // 10b. Staking check and locking
-        let staking_config = storage::get_staking_config(&env);
-        let mut actual_stake = 0i128;
-        if staking_config.enabled && amount >= staking_config.min_amount {
-            // Calculate required stake based on proposal amount
-            let mut required_stake = amount * staking_config.base_stake_bps as i128 / 10_000;
-
-            // Cap at maximum stake amount
-            if required_stake > staking_config.max_stake_amount {
-                required_stake = staking_config.max_stake_amount;
+        if options.voting_period > PROPOSAL_EXPIRY_LEDGERS {
+            return Err(VaultError::InvalidAmount);
+        }
+        if !options.approval_order.is_empty() {
+            Self::validate_approval_order(&env, &options.approval_order)?;
+        }
+        if let Some(tip) = options.executor_tip {
+            if tip < 0 {
+                return Err(VaultError::InvalidAmount);
             }
-
-            // Reputation discount: high reputation users get reduced stake requirement
-            if rep.score >= staking_config.reputation_discount_threshold {
-                let discount =
-                    required_stake * staking_config.reputation_discount_percentage as i128 / 100;
-                required_stake = required_stake.saturating_sub(discount);
+            if tip > storage::get_max_executor_tip(&env) {
+                return Err(VaultError::ExceedsProposalLimit);
             }
+        }
 
-            actual_stake = required_stake;
+        let proposal_id = Self::propose_transfer_internal(
+            env.clone(),
+            proposer,
+            recipient,
+            token_addr,
+            amount,
+            memo,
+            options.priority,
+            options.conditions,
+            options.condition_logic,
+            options.insurance_amount,
+            options.depends_on,
+            options.execution_time,
+            options.category,
+            None,
+        )?;
 
-            // Lock stake tokens in vault
-            if actual_stake > 0 {
-                token::transfer_to_vault(&env, &token_addr, &proposer, actual_stake);
+        if !options.tags.is_empty() || !options.metadata.is_empty() || options.voting_period > 0 {
+            let mut proposal = storage::get_proposal(&env, proposal_id)?;
+            for (i, tag) in options.tags.iter().enumerate() {
+                if options.tags.first_index_of(&tag) != Some(i as u32) {
+                    return Err(VaultError::AlreadyApproved); // duplicate tag
+                }
+                // Already applied automatically
+                if proposal.tags.contains(&tag) {
+                    continue;
+                }
+                if proposal.tags.len() >= MAX_TAGS {
+                    return Err(VaultError::TooManyTags);
+                }
+                proposal.tags.push_back(tag.clone());
+                storage::add_to_tag_index(&env, &tag, proposal_id);
+            }
+            proposal.metadata = options.metadata;
+            if options.voting_period > 0 {
+                proposal.voting_deadline = proposal.created_at + options.voting_period;
             }
+            storage::set_proposal(&env, &proposal);
+            storage::index_deadline(&env, &proposal);
+        }
+        if let Some(tip) = options.executor_tip {
+            storage::set_executor_tip(&env, proposal_id, tip);
+        }
+        if !options.allowed_executors.is_empty() {
+            storage::set_allowed_executors(&env, proposal_id, &options.allowed_executors);
+        }
+        if !options.approval_order.is_empty() {
+            storage::set_approval_order(&env, proposal_id, &options.approval_order);
         }
 
-        // 11. Reserve spending (confirmed on execution)
-        storage::add_daily_spent(&env, today, amount);
-        storage::add_weekly_spent(&env, week, amount);
-
-        // 12. Determine timelock
-        let current_ledger = env.ledger().sequence() as u64;
-        let unlock_ledger = if amount >= config.timelock_threshold {
-            current_ledger + config.timelock_delay
-        } else {
-            0
-        };
+        Ok(proposal_id)
+    }
 
-        // 13. Validate execution_time if provided
-        if let Some(exec_time) = execution_time {
-            Self::validate_execution_time(exec_time, current_ledger, unlock_ledger)?;
+    /// Propose a single transfer that pays several recipients atomically.
+    ///
+    /// The proposal's `amount` is the sum of all splits and goes through the usual
+    /// limit, insurance and approval flow once. On execution every recipient is
+    /// re-validated and paid in the same transaction, so either all transfers
+    /// happen or none do. `recipient` on the proposal is the first split.
+    ///
+    /// # Arguments
+    /// * `splits` - `(recipient, amount)` pairs, 1 to 20 entries, amounts > 0.
+    #[allow(clippy::too_many_arguments)]
+    pub fn propose_split_transfer(
+        env: Env,
+        proposer: Address,
+        splits: Vec<(Address, i128)>,
+        token_addr: Address,
+        memo: Symbol,
+        priority: Priority,
+        insurance_amount: i128,
+    ) -> Result<u64, VaultError> {
+        if splits.is_empty() {
+            return Err(VaultError::InvalidAmount);
+        }
+        if splits.len() > MAX_SPLIT_RECIPIENTS {
+            return Err(VaultError::BatchTooLarge);
         }
 
-        // 14. Create and store the proposal
-        let proposal_id = storage::increment_proposal_id(&env);
-        Self::validate_dependencies(&env, proposal_id, &depends_on)?;
+        let mut total: i128 = 0;
+        for (recipient, amount) in splits.iter() {
+            if amount <= 0 {
+                return Err(VaultError::InvalidAmount);
+            }
+            Self::validate_recipient(&env, &recipient)?;
+            Self::validate_transfer_target(&env, &recipient, &token_addr)?;
+            total = total.checked_add(amount).ok_or(VaultError::InvalidAmount)?;
+        }
 
-        // Create stake record after proposal_id is generated
-        if actual_stake > 0 {
-            let stake_record = types::StakeRecord {
-                proposal_id,
-                staker: proposer.clone(),
-                token: token_addr.clone(),
-                amount: actual_stake,
-                locked_at: current_ledger,
-                refunded: false,
-                slashed: false,
-                slashed_amount: 0,
-                released_at: 0,
-            };
-            storage::set_stake_record(&env, &stake_record);
-        }
+        let (first_recipient, _) = splits.get(0).unwrap();
+        let proposal_id = Self::propose_transfer_internal(
+            env.clone(),
+            proposer,
+            first_recipient,
+            token_addr,
+            total,
+            memo,
+            priority,
+            Vec::new(&env),
+            ConditionLogic::And,
+            insurance_amount,
+            Vec::new(&env),
+            None,
+            None,
+            None,
+        )?;
 
-        // Gas limit: derive from GasConfig (0 = unlimited)
-        let gas_cfg = storage::get_gas_config(&env);
-        let proposal_gas_limit = if gas_cfg.enabled {
-            gas_cfg.default_gas_limit
-        } else {
-            0
-        };
+        storage::set_split_transfer(&env, proposal_id, &splits);
 
-        let proposal = Proposal {
-            id: proposal_id,
-            proposer: proposer.clone(),
-            recipient: recipient.clone(),
-            token: token_addr.clone(),
-            amount,
-            memo,
-            metadata: Map::new(&env),
-            tags: Vec::new(&env),
-            approvals: Vec::new(&env),
-            abstentions: Vec::new(&env),
-            attachments: Vec::new(&env),
-            status: ProposalStatus::Pending,
-            priority: priority.clone(),
-            conditions: conditions.clone(),
-            condition_logic,
-            created_at: current_ledger,
-            expires_at: current_ledger + PROPOSAL_EXPIRY_LEDGERS,
-            unlock_ledger,
-            execution_time,
-            insurance_amount: actual_insurance,
-            stake_amount: actual_stake,
-            gas_limit: proposal_gas_limit,
-            gas_used: 0,
-            snapshot_ledger: current_ledger,
-            snapshot_signers: config.signers.clone(),
-            depends_on: depends_on.clone(),
-            is_swap: false,
-            voting_deadline: if config.default_voting_deadline > 0 {
-                current_ledger + config.default_voting_deadline
-            } else {
-                0
-            },
-        };
+        Ok(proposal_id)
+    }
 
-        storage::set_proposal(&env, &proposal);
-        Self::persist_execution_fee_estimate(&env, &proposal);
-        storage::add_to_priority_queue(&env, priority as u32, proposal_id);
+    /// Get the recipient splits of a split-transfer proposal (`None` for plain transfers).
+    pub fn get_split_transfer(env: Env, proposal_id: u64) -> Option<Vec<(Address, i128)>> {
+        storage::get_split_transfer(&env, proposal_id)
+    }
 
-        // Extend TTL to ensure persistent data stays alive
-        storage::extend_instance_ttl(&env);
+    /// Restrict who may execute a pending proposal.
+    ///
+    /// Only the proposer can set the list, and only while the proposal is
+    /// pending. Once set, `execute_proposal` and `batch_execute_proposals`
+    /// accept only listed executors or an Admin. An empty list removes the
+    /// restriction.
+    ///
+    /// # Arguments
+    /// * `executors` - Keeper addresses, at most 10.
+    pub fn set_allowed_executors(
+        env: Env,
+        proposer: Address,
+        proposal_id: u64,
+        executors: Vec<Address>,
+    ) -> Result<(), VaultError> {
+        proposer.require_auth();
+        Self::ensure_initialized(&env)?;
 
-        // Create audit entry
-        storage::create_audit_entry(&env, AuditAction::ProposeTransfer, &proposer, proposal_id);
-        // 13. Emit events
-        // 15. Emit events
-        if actual_insurance > 0 {
-            events::emit_insurance_locked(
-                &env,
-                proposal_id,
-                &proposer,
-                actual_insurance,
-                &token_addr,
-            );
+        let proposal = storage::get_proposal(&env, proposal_id)?;
+        if proposal.proposer != proposer {
+            return Err(VaultError::Unauthorized);
         }
-        if actual_stake > 0 {
-            events::emit_stake_locked(&env, proposal_id, &proposer, actual_stake, &token_addr);
+        if proposal.status != ProposalStatus::Pending {
+            return Err(VaultError::ProposalNotPending);
+        }
+        if executors.len() > MAX_ALLOWED_EXECUTORS {
+            return Err(VaultError::BatchTooLarge);
         }
-        events::emit_proposal_created(
-            &env,
-            proposal_id,
-            &proposer,
-            &recipient,
-            &token_addr,
-            amount,
-            actual_insurance,
-        );
 
-        // Update reputation for creating proposal
-        Self::update_reputation_on_propose(&env, &proposer);
+        storage::set_allowed_executors(&env, proposal_id, &executors);
+        storage::extend_instance_ttl(&env);
 
-        Ok(proposal_id)
+        Ok(())
     }
 
-    /// Propose multiple transfers in a single batch, supporting multiple token types.
-    ///
-    /// Creates separate proposals for each transfer, enabling complex treasury operations
-    /// like portfolio rebalancing with atomic multi-token transfers.
+    /// Set how many ledgers an approved proposal has to execute before it lapses.
     ///
-    /// # Arguments
-    /// * `proposer` - The address initiating the proposals (must authorize).
-    /// * `transfers` - Vector of transfer details (recipient, token, amount, memo).
-    /// * `priority` - Urgency level applied to all proposals.
-    /// * `conditions` - Optional execution conditions applied to all proposals.
-    /// * `condition_logic` - And/Or logic for combining conditions.
-    /// * `insurance_amount` - Total insurance staked across all proposals.
+    /// Applies to proposals approved after the change; 0 (the default) keeps
+    /// them executable until expiry. Admin only.
+    pub fn set_execution_window(env: Env, admin: Address, ledgers: u64) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+
+        storage::set_execution_window(&env, ledgers);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Vault-wide execution window in ledgers (0 = none).
+    pub fn get_execution_window(env: Env) -> u64 {
+        storage::get_execution_window(&env)
+    }
+
+    /// Override the vault's execution window for one pending proposal.
     ///
-    /// # Returns
-    /// Vector of proposal IDs created.
-    #[allow(clippy::too_many_arguments)]
-    pub fn batch_propose_transfers(
+    /// Only the proposer can set it, and only while the proposal is pending.
+    /// 0 lets the proposal execute until expiry regardless of the vault default.
+    pub fn set_proposal_execution_window(
         env: Env,
         proposer: Address,
-        transfers: Vec<TransferDetails>,
-        priority: Priority,
-        conditions: Vec<Condition>,
-        condition_logic: ConditionLogic,
-        insurance_amount: i128,
-    ) -> Result<Vec<u64>, VaultError> {
+        proposal_id: u64,
+        ledgers: u64,
+    ) -> Result<(), VaultError> {
         proposer.require_auth();
+        Self::ensure_initialized(&env)?;
 
-        if transfers.len() > MAX_BATCH_SIZE {
-            return Err(VaultError::BatchTooLarge);
+        let proposal = storage::get_proposal(&env, proposal_id)?;
+        if proposal.proposer != proposer {
+            return Err(VaultError::Unauthorized);
         }
-
-        let config = storage::get_config(&env)?;
-        let role = storage::get_role(&env, &proposer);
-        if role != Role::Treasurer && role != Role::Admin {
-            return Err(VaultError::InsufficientRole);
+        if proposal.status != ProposalStatus::Pending {
+            return Err(VaultError::ProposalNotPending);
         }
 
-        // Velocity check once for the batch
-        if !storage::check_and_update_velocity(&env, &proposer, &config.velocity_limit) {
-            return Err(VaultError::VelocityLimitExceeded);
+        storage::set_execution_window_override(&env, proposal_id, ledgers);
+        storage::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Last ledger an approved proposal may execute at (0 = no window).
+    pub fn get_execute_by(env: Env, proposal_id: u64) -> u64 {
+        storage::get_execute_by(&env, proposal_id)
+    }
+
+    /// Set how many ledgers before expiry `emit_expiry_warnings` flags a
+    /// proposal (default one day). Admin only.
+    pub fn set_expiry_warning_window(
+        env: Env,
+        admin: Address,
+        ledgers: u64,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
         }
 
-        let today = storage::get_day_number(&env);
-        let week = storage::get_week_number(&env);
-        let mut total_amount = 0i128;
-        let mut token_amounts: Vec<(Address, i128)> = Vec::new(&env);
+        storage::set_expiry_warning_window(&env, ledgers);
+        storage::extend_instance_ttl(&env);
 
-        // Pre-validate all transfers and calculate totals per token
-        for i in 0..transfers.len() {
-            let transfer = transfers.get(i).unwrap();
+        events::emit_config_updated(&env, &admin);
 
-            if transfer.amount <= 0 {
-                return Err(VaultError::InvalidAmount);
-            }
-            if transfer.amount > config.spending_limit {
-                return Err(VaultError::ExceedsProposalLimit);
-            }
+        Ok(())
+    }
 
-            total_amount += transfer.amount;
+    /// Emit `proposal_expiring` for open proposals that expire within the
+    /// warning window, earliest first, up to `max_count` (capped at 20).
+    ///
+    /// Permissionless keeper entry point. Proposals leave the expiry index once
+    /// warned, so each one is warned at most once; entries that already expired
+    /// are dropped without a warning. Returns the number of warnings emitted.
+    pub fn emit_expiry_warnings(env: Env, max_count: u32) -> u32 {
+        let now = env.ledger().sequence() as u64;
+        let horizon = now.saturating_add(storage::get_expiry_warning_window(&env));
+        let limit = max_count.min(MAX_EXPIRY_WARNINGS_PER_CALL);
 
-            // Track per-token amounts
-            let mut found = false;
-            for j in 0..token_amounts.len() {
-                let mut entry = token_amounts.get(j).unwrap();
-                if entry.0 == transfer.token {
-                    entry.1 += transfer.amount;
-                    token_amounts.set(j, entry);
-                    found = true;
-                    break;
-                }
+        let mut handled = 0u32;
+        let mut warned = 0u32;
+        for (expires_at, proposal_id) in storage::get_expiry_index(&env).iter() {
+            if expires_at > horizon || warned >= limit {
+                break;
             }
-            if !found {
-                token_amounts.push_back((transfer.token.clone(), transfer.amount));
+            handled += 1;
+            if expires_at < now {
+                continue;
+            }
+            if let Ok(proposal) = storage::get_proposal(&env, proposal_id) {
+                events::emit_proposal_expiring(&env, proposal_id, expires_at, expires_at - now);
+                Self::notify(&env, &proposal.proposer, "expiry", |prefs| {
+                    prefs.notify_on_expiry
+                });
+                warned += 1;
             }
         }
+        storage::pop_expiry_entries(&env, handled);
 
-        // Check aggregate limits
-        let spent_today = storage::get_daily_spent(&env, today);
-        if spent_today + total_amount > config.daily_limit {
-            return Err(VaultError::ExceedsDailyLimit);
-        }
+        warned
+    }
 
-        let spent_week = storage::get_weekly_spent(&env, week);
-        if spent_week + total_amount > config.weekly_limit {
-            return Err(VaultError::ExceedsWeeklyLimit);
-        }
+    /// Reject pending proposals whose voting deadline has passed, earliest
+    /// deadline first, up to `max_count` (capped at 20).
+    ///
+    /// Permissionless keeper entry point. Each rejection matches the lazy one
+    /// in `approve_proposal` (status, metrics, `proposal_deadline_rejected`)
+    /// and also releases the proposal's reserved spending and deposits.
+    /// Entries whose proposal no longer exists (archived) are dropped from
+    /// the index without counting towards `max_count`.
+    /// Returns the rejected proposal IDs.
+    pub fn enforce_deadlines(env: Env, max_count: u32) -> Vec<u64> {
+        let now = env.ledger().sequence() as u64;
+        let limit = max_count.min(MAX_DEADLINE_REJECTIONS_PER_CALL);
 
-        // Handle insurance
-        let insurance_config = storage::get_insurance_config(&env);
-        let mut actual_insurance = insurance_amount;
-        if insurance_config.enabled && total_amount >= insurance_config.min_amount {
-            let mut min_required =
-                total_amount * insurance_config.min_insurance_bps as i128 / 10_000;
-            let rep = storage::get_reputation(&env, &proposer);
-            if rep.score >= 750 {
-                min_required /= 2;
+        let mut rejected = Vec::new(&env);
+        let mut dropped = false;
+        for (deadline, proposal_id) in storage::get_deadline_index(&env).iter() {
+            if deadline >= now || rejected.len() >= limit {
+                break;
             }
-            if actual_insurance < min_required {
-                return Err(VaultError::InsuranceInsufficient);
+            match storage::get_proposal(&env, proposal_id) {
+                Ok(mut proposal) => {
+                    Self::reject_past_deadline(&env, &mut proposal);
+                    rejected.push_back(proposal_id);
+                }
+                Err(_) => {
+                    storage::remove_deadline_entry(&env, proposal_id);
+                    dropped = true;
+                }
             }
-        } else {
-            actual_insurance = if insurance_amount > 0 {
-                insurance_amount
-            } else {
-                0
-            };
         }
-
-        // Lock insurance if required (use first token in batch)
-        if actual_insurance > 0 && !transfers.is_empty() {
-            let first_token = transfers.get(0).unwrap().token;
-            token::transfer_to_vault(&env, &first_token, &proposer, actual_insurance);
+        if !rejected.is_empty() || dropped {
+            storage::extend_instance_ttl(&env);
         }
 
-        // Reserve spending
-        storage::add_daily_spent(&env, today, total_amount);
-        storage::add_weekly_spent(&env, week, total_amount);
+        rejected
+    }
 
-        // Gas limit: derive from GasConfig (0 = unlimited)
-        let gas_cfg = storage::get_gas_config(&env);
-        let proposal_gas_limit = if gas_cfg.enabled {
-            gas_cfg.default_gas_limit
-        } else {
-            0
-        };
+    /// Get a proposal's executor allow-list (empty = anyone may execute).
+    pub fn get_allowed_executors(env: Env, proposal_id: u64) -> Vec<Address> {
+        storage::get_allowed_executors(&env, proposal_id)
+    }
 
-        // Create proposals
-        let current_ledger = env.ledger().sequence() as u64;
-        let mut proposal_ids = Vec::new(&env);
-        let insurance_per_proposal = if !transfers.is_empty() {
-            actual_insurance / transfers.len() as i128
+    /// Propose a scheduled transfer with delayed execution.
+    ///
+    /// # Arguments
+    /// * `proposer` - The address initiating the proposal (must authorize).
+    /// * `recipient` - The destination address for the funds.
+    /// * `token_addr` - The contract ID of the Stellar Asset Contract (SAC) or custom token.
+    /// * `amount` - The transaction amount (in stroops/smallest unit).
+    /// * `memo` - A descriptive symbol for the transaction.
+    /// * `priority` - Urgency level (Low/Normal/High/Critical).
+    /// * `conditions` - Optional execution conditions.
+    /// * `condition_logic` - And/Or logic for combining conditions.
+    /// * `insurance_amount` - Tokens staked by proposer as guarantee (0 = none).
+    /// * `execution_time` - Scheduled execution ledger.
+    ///
+    /// # Returns
+    /// The unique ID of the newly created proposal.
+    #[allow(clippy::too_many_arguments)]
+    pub fn propose_scheduled_transfer(
+        env: Env,
+        proposer: Address,
+        recipient: Address,
+        token_addr: Address,
+        amount: i128,
+        memo: Symbol,
+        priority: Priority,
+        conditions: Vec<Condition>,
+        condition_logic: ConditionLogic,
+        insurance_amount: i128,
+        execution_time: u64,
+    ) -> Result<u64, VaultError> {
+        let mut options = ProposalOptions::default(&env);
+        options.priority = priority;
+        options.conditions = conditions;
+        options.condition_logic = condition_logic;
+        options.insurance_amount = insurance_amount;
+        options.execution_time = Some(execution_time);
+        Self::propose_transfer_v2(env, proposer, recipient, token_addr, amount, memo, options)
+    }
+
+    /// Propose a new transfer with prerequisite proposal dependencies.
+    ///
+    /// The proposal is blocked from execution until all `depends_on` proposals are executed.
+    /// Dependencies are validated at creation time for existence and circular references.
+    #[allow(clippy::too_many_arguments)]
+    pub fn propose_transfer_with_deps(
+        env: Env,
+        proposer: Address,
+        recipient: Address,
+        token_addr: Address,
+        amount: i128,
+        memo: Symbol,
+        priority: Priority,
+        conditions: Vec<Condition>,
+        condition_logic: ConditionLogic,
+        insurance_amount: i128,
+        depends_on: Vec<u64>,
+    ) -> Result<u64, VaultError> {
+        let mut options = ProposalOptions::default(&env);
+        options.priority = priority;
+        options.conditions = conditions;
+        options.condition_logic = condition_logic;
+        options.insurance_amount = insurance_amount;
+        options.depends_on = depends_on;
+        Self::propose_transfer_v2(env, proposer, recipient, token_addr, amount, memo, options)
+    }
+
+    /// Check whether a transfer proposal would be accepted, without side effects.
+    ///
+    /// Returns recipient list status, remaining spending headroom (with reputation
+    /// boosts applied), velocity slots left, and the insurance and stake the
+    /// proposer would need. No counters are updated.
+    pub fn precheck_proposal(
+        env: Env,
+        proposer: Address,
+        recipient: Address,
+        token_addr: Address,
+        amount: i128,
+    ) -> Result<PrecheckResult, VaultError> {
+        let config = storage::get_config(&env)?;
+
+        let role = storage::get_role(&env, &proposer);
+        let has_role = role == Role::Treasurer || role == Role::Admin;
+        let recipient_allowed = Self::validate_recipient(&env, &recipient).is_ok()
+            && Self::validate_transfer_target(&env, &recipient, &token_addr).is_ok();
+
+        let rep = storage::get_reputation(&env, &proposer);
+        let (spending_limit, daily_limit, weekly_limit) =
+            Self::reputation_adjusted_limits(&config, &rep);
+        let daily_remaining = daily_limit
+            .saturating_sub(storage::get_daily_spent(
+                &env,
+                storage::get_day_number(&env),
+            ))
+            .max(0);
+        let weekly_remaining = weekly_limit
+            .saturating_sub(storage::get_weekly_spent(
+                &env,
+                storage::get_week_number(&env),
+            ))
+            .max(0);
+
+        let velocity_used = storage::get_velocity_count(&env, &proposer, &config.velocity_limit);
+        let velocity_remaining = config.velocity_limit.limit.saturating_sub(velocity_used);
+
+        let required_insurance = if storage::is_insurance_exempt(&env, &recipient) {
+            0
+        } else {
+            Self::required_insurance(
+                &env,
+                &storage::get_insurance_config(&env),
+                &token_addr,
+                amount,
+                &rep,
+            )?
+        };
+        let required_stake = Self::required_stake(&storage::get_staking_config(&env), amount, &rep);
+
+        let within_spending_limit = amount > 0 && amount <= spending_limit;
+        let feasible = has_role
+            && recipient_allowed
+            && within_spending_limit
+            && amount <= daily_remaining
+            && amount <= weekly_remaining
+            && velocity_remaining > 0;
+
+        Ok(PrecheckResult {
+            has_role,
+            recipient_allowed,
+            spending_limit,
+            within_spending_limit,
+            daily_remaining,
+            weekly_remaining,
+            velocity_remaining,
+            required_insurance,
+            required_stake,
+            requires_timelock: amount >= config.timelock_threshold,
+            feasible,
+        })
+    }
+
+    /// Spending, daily and weekly limits after reputation boosts.
+    ///
+    /// High reputation (800+) doubles the per-proposal limit and 900+ triples it;
+    /// 750+ raises the daily and weekly aggregates by 1.5x.
+    fn reputation_adjusted_limits(config: &Config, rep: &Reputation) -> (i128, i128, i128) {
+        let spending = if rep.score >= 900 {
+            config.spending_limit * 3
+        } else if rep.score >= 800 {
+            config.spending_limit * 2
+        } else {
+            config.spending_limit
+        };
+        let (daily, weekly) = if rep.score >= 750 {
+            ((config.daily_limit * 3) / 2, (config.weekly_limit * 3) / 2)
+        } else {
+            (config.daily_limit, config.weekly_limit)
+        };
+        (spending, daily, weekly)
+    }
+
+    /// Freeze the proposer's reputation-adjusted limits when a proposal is
+    /// approved and report them in `proposal_ready`.
+    ///
+    /// Limits are enforced when a proposal is created, and execution never
+    /// recomputes them, so decay during a long timelock cannot block an
+    /// approved proposal. The frozen values record what applied at approval.
+    fn freeze_approval_limits(env: &Env, config: &Config, proposal: &Proposal) -> ApprovalLimits {
+        let mut rep = storage::get_reputation(env, &proposal.proposer);
+        storage::apply_reputation_decay(env, &mut rep);
+        let (spending, daily, weekly) = Self::reputation_adjusted_limits(config, &rep);
+        let limits = ApprovalLimits {
+            rep_boosted: spending > config.spending_limit || daily > config.daily_limit,
+            spending_limit: spending,
+            daily_limit: daily,
+            weekly_limit: weekly,
+        };
+        storage::set_approval_limits(env, proposal.id, &limits);
+        limits
+    }
+
+    /// Start a proposal's execution window on approval (proposal override first,
+    /// then the vault default; 0 = executable until expiry).
+    ///
+    /// The window is counted from approval, or from the end of the timelock if
+    /// there is one, so a short window can never fall entirely inside it.
+    fn start_execution_window(env: &Env, proposal: &Proposal, approval_ledger: u64) -> u64 {
+        let window = storage::get_execution_window_override(env, proposal.id)
+            .unwrap_or_else(|| storage::get_execution_window(env));
+        if window == 0 {
+            return 0;
+        }
+        let execute_by = approval_ledger.max(proposal.unlock_ledger) + window;
+        storage::set_execute_by(env, proposal.id, execute_by);
+        execute_by
+    }
+
+    /// Whether an approved Critical proposal is still timelocked and may take
+    /// further approvals towards a bypass.
+    fn timelock_bypass_open(env: &Env, proposal: &Proposal) -> bool {
+        proposal.status == ProposalStatus::Approved
+            && proposal.priority == Priority::Critical
+            && proposal.unlock_ledger > env.ledger().sequence() as u64
+            && storage::get_timelock_bypass_config(env).enabled
+    }
+
+    /// Whether a proposal can no longer be voted on because it expired or, while
+    /// pending, missed its voting deadline.
+    fn vote_lapsed(env: &Env, proposal: &Proposal) -> bool {
+        let now = env.ledger().sequence() as u64;
+        (proposal.expires_at > 0 && now > proposal.expires_at)
+            || (proposal.status == ProposalStatus::Pending
+                && proposal.voting_deadline > 0
+                && now > proposal.voting_deadline)
+    }
+
+    /// Whether a proposal has enough approvals to clear its timelock early.
+    fn timelock_bypass_reached(env: &Env, proposal: &Proposal) -> bool {
+        let bypass = storage::get_timelock_bypass_config(env);
+        let required = if bypass.bypass_threshold == 0 {
+            proposal.snapshot_signers.len()
+        } else {
+            bypass.bypass_threshold
+        };
+        proposal.approvals.len() >= required
+    }
+
+    /// Whether an approved proposal has missed its execution window.
+    fn execution_window_lapsed(env: &Env, proposal: &Proposal, current_ledger: u64) -> bool {
+        let execute_by = storage::get_execute_by(env, proposal.id);
+        execute_by > 0 && current_ledger > execute_by
+    }
+
+    /// Move a proposal to `to`, appending the change to its status history and
+    /// updating everything keyed on its status (see `apply_status_change`).
+    ///
+    /// Callers still persist the proposal with `storage::set_proposal`.
+    fn transition_status(
+        env: &Env,
+        proposal: &mut Proposal,
+        to: ProposalStatus,
+        actor: &Address,
+        reason: &str,
+    ) {
+        let from = proposal.status.clone();
+        let transition = StatusTransition {
+            from: from.clone(),
+            to: to.clone(),
+            actor: actor.clone(),
+            ledger: env.ledger().sequence() as u64,
+            reason: Symbol::new(env, reason),
+        };
+        storage::push_status_transition(env, proposal.id, &transition);
+        proposal.status = to;
+        // Rewriting the same status (e.g. a second expiry) must not count twice
+        if proposal.status != from {
+            Self::apply_status_change(env, proposal, Some(from));
+        }
+    }
+
+    /// Book a newly stored proposal into the indexes and counters.
+    fn record_proposal_created(env: &Env, proposal: &Proposal) {
+        Self::apply_status_change(env, proposal, None);
+    }
+
+    /// Bookkeeping for a proposal entering its current status from `from`
+    /// (`None` on creation): per-proposer open counts, the expiry, deadline
+    /// and approved indexes, locked deposits, lifecycle timestamps,
+    /// dependencies, the approval-cap snapshot, bundle membership and the
+    /// status counters.
+    fn apply_status_change(env: &Env, proposal: &Proposal, from: Option<ProposalStatus>) {
+        let created = from.is_none();
+        let was_open = from.as_ref().is_some_and(|s| !s.is_terminal());
+        let is_open = !proposal.status.is_terminal();
+        if was_open != is_open {
+            storage::adjust_pending_count(env, &proposal.proposer, is_open);
+        }
+        if created && is_open && proposal.expires_at > 0 {
+            storage::insert_expiry_entry(env, proposal.expires_at, proposal.id);
+        } else if was_open && !is_open {
+            storage::remove_expiry_entry(env, proposal.id);
+        }
+        // Deposits are locked at creation and refunded or slashed when the
+        // proposal reaches a terminal status.
+        if created && is_open {
+            storage::adjust_locked_deposits(env, proposal, 1);
+        } else if was_open && !is_open {
+            storage::adjust_locked_deposits(env, proposal, -1);
+        }
+        storage::index_deadline(env, proposal);
+        storage::record_lifecycle_timestamp(env, proposal, created);
+        if proposal.status == ProposalStatus::Approved {
+            storage::add_approved(env, proposal.id);
+        } else if from == Some(ProposalStatus::Approved) {
+            storage::remove_approved(env, proposal.id);
+        }
+        if created {
+            if !proposal.depends_on.is_empty() {
+                storage::register_dependencies(env, proposal);
+            }
+            storage::snapshot_approval_caps(env, proposal.id);
+        }
+        if proposal.status == ProposalStatus::Executed {
+            storage::release_dependents(env, proposal.id);
+        }
+        if was_open && !is_open {
+            storage::detach_from_bundle(env, proposal.id);
+        }
+        storage::move_status_count(env, from, proposal.status.clone());
+    }
+
+    /// Expire a proposal that missed its execution window and return its deposits.
+    fn lapse_execution_window(env: &Env, proposal: &mut Proposal, actor: &Address) {
+        Self::transition_status(env, proposal, ProposalStatus::Expired, actor, "window");
+        storage::set_proposal(env, proposal);
+        Self::refund_proposal_deposits(env, proposal);
+        storage::metrics_on_expiry(env);
+        events::emit_proposal_expired(env, proposal.id, storage::get_execute_by(env, proposal.id));
+        Self::notify(env, &proposal.proposer, "expiry", |prefs| {
+            prefs.notify_on_expiry
+        });
+    }
+
+    /// Add a notification to `addr`'s digest if their preferences opt in to it.
+    fn notify(env: &Env, addr: &Address, kind: &str, wants: fn(&NotificationPreferences) -> bool) {
+        let prefs = storage::get_notification_prefs(env, addr);
+        if wants(&prefs) {
+            storage::bump_notification_digest(env, addr);
+            let route = prefs.route_to.unwrap_or_else(|| addr.clone());
+            events::emit_notification(env, &route, addr, Symbol::new(env, kind));
+        }
+    }
+
+    /// Notify every signer except the proposer about a new proposal.
+    fn notify_signers_of_proposal(env: &Env, config: &Config, proposer: &Address) {
+        for signer in config.signers.iter() {
+            if signer != *proposer {
+                Self::notify(env, &signer, "proposal", |prefs| prefs.notify_on_proposal);
+            }
+        }
+    }
+
+    /// Reject a pending proposal whose voting deadline has passed, releasing
+    /// its reserved spending and deposits.
+    fn reject_past_deadline(env: &Env, proposal: &mut Proposal) {
+        Self::transition_status(
+            env,
+            proposal,
+            ProposalStatus::Rejected,
+            &env.current_contract_address(),
+            "deadline",
+        );
+        storage::set_proposal(env, proposal);
+        storage::remove_from_priority_queue(env, proposal.priority.clone() as u32, proposal.id);
+        storage::refund_spending_limits(env, proposal.amount);
+        storage::unlog_daily_proposal(env, storage::get_day_number(env), proposal.id);
+        // Releasing a reservation never fails
+        let _ = Self::adjust_category_budget(env, proposal.id, -proposal.amount);
+        Self::refund_proposal_deposits(env, proposal);
+        storage::metrics_on_rejection(env);
+        events::emit_proposal_deadline_rejected(env, proposal.id, proposal.voting_deadline);
+        Self::notify(env, &proposal.proposer, "rejection", |prefs| {
+            prefs.notify_on_rejection
+        });
+    }
+
+    /// Reject a proposal whose scheduled retry can no longer meet the vote
+    /// requirements, clearing its retry state and refunding deposits.
+    fn invalidate_retry(
+        env: &Env,
+        proposal: &mut Proposal,
+        actor: &Address,
+        retry_state: &RetryState,
+        err: VaultError,
+    ) {
+        Self::transition_status(env, proposal, ProposalStatus::Rejected, actor, "retry");
+        storage::set_proposal(env, proposal);
+        storage::remove_retry_state(env, proposal.id);
+        storage::remove_from_priority_queue(env, proposal.priority.clone() as u32, proposal.id);
+        Self::refund_proposal_deposits(env, proposal);
+        storage::metrics_on_rejection(env);
+        events::emit_requirements_invalidated(
+            env,
+            proposal.id,
+            retry_state.retry_count,
+            err as u32,
+        );
+        Self::notify(env, &proposal.proposer, "rejection", |prefs| {
+            prefs.notify_on_rejection
+        });
+    }
+
+    /// Minimum insurance a proposer must lock for `amount` (0 if not required).
+    ///
+    /// Reputation 750+ halves the requirement.
+    fn required_insurance(
+        env: &Env,
+        insurance_config: &InsuranceConfig,
+        token_addr: &Address,
+        amount: i128,
+        rep: &Reputation,
+    ) -> Result<i128, VaultError> {
+        if !insurance_config.enabled
+            || !Self::insurance_threshold_met(env, insurance_config, token_addr, amount)?
+        {
+            return Ok(0);
+        }
+        let min_required = math::bps_of(amount, insurance_config.min_insurance_bps);
+        if rep.score >= 750 {
+            Ok(min_required / 2)
+        } else {
+            Ok(min_required)
+        }
+    }
+
+    /// Whether `amount` of `token_addr` is large enough to need insurance.
+    ///
+    /// With a USD threshold set and an oracle configured the USD value
+    /// decides; if the price cannot be read, `oracle_fallback` picks between
+    /// the token-unit threshold, always requiring insurance, or failing.
+    fn insurance_threshold_met(
+        env: &Env,
+        insurance_config: &InsuranceConfig,
+        token_addr: &Address,
+        amount: i128,
+    ) -> Result<bool, VaultError> {
+        let oracle_configured = matches!(
+            storage::get_oracle_config(env),
+            OptionalVaultOracleConfig::Some(_)
+        );
+        let usd_threshold = storage::get_insurance_usd_threshold(env);
+        if usd_threshold.min_amount_usd > 0 && oracle_configured {
+            match Self::convert_to_usd(env, token_addr.clone(), amount) {
+                Ok(usd) => return Ok(usd >= usd_threshold.min_amount_usd),
+                Err(err) => match usd_threshold.oracle_fallback {
+                    InsuranceOracleFallback::TokenThreshold => {}
+                    InsuranceOracleFallback::RequireInsurance => return Ok(true),
+                    InsuranceOracleFallback::Reject => return Err(err),
+                },
+            }
+        }
+        Ok(amount >= insurance_config.min_amount)
+    }
+
+    /// Stake a proposer must lock for `amount` (0 if staking does not apply).
+    fn required_stake(
+        staking_config: &types::StakingConfig,
+        amount: i128,
+        rep: &Reputation,
+    ) -> i128 {
+        if !staking_config.enabled || amount < staking_config.min_amount {
+            return 0;
+        }
+        let mut required_stake = math::bps_of(amount, staking_config.base_stake_bps);
+        if required_stake > staking_config.max_stake_amount {
+            required_stake = staking_config.max_stake_amount;
+        }
+        if rep.score >= staking_config.reputation_discount_threshold {
+            let discount = math::pct_of(
+                required_stake,
+                staking_config.reputation_discount_percentage,
+            );
+            required_stake = required_stake.saturating_sub(discount);
+        }
+        required_stake
+    }
+
+    /// Check `amount` against the proposer's reputation-adjusted per-proposal,
+    /// daily and weekly limits. Returns the proposer's reputation for callers
+    /// that size insurance or stake from it.
+    fn check_spending_limits(
+        env: &Env,
+        config: &Config,
+        proposer: &Address,
+        amount: i128,
+    ) -> Result<Reputation, VaultError> {
+        let rep = storage::get_reputation(env, proposer);
+        storage::apply_reputation_decay(env, &mut rep.clone());
+        let (adjusted_spending_limit, adjusted_daily_limit, adjusted_weekly_limit) =
+            Self::reputation_adjusted_limits(config, &rep);
+        if amount > adjusted_spending_limit {
+            return Err(VaultError::ExceedsProposalLimit);
+        }
+
+        let today = storage::get_day_number(env);
+        if storage::get_daily_spent(env, today) + amount > adjusted_daily_limit {
+            return Err(VaultError::ExceedsDailyLimit);
+        }
+
+        let week = storage::get_week_number(env);
+        if storage::get_weekly_spent(env, week) + amount > adjusted_weekly_limit {
+            return Err(VaultError::ExceedsWeeklyLimit);
+        }
+        Ok(rep)
+    }
+
+    /// Reserve daily and weekly capacity for a new proposal. Released again by
+    /// `refund_spending_limits` on cancellation or rejection.
+    fn reserve_spending(env: &Env, amount: i128) {
+        storage::add_daily_spent(env, storage::get_day_number(env), amount);
+        storage::add_weekly_spent(env, storage::get_week_number(env), amount);
+    }
+
+    /// Token and amount a swap operation draws from the vault, used for limit
+    /// accounting. Operations that spend nothing (reward claims) report zero.
+    fn swap_spend(env: &Env, swap_op: &SwapProposal) -> (Address, i128) {
+        match swap_op {
+            SwapProposal::Swap(_, token_in, _, amount_in, _) => (token_in.clone(), *amount_in),
+            SwapProposal::AddLiquidity(_, token_a, _, amount_a, _, _) => {
+                (token_a.clone(), *amount_a)
+            }
+            SwapProposal::RemoveLiquidity(_, lp_token, amount, _, _) => (lp_token.clone(), *amount),
+            SwapProposal::StakeLp(_, lp_token, amount) => (lp_token.clone(), *amount),
+            SwapProposal::UnstakeLp(_, lp_token, amount) => (lp_token.clone(), *amount),
+            SwapProposal::ClaimRewards(_) => (env.current_contract_address(), 0),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn propose_transfer_internal(
+        env: Env,
+        proposer: Address,
+        recipient: Address,
+        token_addr: Address,
+        amount: i128,
+        memo: Symbol,
+        priority: Priority,
+        conditions: Vec<Condition>,
+        condition_logic: ConditionLogic,
+        insurance_amount: i128,
+        depends_on: Vec<u64>,
+        execution_time: Option<u64>,
+        category: Option<Symbol>,
+        commitment: Option<BytesN<32>>,
+    ) -> Result<u64, VaultError> {
+        // 1. Verify identity
+        proposer.require_auth();
+
+        // 2. Check initialization and load config (single read — gas optimization)
+        let config = storage::get_config(&env)?;
+
+        // 3. Check permission
+        let role = storage::get_role(&env, &proposer);
+        if role != Role::Treasurer && role != Role::Admin {
+            return Err(VaultError::InsufficientRole);
+        }
+
+        Self::check_pending_cap(&env, &config, &proposer, 1)?;
+
+        // 4. Validate memo and recipient against lists and self-transfers. A
+        // committed transfer's memo and recipient are placeholders; the real
+        // recipient is checked on reveal.
+        if commitment.is_none() {
+            Self::ensure_memo_allowed(&env, &config, &memo)?;
+            Self::validate_recipient(&env, &recipient)?;
+            Self::validate_transfer_target(&env, &recipient, &token_addr)?;
+        }
+        Self::ensure_token_contract(&env, &token_addr)?;
+
+        // 5. Velocity Limit Check (Sliding Window)
+        if !storage::check_and_update_velocity(
+            &env,
+            &proposer,
+            &config.velocity_limit,
+            VelocityAction::Propose,
+        ) {
+            return Err(VaultError::VelocityLimitExceeded);
+        }
+
+        // 6. Validate amount
+        if amount <= 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+
+        // 7-9. Check per-proposal, daily and weekly limits with reputation boost
+        let rep = Self::check_spending_limits(&env, &config, &proposer, amount)?;
+
+        // 10. Insurance check and locking
+        let insurance_config = storage::get_insurance_config(&env);
+        let min_required = if commitment.is_none() && storage::is_insurance_exempt(&env, &recipient)
+        {
+            0
+        } else {
+            Self::required_insurance(&env, &insurance_config, &token_addr, amount, &rep)?
+        };
+        // Committed transfers lock the minimum on the proposer's behalf
+        let insurance_amount = if commitment.is_some() {
+            min_required
+        } else {
+            insurance_amount
+        };
+        if insurance_amount < min_required {
+            return Err(VaultError::InsuranceInsufficient);
+        }
+        let actual_insurance = insurance_amount.max(0);
+
+        // Lock insurance tokens in vault
+        if actual_insurance > 0 {
+            token::transfer_to_vault(&env, &token_addr, &proposer, actual_insurance);
+        }
+
+        // 10b. Staking check and locking
+        let staking_config = storage::get_staking_config(&env);
+        let actual_stake = Self::required_stake(&staking_config, amount, &rep);
+        if actual_stake > 0 {
+            token::transfer_to_vault(&env, &token_addr, &proposer, actual_stake);
+        }
+
+        // 11. Reserve spending (confirmed on execution)
+        Self::reserve_spending(&env, amount);
+
+        // 12. Determine timelock
+        let current_ledger = env.ledger().sequence() as u64;
+        let unlock_ledger = if amount >= config.timelock_threshold {
+            current_ledger + config.timelock_delay
         } else {
             0
         };
 
-        for i in 0..transfers.len() {
-            let transfer = transfers.get(i).unwrap();
-            let proposal_id = storage::increment_proposal_id(&env);
+        // 13. Validate execution_time if provided
+        if let Some(exec_time) = execution_time {
+            Self::validate_execution_time(exec_time, current_ledger, unlock_ledger)?;
+        }
+
+        // 14. Create and store the proposal
+        storage::track_token(&env, &token_addr);
+        let proposal_id = storage::increment_proposal_id(&env);
+        Self::validate_dependencies(&env, proposal_id, &depends_on)?;
+        Self::reserve_category_budget(&env, &category, proposal_id, amount)?;
+        storage::log_daily_proposal(&env, storage::get_day_number(&env), proposal_id);
+
+        // Create stake record after proposal_id is generated
+        if actual_stake > 0 {
+            let stake_record = types::StakeRecord {
+                proposal_id,
+                staker: proposer.clone(),
+                token: token_addr.clone(),
+                amount: actual_stake,
+                locked_at: current_ledger,
+                refunded: false,
+                slashed: false,
+                slashed_amount: 0,
+                released_at: 0,
+            };
+            storage::set_stake_record(&env, &stake_record);
+        }
+
+        // Gas limit: derive from GasConfig (0 = unlimited)
+        let gas_cfg = storage::get_gas_config(&env);
+        let proposal_gas_limit = if gas_cfg.enabled {
+            gas_cfg.default_gas_limit
+        } else {
+            0
+        };
+
+        let mut proposal = Proposal {
+            id: proposal_id,
+            proposer: proposer.clone(),
+            recipient: recipient.clone(),
+            token: token_addr.clone(),
+            amount,
+            memo,
+            metadata: Map::new(&env),
+            tags: Vec::new(&env),
+            approvals: Vec::new(&env),
+            abstentions: Vec::new(&env),
+            attachments: Vec::new(&env),
+            status: ProposalStatus::Pending,
+            priority: priority.clone(),
+            conditions: conditions.clone(),
+            condition_logic,
+            created_at: current_ledger,
+            expires_at: current_ledger + PROPOSAL_EXPIRY_LEDGERS,
+            unlock_ledger,
+            execution_time,
+            insurance_amount: actual_insurance,
+            stake_amount: actual_stake,
+            gas_limit: proposal_gas_limit,
+            gas_used: 0,
+            snapshot_ledger: current_ledger,
+            snapshot_signers: config.signers.clone(),
+            depends_on: depends_on.clone(),
+            is_swap: false,
+            voting_deadline: if config.default_voting_deadline > 0 {
+                current_ledger + config.default_voting_deadline
+            } else {
+                0
+            },
+        };
+        Self::apply_auto_tags(&env, &mut proposal, &category);
+
+        storage::set_proposal(&env, &proposal);
+        Self::record_proposal_created(&env, &proposal);
+        storage::set_executor_tip(&env, proposal_id, Self::default_executor_tip(&env));
+        storage::set_vote_snapshot(
+            &env,
+            proposal_id,
+            Self::calculate_threshold(&config, &amount),
+            config.quorum,
+        );
+        if let Some(commitment) = &commitment {
+            storage::set_commitment(&env, proposal_id, commitment);
+        }
+        Self::persist_execution_fee_estimate(&env, &proposal);
+        storage::add_to_priority_queue(&env, priority as u32, proposal_id);
+
+        // Extend TTL to ensure persistent data stays alive
+        storage::extend_instance_ttl(&env);
+
+        // Create audit entry
+        storage::create_audit_entry(&env, AuditAction::ProposeTransfer, &proposer, proposal_id);
+        // 13. Emit events
+        // 15. Emit events
+        if actual_insurance > 0 {
+            events::emit_insurance_locked(
+                &env,
+                proposal_id,
+                &proposer,
+                actual_insurance,
+                &token_addr,
+            );
+        }
+        if actual_stake > 0 {
+            events::emit_stake_locked(&env, proposal_id, &proposer, actual_stake, &token_addr);
+        }
+        events::emit_proposal_created(&env, &proposal);
+        Self::notify_signers_of_proposal(&env, &config, &proposer);
+
+        // Update reputation for creating proposal
+        Self::update_reputation_on_propose(&env, &proposer);
+        storage::metrics_on_proposal(&env);
+
+        Ok(proposal_id)
+    }
+
+    /// Propose multiple transfers in a single batch, supporting multiple token types.
+    ///
+    /// Creates separate proposals for each transfer, enabling complex treasury operations
+    /// like portfolio rebalancing with atomic multi-token transfers.
+    ///
+    /// # Arguments
+    /// * `proposer` - The address initiating the proposals (must authorize).
+    /// * `transfers` - Vector of transfer details (recipient, token, amount, memo).
+    /// * `priority` - Urgency level applied to all proposals.
+    /// * `conditions` - Optional execution conditions applied to all proposals.
+    /// * `condition_logic` - And/Or logic for combining conditions.
+    /// * `insurance_amount` - Total insurance staked across all proposals.
+    ///
+    /// # Returns
+    /// Vector of proposal IDs created.
+    #[allow(clippy::too_many_arguments)]
+    pub fn batch_propose_transfers(
+        env: Env,
+        proposer: Address,
+        transfers: Vec<TransferDetails>,
+        priority: Priority,
+        conditions: Vec<Condition>,
+        condition_logic: ConditionLogic,
+        insurance_amount: i128,
+    ) -> Result<Vec<u64>, VaultError> {
+        let mut memos = Vec::new(&env);
+        for _ in 0..transfers.len() {
+            memos.push_back(Symbol::new(&env, "batch"));
+        }
+        Self::batch_propose_internal(
+            env,
+            proposer,
+            transfers,
+            memos,
+            priority,
+            conditions,
+            condition_logic,
+            insurance_amount,
+        )
+    }
+
+    /// Create one proposal per payroll-style entry from a template.
+    ///
+    /// Each entry's amount must fall within the template bounds and each
+    /// recipient must pass the recipient lists. Limits are checked once over
+    /// the summed amount and velocity is charged once, as in
+    /// `batch_propose_transfers`. Any invalid entry fails the whole batch.
+    ///
+    /// # Arguments
+    /// * `entries` - 1 to `MAX_BATCH_SIZE` recipients, amounts and optional memos.
+    pub fn batch_create_from_template(
+        env: Env,
+        proposer: Address,
+        template_id: u64,
+        entries: Vec<TemplateBatchEntry>,
+    ) -> Result<Vec<u64>, VaultError> {
+        let template = storage::get_template(&env, template_id)?;
+        if !template.is_active {
+            return Err(VaultError::TemplateInactive);
+        }
+        if entries.is_empty() {
+            return Err(VaultError::InvalidAmount);
+        }
+        if entries.len() > MAX_BATCH_SIZE {
+            return Err(VaultError::BatchTooLarge);
+        }
+        Self::ensure_token_contract(&env, &template.token)?;
+
+        let mut transfers = Vec::new(&env);
+        let mut memos = Vec::new(&env);
+        for entry in entries.iter() {
+            if template.min_amount > 0 && entry.amount < template.min_amount {
+                return Err(VaultError::TemplateValidationFailed);
+            }
+            if template.max_amount > 0 && entry.amount > template.max_amount {
+                return Err(VaultError::TemplateValidationFailed);
+            }
+            Self::validate_recipient(&env, &entry.recipient)?;
+            Self::validate_transfer_target(&env, &entry.recipient, &template.token)?;
+            transfers.push_back(TransferDetails {
+                recipient: entry.recipient,
+                token: template.token.clone(),
+                amount: entry.amount,
+            });
+            memos.push_back(entry.memo_override.unwrap_or(template.memo.clone()));
+        }
+
+        let proposal_ids = Self::batch_propose_internal(
+            env.clone(),
+            proposer.clone(),
+            transfers,
+            memos,
+            Priority::Normal,
+            Vec::new(&env),
+            ConditionLogic::And,
+            0,
+        )?;
+        for proposal_id in proposal_ids.iter() {
+            Self::apply_template_tags(&env, proposal_id, &template)?;
+            events::emit_proposal_from_template(
+                &env,
+                proposal_id,
+                template_id,
+                &template.name,
+                &proposer,
+            );
+        }
+
+        Ok(proposal_ids)
+    }
+
+    /// Shared body of the batch creation entry points; `memos[i]` is the memo
+    /// of the proposal created for `transfers[i]`.
+    #[allow(clippy::too_many_arguments)]
+    fn batch_propose_internal(
+        env: Env,
+        proposer: Address,
+        transfers: Vec<TransferDetails>,
+        memos: Vec<Symbol>,
+        priority: Priority,
+        conditions: Vec<Condition>,
+        condition_logic: ConditionLogic,
+        insurance_amount: i128,
+    ) -> Result<Vec<u64>, VaultError> {
+        proposer.require_auth();
+
+        if transfers.len() > MAX_BATCH_SIZE {
+            return Err(VaultError::BatchTooLarge);
+        }
+
+        let config = storage::get_config(&env)?;
+        let role = storage::get_role(&env, &proposer);
+        if role != Role::Treasurer && role != Role::Admin {
+            return Err(VaultError::InsufficientRole);
+        }
+        Self::check_pending_cap(&env, &config, &proposer, transfers.len())?;
+
+        // Velocity check once for the batch
+        if !storage::check_and_update_velocity(
+            &env,
+            &proposer,
+            &config.velocity_limit,
+            VelocityAction::Propose,
+        ) {
+            return Err(VaultError::VelocityLimitExceeded);
+        }
+
+        let today = storage::get_day_number(&env);
+        let week = storage::get_week_number(&env);
+        let mut total_amount = 0i128;
+        let mut insured_amount = 0i128;
+        let mut token_amounts: Vec<(Address, i128)> = Vec::new(&env);
+
+        // Pre-validate all transfers and calculate totals per token
+        for i in 0..transfers.len() {
+            let transfer = transfers.get(i).unwrap();
+
+            if transfer.amount <= 0 {
+                return Err(VaultError::InvalidAmount);
+            }
+            if transfer.amount > config.spending_limit {
+                return Err(VaultError::ExceedsProposalLimit);
+            }
+            // Same per-recipient and token checks as a single proposal
+            Self::validate_recipient(&env, &transfer.recipient)?;
+            Self::validate_transfer_target(&env, &transfer.recipient, &transfer.token)?;
+            Self::ensure_token_contract(&env, &transfer.token)?;
+
+            total_amount += transfer.amount;
+            if !storage::is_insurance_exempt(&env, &transfer.recipient) {
+                insured_amount += transfer.amount;
+            }
+
+            // Track per-token amounts
+            let mut found = false;
+            for j in 0..token_amounts.len() {
+                let mut entry = token_amounts.get(j).unwrap();
+                if entry.0 == transfer.token {
+                    entry.1 += transfer.amount;
+                    token_amounts.set(j, entry);
+                    found = true;
+                    break;
+                }
+            }
+            if !found {
+                token_amounts.push_back((transfer.token.clone(), transfer.amount));
+            }
+        }
+
+        // Check aggregate limits
+        let spent_today = storage::get_daily_spent(&env, today);
+        if spent_today + total_amount > config.daily_limit {
+            return Err(VaultError::ExceedsDailyLimit);
+        }
+
+        let spent_week = storage::get_weekly_spent(&env, week);
+        if spent_week + total_amount > config.weekly_limit {
+            return Err(VaultError::ExceedsWeeklyLimit);
+        }
+
+        // Handle insurance (transfers to exempt recipients are not insured)
+        let insurance_config = storage::get_insurance_config(&env);
+        let rep = storage::get_reputation(&env, &proposer);
+        let first_token = transfers.get(0).unwrap().token;
+        let min_required =
+            Self::required_insurance(&env, &insurance_config, &first_token, insured_amount, &rep)?;
+        if insurance_amount < min_required {
+            return Err(VaultError::InsuranceInsufficient);
+        }
+        let actual_insurance = insurance_amount.max(0);
+
+        // Lock insurance if required (use first token in batch); every proposal
+        // records this token so refunds and slashes use what was actually locked
+        let insurance_token = transfers.get(0).map(|t| t.token);
+        if actual_insurance > 0 {
+            if let Some(first_token) = &insurance_token {
+                token::transfer_to_vault(&env, first_token, &proposer, actual_insurance);
+            }
+        }
+
+        // Reserve spending
+        storage::add_daily_spent(&env, today, total_amount);
+        storage::add_weekly_spent(&env, week, total_amount);
+
+        // Gas limit: derive from GasConfig (0 = unlimited)
+        let gas_cfg = storage::get_gas_config(&env);
+        let proposal_gas_limit = if gas_cfg.enabled {
+            gas_cfg.default_gas_limit
+        } else {
+            0
+        };
+
+        // Create proposals
+        let current_ledger = env.ledger().sequence() as u64;
+        let mut proposal_ids = Vec::new(&env);
+        let insurance_per_proposal = if !transfers.is_empty() {
+            actual_insurance / transfers.len() as i128
+        } else {
+            0
+        };
+        // The first proposal carries the division remainder so nothing is stranded
+        let insurance_dust = actual_insurance - insurance_per_proposal * transfers.len() as i128;
+
+        for i in 0..transfers.len() {
+            let transfer = transfers.get(i).unwrap();
+            storage::track_token(&env, &transfer.token);
+            let proposal_id = storage::increment_proposal_id(&env);
+            Self::reserve_category_budget(&env, &None, proposal_id, transfer.amount)?;
+            storage::log_daily_proposal(&env, today, proposal_id);
+            let proposal_insurance = if i == 0 {
+                insurance_per_proposal + insurance_dust
+            } else {
+                insurance_per_proposal
+            };
+
+            let mut proposal = Proposal {
+                id: proposal_id,
+                proposer: proposer.clone(),
+                recipient: transfer.recipient.clone(),
+                token: transfer.token.clone(),
+                amount: transfer.amount,
+                memo: memos.get(i).unwrap(),
+                metadata: Map::new(&env),
+                tags: Vec::new(&env),
+                approvals: Vec::new(&env),
+                abstentions: Vec::new(&env),
+                attachments: Vec::new(&env),
+                status: ProposalStatus::Pending,
+                priority: priority.clone(),
+                conditions: conditions.clone(),
+                condition_logic: condition_logic.clone(),
+                created_at: current_ledger,
+                expires_at: calculate_expiration_ledger(&config, &priority, current_ledger),
+                unlock_ledger: 0,
+                execution_time: None,
+                insurance_amount: proposal_insurance,
+                stake_amount: 0, // Batch proposals don't require individual stakes
+                gas_limit: proposal_gas_limit,
+                gas_used: 0,
+                snapshot_ledger: current_ledger,
+                snapshot_signers: config.signers.clone(),
+                depends_on: Vec::new(&env),
+                is_swap: false,
+                voting_deadline: if config.default_voting_deadline > 0 {
+                    current_ledger + config.default_voting_deadline
+                } else {
+                    0
+                },
+            };
+            Self::apply_auto_tags(&env, &mut proposal, &None);
+
+            if let Some(first_token) = &insurance_token {
+                if proposal_insurance > 0 && *first_token != proposal.token {
+                    storage::set_insurance_token(&env, proposal_id, first_token);
+                }
+            }
+            storage::set_proposal(&env, &proposal);
+            Self::record_proposal_created(&env, &proposal);
+            storage::set_executor_tip(&env, proposal_id, Self::default_executor_tip(&env));
+            storage::set_vote_snapshot(
+                &env,
+                proposal_id,
+                Self::calculate_threshold(&config, &transfer.amount),
+                config.quorum,
+            );
+            Self::persist_execution_fee_estimate(&env, &proposal);
+            storage::add_to_priority_queue(&env, priority.clone() as u32, proposal_id);
+            proposal_ids.push_back(proposal_id);
+
+            events::emit_proposal_created(&env, &proposal);
+            Self::notify_signers_of_proposal(&env, &config, &proposer);
+        }
+
+        storage::extend_instance_ttl(&env);
+
+        if actual_insurance > 0 {
+            let first_token = transfers.get(0).unwrap().token;
+            events::emit_insurance_locked(
+                &env,
+                proposal_ids.get(0).unwrap(),
+                &proposer,
+                actual_insurance,
+                &first_token,
+            );
+        }
+
+        Self::update_reputation_on_propose(&env, &proposer);
+        for _ in 0..proposal_ids.len() {
+            storage::metrics_on_proposal(&env);
+        }
+
+        Ok(proposal_ids)
+    }
+
+    /// Approve a pending proposal.
+    ///
+    /// Approval requires `require_auth()` from a valid signer.
+    /// When the threshold is reached AND quorum is satisfied, the status changes to `Approved`.
+    /// If the amount exceeds the `timelock_threshold`, an `unlock_ledger` is calculated.
+    ///
+    /// Quorum = approvals + abstentions. The approval threshold is checked only against
+    /// explicit approvals. Both must be satisfied to transition to `Approved`.
+    ///
+    /// Supports delegation: if the signer has delegated their voting power, the vote
+    /// is recorded under the effective voter (following the delegation chain).
+    ///
+    /// # Arguments
+    /// * `signer` - The authorized address providing approval.
+    /// * `proposal_id` - ID of the proposal to approve.
+    pub fn approve_proposal(env: Env, signer: Address, proposal_id: u64) -> Result<(), VaultError> {
+        // Verify identity - CRITICAL for security
+        signer.require_auth();
+        Self::approve_proposal_internal(env, signer, proposal_id, None)
+    }
+
+    /// Approve a pending proposal with a short justification.
+    ///
+    /// Behaves like `approve_proposal`; the note is stored per approver and
+    /// emitted alongside the approval.
+    pub fn approve_with_note(
+        env: Env,
+        signer: Address,
+        proposal_id: u64,
+        note: Symbol,
+    ) -> Result<(), VaultError> {
+        signer.require_auth();
+        Self::approve_proposal_internal(env, signer, proposal_id, Some(note))
+    }
+
+    /// Approval justifications recorded for a proposal, keyed by approver.
+    pub fn get_approval_notes(env: Env, proposal_id: u64) -> Map<Address, Symbol> {
+        storage::get_approval_notes(&env, proposal_id)
+    }
+
+    /// Require a note on approvals of proposals at or above the timelock threshold.
+    ///
+    /// When enabled, plain `approve_proposal` fails for such proposals and
+    /// signers must use `approve_with_note`. Admin only.
+    pub fn set_approval_note_required(
+        env: Env,
+        admin: Address,
+        required: bool,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+
+        storage::set_approval_note_required(&env, required);
+        storage::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Whether approvals of timelock-sized proposals must carry a note.
+    pub fn get_approval_note_required(env: Env) -> bool {
+        storage::get_approval_note_required(&env)
+    }
+
+    /// Choose whether a proposer may approve their own proposal.
+    ///
+    /// Enabled by default. When disabled, the proposer cannot approve (they may
+    /// still abstain for quorum) and any earlier self-approval no longer counts
+    /// toward the threshold. Admin only.
+    pub fn set_proposer_can_approve(
+        env: Env,
+        admin: Address,
+        allowed: bool,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+
+        storage::set_proposer_can_approve(&env, allowed);
+        storage::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Whether proposers may approve their own proposals.
+    pub fn get_proposer_can_approve(env: Env) -> bool {
+        storage::get_proposer_can_approve(&env)
+    }
+
+    /// Group related pending proposals so signers can approve them with one vote.
+    ///
+    /// Up to `MAX_BUNDLE_SIZE` Pending proposals owned by `proposer`; a proposal
+    /// belongs to at most one bundle. Members are detached once they execute or
+    /// otherwise reach a terminal status.
+    pub fn create_bundle(
+        env: Env,
+        proposer: Address,
+        proposal_ids: Vec<u64>,
+    ) -> Result<u64, VaultError> {
+        proposer.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        if proposal_ids.is_empty() || proposal_ids.len() > MAX_BUNDLE_SIZE {
+            return Err(VaultError::BatchTooLarge);
+        }
+
+        for (i, proposal_id) in proposal_ids.iter().enumerate() {
+            let proposal = storage::get_proposal(&env, proposal_id)?;
+            if proposal.proposer != proposer {
+                return Err(VaultError::Unauthorized);
+            }
+            if proposal.status != ProposalStatus::Pending {
+                return Err(VaultError::ProposalNotPending);
+            }
+            if storage::get_proposal_bundle(&env, proposal_id).is_some()
+                || proposal_ids.first_index_of(proposal_id) != Some(i as u32)
+            {
+                return Err(VaultError::AddressAlreadyOnList);
+            }
+        }
+
+        let bundle_id = storage::increment_bundle_id(&env);
+        let bundle = ProposalBundle {
+            id: bundle_id,
+            proposer: proposer.clone(),
+            proposal_ids: proposal_ids.clone(),
+            created_at: env.ledger().sequence() as u64,
+        };
+        storage::set_bundle(&env, &bundle);
+        for proposal_id in proposal_ids.iter() {
+            storage::set_proposal_bundle(&env, proposal_id, bundle_id);
+        }
+        storage::extend_instance_ttl(&env);
+
+        events::emit_bundle_created(&env, bundle_id, &proposer, &proposal_ids);
+
+        Ok(bundle_id)
+    }
+
+    /// Approve every remaining member of a bundle.
+    ///
+    /// Each member goes through the same checks as `approve_proposal`
+    /// (snapshot, double vote, caps, deadlines). Under `SkipAndReport` members
+    /// that fail are skipped and their IDs returned; under `AllOrNothing` the
+    /// first failure aborts the whole call. Members whose vote has lapsed are
+    /// skipped untouched and left to the usual keeper cleanup.
+    pub fn approve_bundle(
+        env: Env,
+        signer: Address,
+        bundle_id: u64,
+    ) -> Result<Vec<u64>, VaultError> {
+        signer.require_auth();
+
+        let bundle = storage::get_bundle(&env, bundle_id)?;
+        let mode = storage::get_bundle_approval_mode(&env);
+
+        let mut approved = Vec::new(&env);
+        let mut skipped = Vec::new(&env);
+        for proposal_id in bundle.proposal_ids.iter() {
+            // A lapsed member would be closed out by the vote below; a skipped
+            // failure must not leave that write behind.
+            if mode == BundleApprovalMode::SkipAndReport
+                && Self::vote_lapsed(&env, &storage::get_proposal(&env, proposal_id)?)
+            {
+                skipped.push_back(proposal_id);
+                continue;
+            }
+            match Self::approve_proposal_internal(env.clone(), signer.clone(), proposal_id, None) {
+                Ok(()) => approved.push_back(proposal_id),
+                Err(err) => {
+                    if mode == BundleApprovalMode::AllOrNothing {
+                        return Err(err);
+                    }
+                    skipped.push_back(proposal_id);
+                }
+            }
+        }
+
+        events::emit_bundle_approved(&env, bundle_id, &signer, &approved, &skipped);
+
+        Ok(skipped)
+    }
+
+    /// Get a bundle and its remaining members.
+    pub fn get_bundle(env: Env, bundle_id: u64) -> Result<ProposalBundle, VaultError> {
+        storage::get_bundle(&env, bundle_id)
+    }
+
+    /// Choose how `approve_bundle` treats members the signer cannot approve.
+    ///
+    /// Defaults to `SkipAndReport`. Admin only.
+    pub fn set_bundle_approval_mode(
+        env: Env,
+        admin: Address,
+        mode: BundleApprovalMode,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+
+        storage::set_bundle_approval_mode(&env, &mode);
+        storage::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Current `approve_bundle` failure handling.
+    pub fn get_bundle_approval_mode(env: Env) -> BundleApprovalMode {
+        storage::get_bundle_approval_mode(&env)
+    }
+
+    fn approve_proposal_internal(
+        env: Env,
+        signer: Address,
+        proposal_id: u64,
+        note: Option<Symbol>,
+    ) -> Result<(), VaultError> {
+        // Get config and validate signer
+        let config = storage::get_config(&env)?;
+        if !config.signers.contains(&signer) {
+            return Err(VaultError::NotASigner);
+        }
+
+        // Check permission
+
+        // Get proposal
+        let mut proposal = storage::get_proposal(&env, proposal_id)?;
+
+        // Snapshot check: voter must have been a signer at proposal creation
+        if !proposal.snapshot_signers.contains(&signer) {
+            return Err(VaultError::VoterNotInSnapshot);
+        }
+
+        // Resolve delegation chain to get effective voter
+        let effective_voter = Self::resolve_delegation_chain(&env, &signer, 0);
+        let is_delegated = effective_voter != signer;
+
+        // Validate state. Timelocked Critical proposals keep collecting
+        // approvals so their signers can reach the timelock bypass.
+        let was_pending = proposal.status == ProposalStatus::Pending;
+        if !was_pending && !Self::timelock_bypass_open(&env, &proposal) {
+            return Err(VaultError::ProposalNotPending);
+        }
+
+        // Prevent double-approval or abstaining then approving (check effective voter)
+        if proposal.approvals.contains(&effective_voter)
+            || proposal.abstentions.contains(&effective_voter)
+        {
+            return Err(VaultError::AlreadyApproved);
+        }
+
+        // Self-approval restriction (no dedicated error variant left)
+        if !storage::get_proposer_can_approve(&env)
+            && (signer == proposal.proposer || effective_voter == proposal.proposer)
+        {
+            return Err(VaultError::Unauthorized);
+        }
+
+        // Junior signers may only approve proposals up to their cap
+        let cap = storage::get_proposal_approval_cap(&env, proposal_id, &effective_voter);
+        if cap > 0 && proposal.amount > cap {
+            return Err(VaultError::InsufficientRole);
+        }
+
+        // Hierarchical sign-off: earlier stages approve first (no dedicated error variant left)
+        if !Self::approval_stage(&env, &proposal, &effective_voter).1 {
+            return Err(VaultError::ConditionsNotMet);
+        }
+
+        // Large approvals may need a justification (no dedicated error variant left)
+        if note.is_none()
+            && storage::get_approval_note_required(&env)
+            && proposal.amount >= config.timelock_threshold
+        {
+            return Err(VaultError::ConditionsNotMet);
+        }
+
+        // Check expiration (only if expiration is enabled, i.e., expires_at > 0)
+        let current_ledger = env.ledger().sequence() as u64;
+        if proposal.expires_at > 0 && current_ledger > proposal.expires_at {
+            Self::transition_status(
+                &env,
+                &mut proposal,
+                ProposalStatus::Expired,
+                &signer,
+                "expired",
+            );
+            storage::set_proposal(&env, &proposal);
+            storage::metrics_on_expiry(&env);
+            events::emit_proposal_expired(&env, proposal_id, proposal.expires_at);
+            return Err(VaultError::ProposalExpired);
+        }
+
+        // Check voting deadline
+        if was_pending && proposal.voting_deadline > 0 && current_ledger > proposal.voting_deadline
+        {
+            Self::transition_status(
+                &env,
+                &mut proposal,
+                ProposalStatus::Rejected,
+                &signer,
+                "deadline",
+            );
+            storage::set_proposal(&env, &proposal);
+            storage::metrics_on_rejection(&env);
+            events::emit_proposal_deadline_rejected(&env, proposal_id, proposal.voting_deadline);
+            return Err(VaultError::VotingDeadlinePassed);
+        }
+
+        // Add approval using effective voter
+        proposal.approvals.push_back(effective_voter.clone());
+        let current_ledger = env.ledger().sequence() as u64;
+        storage::set_approval_ledger(&env, proposal_id, &signer, current_ledger);
+
+        // Emit delegated vote event if voting through delegation
+        if is_delegated {
+            events::emit_delegated_vote(&env, proposal_id, &effective_voter, &signer);
+        }
+
+        // Calculate current vote totals
+        let approval_count = proposal.approvals.len();
+        let quorum_votes = approval_count + proposal.abstentions.len();
+        let previous_quorum_votes = quorum_votes.saturating_sub(1);
+        let quorum = Self::required_quorum(&env, &config, &proposal);
+        let was_quorum_reached = quorum == 0 || previous_quorum_votes >= quorum;
+
+        // Check if threshold met AND quorum satisfied
+        let threshold_reached = Self::is_threshold_reached(&env, &config, &proposal);
+        let quorum_reached = quorum == 0 || quorum_votes >= quorum;
+        if quorum > 0 && !was_quorum_reached && quorum_reached {
+            events::emit_quorum_reached(&env, proposal_id, quorum_votes, quorum);
+        }
+
+        if was_pending && threshold_reached && quorum_reached {
+            let limits = Self::freeze_approval_limits(&env, &config, &proposal);
+
+            // Check if proposal has execution_time (scheduled)
+            if proposal.execution_time.is_some() {
+                // Transition to Scheduled status
+                Self::transition_status(
+                    &env,
+                    &mut proposal,
+                    ProposalStatus::Scheduled,
+                    &signer,
+                    "approve",
+                );
+                events::emit_proposal_scheduled(
+                    &env,
+                    proposal_id,
+                    proposal.execution_time.unwrap(),
+                    current_ledger,
+                );
+            } else {
+                // Immediate execution - transition to Approved
+                Self::transition_status(
+                    &env,
+                    &mut proposal,
+                    ProposalStatus::Approved,
+                    &signer,
+                    "approve",
+                );
+
+                // Check for Timelock
+                if proposal.amount >= config.timelock_threshold {
+                    let current_ledger = env.ledger().sequence() as u64;
+                    proposal.unlock_ledger = current_ledger + config.timelock_delay;
+                } else {
+                    proposal.unlock_ledger = 0;
+                }
+                let execute_by = Self::start_execution_window(&env, &proposal, current_ledger);
+
+                events::emit_proposal_ready(
+                    &env,
+                    proposal_id,
+                    proposal.unlock_ledger,
+                    limits.rep_boosted,
+                    (
+                        limits.spending_limit,
+                        limits.daily_limit,
+                        limits.weekly_limit,
+                    ),
+                    execute_by,
+                );
+            }
+        }
+
+        if Self::timelock_bypass_open(&env, &proposal)
+            && Self::timelock_bypass_reached(&env, &proposal)
+        {
+            proposal.unlock_ledger = 0;
+            events::emit_timelock_bypassed(&env, proposal_id, &proposal.approvals);
+        }
+
+        storage::set_proposal(&env, &proposal);
+        storage::extend_instance_ttl(&env);
+
+        // Create audit entry
+        storage::create_audit_entry(&env, AuditAction::ApproveProposal, &signer, proposal_id);
+
+        // Emit event
+        events::emit_proposal_approved(
+            &env,
+            proposal_id,
+            &effective_voter,
+            approval_count,
+            Self::required_threshold(&env, &config, &proposal),
+        );
+        Self::notify(&env, &proposal.proposer, "approval", |prefs| {
+            prefs.notify_on_approval
+        });
+        if let Some(note) = note {
+            storage::set_approval_note(&env, proposal_id, &effective_voter, &note);
+            events::emit_approval_note(&env, proposal_id, &effective_voter, &note);
+        }
+
+        // Reputation boost for approving (credit the effective voter)
+        Self::update_reputation_on_approval(&env, &effective_voter);
+
+        Ok(())
+    }
+
+    /// Abstain from a pending proposal explicitly.
+    ///
+    /// The signer's vote counts towards the quorum but does not contribute
+    /// to the total approvals required to meet the threshold.
+    ///
+    /// # Arguments
+    /// * `signer` - The authorized address providing the abstention.
+    /// * `proposal_id` - ID of the proposal to abstain from.
+    pub fn abstain_proposal(env: Env, signer: Address, proposal_id: u64) -> Result<(), VaultError> {
+        // Verify identity
+        signer.require_auth();
+
+        // Get config and validate signer
+        let config = storage::get_config(&env)?;
+        if !config.signers.contains(&signer) {
+            return Err(VaultError::NotASigner);
+        }
+
+        // Get proposal
+        let mut proposal = storage::get_proposal(&env, proposal_id)?;
+
+        // Snapshot check: voter must have been a signer at proposal creation
+        if !proposal.snapshot_signers.contains(&signer) {
+            return Err(VaultError::VoterNotInSnapshot);
+        }
+
+        // Resolve delegation chain to get effective voter
+        let effective_voter = Self::resolve_delegation_chain(&env, &signer, 0);
+        let is_delegated = effective_voter != signer;
+
+        // Validate state
+        if proposal.status != ProposalStatus::Pending {
+            return Err(VaultError::ProposalNotPending);
+        }
+
+        // Prevent double-abstaining or approving then abstaining
+        if proposal.approvals.contains(&effective_voter)
+            || proposal.abstentions.contains(&effective_voter)
+        {
+            return Err(VaultError::AlreadyApproved);
+        }
+
+        // Check expiration
+        let current_ledger = env.ledger().sequence() as u64;
+        if proposal.expires_at > 0 && current_ledger > proposal.expires_at {
+            Self::transition_status(
+                &env,
+                &mut proposal,
+                ProposalStatus::Expired,
+                &signer,
+                "expired",
+            );
+            storage::set_proposal(&env, &proposal);
+            storage::metrics_on_expiry(&env);
+            events::emit_proposal_expired(&env, proposal_id, proposal.expires_at);
+            return Err(VaultError::ProposalExpired);
+        }
+
+        // Check voting deadline
+        if proposal.voting_deadline > 0 && current_ledger > proposal.voting_deadline {
+            Self::transition_status(
+                &env,
+                &mut proposal,
+                ProposalStatus::Rejected,
+                &signer,
+                "deadline",
+            );
+            storage::set_proposal(&env, &proposal);
+            storage::metrics_on_rejection(&env);
+            events::emit_proposal_deadline_rejected(&env, proposal_id, proposal.voting_deadline);
+            return Err(VaultError::VotingDeadlinePassed);
+        }
+
+        // Add abstention using effective voter
+        proposal.abstentions.push_back(effective_voter.clone());
+
+        // Emit delegated vote event if voting through delegation
+        if is_delegated {
+            events::emit_delegated_vote(&env, proposal_id, &effective_voter, &signer);
+        }
+
+        // Calculate current vote totals
+        let approval_count = proposal.approvals.len();
+        let abstention_count = proposal.abstentions.len();
+        let quorum_votes = approval_count + abstention_count;
+        let previous_quorum_votes = quorum_votes.saturating_sub(1);
+        let quorum = Self::required_quorum(&env, &config, &proposal);
+        let was_quorum_reached = quorum == 0 || previous_quorum_votes >= quorum;
+
+        // Check if threshold met AND quorum satisfied
+        let threshold_reached = Self::is_threshold_reached(&env, &config, &proposal);
+        let quorum_reached = quorum == 0 || quorum_votes >= quorum;
+        if quorum > 0 && !was_quorum_reached && quorum_reached {
+            events::emit_quorum_reached(&env, proposal_id, quorum_votes, quorum);
+        }
+
+        if threshold_reached && quorum_reached {
+            let limits = Self::freeze_approval_limits(&env, &config, &proposal);
+            if proposal.execution_time.is_some() {
+                Self::transition_status(
+                    &env,
+                    &mut proposal,
+                    ProposalStatus::Scheduled,
+                    &signer,
+                    "abstain",
+                );
+                events::emit_proposal_scheduled(
+                    &env,
+                    proposal_id,
+                    proposal.execution_time.unwrap(),
+                    current_ledger,
+                );
+            } else {
+                Self::transition_status(
+                    &env,
+                    &mut proposal,
+                    ProposalStatus::Approved,
+                    &signer,
+                    "abstain",
+                );
+                if proposal.amount >= config.timelock_threshold {
+                    proposal.unlock_ledger = current_ledger + config.timelock_delay;
+                } else {
+                    proposal.unlock_ledger = 0;
+                }
+                let execute_by = Self::start_execution_window(&env, &proposal, current_ledger);
+                events::emit_proposal_ready(
+                    &env,
+                    proposal_id,
+                    proposal.unlock_ledger,
+                    limits.rep_boosted,
+                    (
+                        limits.spending_limit,
+                        limits.daily_limit,
+                        limits.weekly_limit,
+                    ),
+                    execute_by,
+                );
+            }
+        }
+
+        storage::set_proposal(&env, &proposal);
+        storage::extend_instance_ttl(&env);
+
+        // Create audit entry
+        storage::create_audit_entry(&env, AuditAction::AbstainProposal, &signer, proposal_id);
+
+        // Emit event
+        events::emit_proposal_abstained(
+            &env,
+            proposal_id,
+            &effective_voter,
+            abstention_count,
+            quorum_votes,
+        );
+
+        // Track participation for abstaining
+        Self::update_reputation_on_abstention(&env, &effective_voter);
+
+        Ok(())
+    }
+
+    /// Withdraw a previously cast abstention on a pending proposal.
+    ///
+    /// Removes the signer (or their effective delegate) from `abstentions`, which
+    /// lowers the quorum participation count. The signer may then approve normally.
+    pub fn withdraw_abstention(
+        env: Env,
+        signer: Address,
+        proposal_id: u64,
+    ) -> Result<(), VaultError> {
+        signer.require_auth();
+        Self::withdraw_abstention_internal(&env, &signer, proposal_id)
+    }
+
+    /// Convert an abstention into an approval in a single call.
+    ///
+    /// Equivalent to `withdraw_abstention` followed by `approve_proposal`, including
+    /// the threshold/quorum transition check.
+    pub fn convert_abstention_to_approval(
+        env: Env,
+        signer: Address,
+        proposal_id: u64,
+    ) -> Result<(), VaultError> {
+        signer.require_auth();
+        Self::withdraw_abstention_internal(&env, &signer, proposal_id)?;
+        Self::approve_proposal_internal(env, signer, proposal_id, None)
+    }
+
+    fn withdraw_abstention_internal(
+        env: &Env,
+        signer: &Address,
+        proposal_id: u64,
+    ) -> Result<(), VaultError> {
+        let config = storage::get_config(env)?;
+        if !config.signers.contains(signer) {
+            return Err(VaultError::NotASigner);
+        }
+
+        let mut proposal = storage::get_proposal(env, proposal_id)?;
+        if proposal.status != ProposalStatus::Pending {
+            return Err(VaultError::ProposalNotPending);
+        }
+
+        let effective_voter = Self::resolve_delegation_chain(env, signer, 0);
+        let index = proposal
+            .abstentions
+            .first_index_of(&effective_voter)
+            .ok_or(VaultError::Unauthorized)?;
+        proposal.abstentions.remove(index);
+
+        storage::set_proposal(env, &proposal);
+        storage::extend_instance_ttl(env);
+
+        let quorum_votes = proposal.approvals.len() + proposal.abstentions.len();
+        events::emit_abstention_withdrawn(env, proposal_id, &effective_voter, quorum_votes);
+
+        Ok(())
+    }
+
+    /// Give the post-timelock second confirmation a large proposal needs.
+    ///
+    /// Required for amounts of at least `second_look_multiple *
+    /// timelock_threshold`. The confirmer must be a current signer who did
+    /// not approve the proposal, and can only confirm once the timelock has
+    /// passed.
+    pub fn confirm_execution(
+        env: Env,
+        signer: Address,
+        proposal_id: u64,
+    ) -> Result<(), VaultError> {
+        signer.require_auth();
+
+        let config = storage::get_config(&env)?;
+        if !config.signers.contains(&signer) {
+            return Err(VaultError::NotASigner);
+        }
+        let proposal = storage::get_proposal(&env, proposal_id)?;
+        if proposal.status != ProposalStatus::Approved
+            && proposal.status != ProposalStatus::Scheduled
+        {
+            return Err(VaultError::ProposalNotApproved);
+        }
+        if !Self::second_look_required(&env, &config, &proposal) {
+            return Err(VaultError::ConditionsNotMet);
+        }
+        let current_ledger = env.ledger().sequence() as u64;
+        if proposal.unlock_ledger > 0 && current_ledger < proposal.unlock_ledger {
+            return Err(VaultError::TimelockNotExpired);
+        }
+        if storage::get_second_look(&env, proposal_id).is_some()
+            || proposal.approvals.contains(&signer)
+        {
+            return Err(VaultError::AlreadyApproved);
+        }
+
+        storage::set_second_look(&env, proposal_id, &signer);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_second_look_confirmed(&env, proposal_id, &signer);
+
+        Ok(())
+    }
+
+    /// Require a second confirmation for proposals of at least `multiple`
+    /// times the timelock threshold (0 disables it). Admin only.
+    pub fn set_second_look_multiple(
+        env: Env,
+        admin: Address,
+        multiple: u32,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+
+        storage::set_second_look_multiple(&env, multiple);
+        storage::extend_instance_ttl(&env);
 
-            let proposal = Proposal {
-                id: proposal_id,
-                proposer: proposer.clone(),
-                recipient: transfer.recipient.clone(),
-                token: transfer.token.clone(),
-                amount: transfer.amount,
-                memo: Symbol::new(&env, "batch"),
-                metadata: Map::new(&env),
-                tags: Vec::new(&env),
-                approvals: Vec::new(&env),
-                abstentions: Vec::new(&env),
-                attachments: Vec::new(&env),
-                status: ProposalStatus::Pending,
-                priority: priority.clone(),
-                conditions: conditions.clone(),
-                condition_logic: condition_logic.clone(),
-                created_at: current_ledger,
-                expires_at: calculate_expiration_ledger(&config, &priority, current_ledger),
-                unlock_ledger: 0,
-                execution_time: None,
-                insurance_amount: insurance_per_proposal,
-                stake_amount: 0, // Batch proposals don't require individual stakes
-                gas_limit: proposal_gas_limit,
-                gas_used: 0,
-                snapshot_ledger: current_ledger,
-                snapshot_signers: config.signers.clone(),
-                depends_on: Vec::new(&env),
-                is_swap: false,
-                voting_deadline: if config.default_voting_deadline > 0 {
-                    current_ledger + config.default_voting_deadline
-                } else {
-                    0
-                },
-            };
+        events::emit_config_updated(&env, &admin);
 
-            storage::set_proposal(&env, &proposal);
-            Self::persist_execution_fee_estimate(&env, &proposal);
-            storage::add_to_priority_queue(&env, priority.clone() as u32, proposal_id);
-            proposal_ids.push_back(proposal_id);
+        Ok(())
+    }
 
-            events::emit_proposal_created(
-                &env,
-                proposal_id,
-                &proposer,
-                &transfer.recipient,
-                &transfer.token,
-                transfer.amount,
-                insurance_per_proposal,
-            );
+    /// Multiple of the timelock threshold that triggers a second look (0 = off).
+    pub fn get_second_look_multiple(env: Env) -> u32 {
+        storage::get_second_look_multiple(&env)
+    }
+
+    /// Signer who gave a proposal's second confirmation, if any.
+    pub fn get_second_look(env: Env, proposal_id: u64) -> Option<Address> {
+        storage::get_second_look(&env, proposal_id)
+    }
+
+    /// Finalizes and executes an approved proposal.
+    ///
+    /// Can be called by anyone (even an automated tool) as long as:
+    /// 1. The proposal status is `Approved`.
+    /// 2. The required approvals threshold and quorum are still satisfied.
+    /// 3. Any applicable timelock has expired.
+    /// 4. The vault has sufficient balance of the target token.
+    ///
+    /// A proposal whose execution window has lapsed is moved to `Expired`
+    /// and its deposits refunded; the call returns `Ok` so that persists.
+    ///
+    /// Rollback behavior:
+    /// - A snapshot of execution-critical state is recorded before transfer.
+    /// - If transfer fails, proposal and queue state are restored from snapshot.
+    /// - A rollback event is emitted with the failure reason code.
+    ///
+    /// # Arguments
+    /// * `executor` - The address triggering the final transfer (must authorize).
+    /// * `proposal_id` - ID of the proposal to execute.
+    pub fn execute_proposal(
+        env: Env,
+        executor: Address,
+        proposal_id: u64,
+    ) -> Result<(), VaultError> {
+        // Executor must authorize (to prevent griefing)
+        executor.require_auth();
+        Self::ensure_not_paused(&env)?;
+
+        // Get proposal
+        let mut proposal = storage::get_proposal(&env, proposal_id)?;
+
+        // Validate state
+        if proposal.status == ProposalStatus::Executed {
+            return Err(VaultError::ProposalAlreadyExecuted);
+        }
+        if proposal.status == ProposalStatus::Vetoed {
+            return Err(VaultError::ProposalNotApproved);
+        }
+        if proposal.status != ProposalStatus::Approved {
+            return Err(VaultError::ProposalNotApproved);
+        }
+        // Committed transfers only execute through `reveal_and_execute`
+        if storage::get_commitment(&env, proposal_id).is_some() {
+            return Err(VaultError::Unauthorized);
         }
 
-        storage::extend_instance_ttl(&env);
+        // Designated keepers only, if the proposer set an allow-list
+        Self::ensure_executor_allowed(&env, proposal_id, &executor)?;
 
-        if actual_insurance > 0 {
-            let first_token = transfers.get(0).unwrap().token;
-            events::emit_insurance_locked(
+        // Check expiration (even approved proposals can expire)
+        let current_ledger = env.ledger().sequence() as u64;
+        if current_ledger > proposal.expires_at {
+            Self::transition_status(
                 &env,
-                proposal_ids.get(0).unwrap(),
-                &proposer,
-                actual_insurance,
-                &first_token,
+                &mut proposal,
+                ProposalStatus::Expired,
+                &executor,
+                "expired",
             );
+            storage::set_proposal(&env, &proposal);
+            storage::metrics_on_expiry(&env);
+            events::emit_proposal_expired(&env, proposal_id, proposal.expires_at);
+            return Err(VaultError::ProposalExpired);
         }
 
-        Self::update_reputation_on_propose(&env, &proposer);
+        // Time-sensitive proposals must execute within their window after
+        // approval. Return Ok so the expiry and deposit refund persist.
+        if Self::execution_window_lapsed(&env, &proposal, current_ledger) {
+            Self::lapse_execution_window(&env, &mut proposal, &executor);
+            return Ok(());
+        }
 
-        Ok(proposal_ids)
+        // Check Timelock
+        if proposal.unlock_ledger > 0 && current_ledger < proposal.unlock_ledger {
+            return Err(VaultError::TimelockNotExpired);
+        }
+
+        // Dependencies must be fully executed before this proposal can execute.
+        Self::ensure_dependencies_executable(&env, &proposal)?;
+
+        // Enforce retry constraints if this is a retry attempt
+        let config = storage::get_config(&env)?;
+
+        // Large transfers wait for a fresh signer's second look (no dedicated error variant left)
+        if Self::second_look_pending(&env, &config, &proposal) {
+            return Err(VaultError::ConditionsNotMet);
+        }
+        let retry_state =
+            storage::get_retry_state(&env, proposal_id).filter(|state| state.retry_count > 0);
+        if let Err(err) = Self::ensure_vote_requirements_satisfied(&env, &config, &proposal) {
+            // Signers or thresholds changed since approval: a pending retry can
+            // never succeed, so end it. Return Ok so the rejection persists.
+            if let Some(retry_state) = retry_state {
+                Self::invalidate_retry(&env, &mut proposal, &executor, &retry_state, err);
+                return Ok(());
+            }
+            return Err(err);
+        }
+        if let Some(retry_state) = retry_state {
+            // Check if max retries exhausted
+            if config.retry_config.enabled
+                && retry_state.retry_count >= config.retry_config.max_retries
+            {
+                return Err(VaultError::RetryError);
+            }
+            // Check backoff period
+            if current_ledger < retry_state.next_retry_ledger {
+                return Err(VaultError::RetryError);
+            }
+        }
+
+        // Attempt execution (hooks run inside) — retryable failures are handled below
+        let exec_result =
+            Self::try_execute_transfer(&env, &executor, &mut proposal, current_ledger);
+
+        match exec_result {
+            Ok(()) => {
+                // Update proposal status
+                let retried = storage::get_retry_state(&env, proposal_id)
+                    .is_some_and(|state| state.retry_count > 0);
+                Self::transition_status(
+                    &env,
+                    &mut proposal,
+                    ProposalStatus::Executed,
+                    &executor,
+                    "execute",
+                );
+                storage::set_proposal(&env, &proposal);
+                Self::record_execution_proof(&env, &config, &proposal, current_ledger);
+                storage::remove_retry_state(&env, proposal_id);
+                storage::extend_instance_ttl(&env);
+
+                // Emit execution event (rich: includes token and ledger)
+                storage::mark_recipient_paid(&env, &proposal.recipient);
+                events::emit_proposal_executed(
+                    &env,
+                    proposal_id,
+                    &executor,
+                    &proposal.recipient,
+                    &proposal.token,
+                    proposal.amount,
+                    current_ledger,
+                    storage::get_executor_tip(&env, proposal_id),
+                    storage::get_total_outflow(&env, &proposal.token),
+                );
+                Self::notify(&env, &proposal.proposer, "execution", |prefs| {
+                    prefs.notify_on_execution
+                });
+
+                // Update reputation: proposer +10, each approver +5
+                Self::update_reputation_on_execution(&env, &proposal);
+
+                Self::record_execution_stats(&env, &proposal, current_ledger);
+                if retried {
+                    storage::metrics_on_retry_succeeded(&env);
+                }
+                events::emit_metrics_updated(&env, &storage::get_metrics(&env));
+
+                Ok(())
+            }
+            // A split payout may fail part-way through; scheduling a retry would
+            // persist the legs that already went out, so let it roll back instead.
+            Err(err)
+                if Self::is_retryable_error(&err)
+                    && !(err == VaultError::TransferFailed
+                        && storage::get_split_transfer(&env, proposal_id).is_some()) =>
+            {
+                // Check if retry is configured
+                if !config.retry_config.enabled {
+                    return Err(err);
+                }
+
+                // Schedule retry and return Ok — Soroban rolls back state on Err,
+                // so we must return Ok to persist the retry state. The proposal
+                // remains in Approved status, signaling that execution is pending.
+                Self::schedule_retry(
+                    &env,
+                    proposal_id,
+                    &config.retry_config,
+                    current_ledger,
+                    &err,
+                )?;
+                Ok(())
+            }
+            Err(err) => Err(err),
+        }
     }
 
-    /// Approve a pending proposal.
+    /// Re-attempt execution of a proposal whose previous attempt scheduled a retry.
     ///
-    /// Approval requires `require_auth()` from a valid signer.
-    /// When the threshold is reached AND quorum is satisfied, the status changes to `Approved`.
-    /// If the amount exceeds the `timelock_threshold`, an `unlock_ledger` is calculated.
+    /// Fails with `RetryError` if no retry is pending or its backoff has not
+    /// elapsed; otherwise emits `retry_attempted` and runs `execute_proposal`.
+    pub fn retry_execution(
+        env: Env,
+        executor: Address,
+        proposal_id: u64,
+    ) -> Result<(), VaultError> {
+        let retry_state = storage::get_retry_state(&env, proposal_id)
+            .filter(|state| state.retry_count > 0)
+            .ok_or(VaultError::RetryError)?;
+        if (env.ledger().sequence() as u64) < retry_state.next_retry_ledger {
+            return Err(VaultError::RetryError);
+        }
+
+        events::emit_retry_attempted(&env, proposal_id, retry_state.retry_count, &executor);
+        Self::execute_proposal(env, executor, proposal_id)
+    }
+
+    pub fn get_retry_state(env: Env, proposal_id: u64) -> Option<RetryState> {
+        storage::get_retry_state(&env, proposal_id)
+    }
+
+    pub fn delegate_voting_power(
+        env: Env,
+        delegator: Address,
+        _delegate: Address,
+        _expiry_ledger: u64,
+    ) -> Result<(), VaultError> {
+        delegator.require_auth();
+        let config = storage::get_config(&env)?;
+        if !config.signers.contains(&delegator) {
+            return Err(VaultError::NotASigner);
+        }
+        Err(VaultError::Unauthorized)
+    }
+
+    // Delegation currently resolves to self until full delegation flow is restored.
+    fn resolve_delegation_chain(_env: &Env, voter: &Address, _depth: u32) -> Address {
+        voter.clone()
+    }
+
+    pub fn revoke_delegation(env: Env, delegator: Address) -> Result<(), VaultError> {
+        delegator.require_auth();
+        let config = storage::get_config(&env)?;
+        if !config.signers.contains(&delegator) {
+            return Err(VaultError::NotASigner);
+        }
+        Err(VaultError::Unauthorized)
+    }
+    /// Veto a proposal. Can be called only by configured veto addresses.
     ///
-    /// Quorum = approvals + abstentions. The approval threshold is checked only against
-    /// explicit approvals. Both must be satisfied to transition to `Approved`.
+    /// A veto moves a proposal to `Vetoed` and removes it from the priority queue.
+    /// Vetoed proposals are blocked from execution.
+    pub fn veto_proposal(env: Env, vetoer: Address, proposal_id: u64) -> Result<(), VaultError> {
+        vetoer.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        if !storage::is_veto_address(&env, &vetoer)? {
+            return Err(VaultError::Unauthorized);
+        }
+
+        let mut proposal = storage::get_proposal(&env, proposal_id)?;
+
+        if proposal.status == ProposalStatus::Executed {
+            return Err(VaultError::ProposalAlreadyExecuted);
+        }
+        if proposal.status == ProposalStatus::Vetoed {
+            return Ok(());
+        }
+        if proposal.status != ProposalStatus::Pending && proposal.status != ProposalStatus::Approved
+        {
+            return Err(VaultError::ProposalNotPending);
+        }
+
+        Self::transition_status(&env, &mut proposal, ProposalStatus::Vetoed, &vetoer, "veto");
+        storage::set_proposal(&env, &proposal);
+        storage::remove_from_priority_queue(&env, proposal.priority.clone() as u32, proposal_id);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_proposal_vetoed(&env, proposal_id, &vetoer);
+        Self::notify(&env, &proposal.proposer, "rejection", |prefs| {
+            prefs.notify_on_rejection
+        });
+
+        Ok(())
+    }
+
+    /// Cancel a pending proposal and refund reserved spending limits.
     ///
-    /// Supports delegation: if the signer has delegated their voting power, the vote
-    /// is recorded under the effective voter (following the delegation chain).
+    /// Only the original proposer or an Admin can cancel. Unlike rejection,
+    /// cancellation **refunds** the reserved daily/weekly spending amounts so
+    /// the capacity is available for future proposals.
     ///
     /// # Arguments
-    /// * `signer` - The authorized address providing approval.
-    /// * `proposal_id` - ID of the proposal to approve.
-    pub fn approve_proposal(env: Env, signer: Address, proposal_id: u64) -> Result<(), VaultError> {
-        // Verify identity - CRITICAL for security
-        signer.require_auth();
-
-        // Get config and validate signer
-        let config = storage::get_config(&env)?;
-        if !config.signers.contains(&signer) {
-            return Err(VaultError::NotASigner);
-        }
-
-        // Check permission
+    /// * `canceller` - Address initiating the cancellation (must authorize).
+    /// * `proposal_id` - ID of the proposal to cancel.
+    /// * `reason` - Short symbol describing why the proposal is being cancelled.
+    ///
+    /// # Returns
+    /// `Ok(())` on success, or a `VaultError` on failure.
+    pub fn cancel_proposal(
+        env: Env,
+        canceller: Address,
+        proposal_id: u64,
+        reason: Symbol,
+    ) -> Result<(), VaultError> {
+        canceller.require_auth();
+        Self::ensure_initialized(&env)?;
 
-        // Get proposal
         let mut proposal = storage::get_proposal(&env, proposal_id)?;
 
-        // Snapshot check: voter must have been a signer at proposal creation
-        if !proposal.snapshot_signers.contains(&signer) {
-            return Err(VaultError::VoterNotInSnapshot);
+        // Guard: already cancelled
+        if proposal.status == ProposalStatus::Cancelled {
+            return Err(VaultError::ProposalAlreadyCancelled);
         }
 
-        // Resolve delegation chain to get effective voter
-        let effective_voter = Self::resolve_delegation_chain(&env, &signer, 0);
-        let is_delegated = effective_voter != signer;
-
-        // Validate state
+        // Guard: only Pending proposals can be cancelled
         if proposal.status != ProposalStatus::Pending {
             return Err(VaultError::ProposalNotPending);
         }
 
-        // Prevent double-approval or abstaining then approving (check effective voter)
-        if proposal.approvals.contains(&effective_voter)
-            || proposal.abstentions.contains(&effective_voter)
-        {
-            return Err(VaultError::AlreadyApproved);
+        // Authorization: only proposer or Admin
+        let role = storage::get_role(&env, &canceller);
+        if role != Role::Admin && canceller != proposal.proposer {
+            return Err(VaultError::Unauthorized);
         }
 
-        // Check expiration (only if expiration is enabled, i.e., expires_at > 0)
-        let current_ledger = env.ledger().sequence() as u64;
-        if proposal.expires_at > 0 && current_ledger > proposal.expires_at {
-            proposal.status = ProposalStatus::Expired;
-            storage::set_proposal(&env, &proposal);
-            storage::metrics_on_expiry(&env);
-            events::emit_proposal_expired(&env, proposal_id, proposal.expires_at);
-            return Err(VaultError::ProposalExpired);
-        }
+        // Admin acting on *another* proposer's proposal → rejection semantics
+        let is_rejection = role == Role::Admin && canceller != proposal.proposer;
 
-        // Check voting deadline
-        if proposal.voting_deadline > 0 && current_ledger > proposal.voting_deadline {
-            proposal.status = ProposalStatus::Rejected;
+        if is_rejection {
+            Self::transition_status(
+                &env,
+                &mut proposal,
+                ProposalStatus::Rejected,
+                &canceller,
+                "reject",
+            );
             storage::set_proposal(&env, &proposal);
-            storage::metrics_on_rejection(&env);
-            events::emit_proposal_deadline_rejected(&env, proposal_id, proposal.voting_deadline);
-            return Err(VaultError::VotingDeadlinePassed);
-        }
-
-        // Add approval using effective voter
-        proposal.approvals.push_back(effective_voter.clone());
-        let current_ledger = env.ledger().sequence() as u64;
-        storage::set_approval_ledger(&env, proposal_id, &signer, current_ledger);
-
-        // Emit delegated vote event if voting through delegation
-        if is_delegated {
-            events::emit_delegated_vote(&env, proposal_id, &effective_voter, &signer);
-        }
-
-        // Calculate current vote totals
-        let approval_count = proposal.approvals.len();
-        let quorum_votes = approval_count + proposal.abstentions.len();
-        let previous_quorum_votes = quorum_votes.saturating_sub(1);
-        let was_quorum_reached = config.quorum == 0 || previous_quorum_votes >= config.quorum;
-
-        // Check if threshold met AND quorum satisfied
-        let threshold_reached = Self::is_threshold_reached(&env, &config, &proposal);
-        let quorum_reached = config.quorum == 0 || quorum_votes >= config.quorum;
-        if config.quorum > 0 && !was_quorum_reached && quorum_reached {
-            events::emit_quorum_reached(&env, proposal_id, quorum_votes, config.quorum);
-        }
+            storage::remove_from_priority_queue(
+                &env,
+                proposal.priority.clone() as u32,
+                proposal_id,
+            );
+            Self::update_reputation_on_rejection(&env, &proposal.proposer);
 
-        if threshold_reached && quorum_reached {
-            // Check if proposal has execution_time (scheduled)
-            if proposal.execution_time.is_some() {
-                // Transition to Scheduled status
-                proposal.status = ProposalStatus::Scheduled;
-                events::emit_proposal_scheduled(
+            // ── Slash insurance ──────────────────────────────────────────────
+            let insurance_config = storage::get_insurance_config(&env);
+            if insurance_config.enabled && proposal.insurance_amount > 0 {
+                let slashed =
+                    math::pct_of(proposal.insurance_amount, insurance_config.slash_percentage);
+                let kept = proposal.insurance_amount.saturating_sub(slashed);
+                let destination = storage::get_slash_destination(&env);
+                if kept > 0 {
+                    token::transfer(
+                        &env,
+                        &storage::get_insurance_token(&env, &proposal),
+                        &proposal.proposer,
+                        kept,
+                    );
+                }
+                if slashed > 0 {
+                    Self::distribute_insurance_slash(
+                        &env,
+                        &proposal,
+                        &canceller,
+                        &destination,
+                        slashed,
+                    );
+                }
+                events::emit_insurance_slashed(
                     &env,
                     proposal_id,
-                    proposal.execution_time.unwrap(),
-                    current_ledger,
+                    &proposal.proposer,
+                    slashed,
+                    kept,
+                    &destination,
                 );
-            } else {
-                // Immediate execution - transition to Approved
-                proposal.status = ProposalStatus::Approved;
-
-                // Check for Timelock
-                if proposal.amount >= config.timelock_threshold {
-                    let current_ledger = env.ledger().sequence() as u64;
-                    proposal.unlock_ledger = current_ledger + config.timelock_delay;
-                } else {
-                    proposal.unlock_ledger = 0;
-                }
-
-                events::emit_proposal_ready(&env, proposal_id, proposal.unlock_ledger);
             }
-        }
-
-        storage::set_proposal(&env, &proposal);
-        storage::extend_instance_ttl(&env);
-
-        // Create audit entry
-        storage::create_audit_entry(&env, AuditAction::ApproveProposal, &signer, proposal_id);
 
-        // Emit event
-        events::emit_proposal_approved(
-            &env,
-            proposal_id,
-            &effective_voter,
-            approval_count,
-            config.threshold,
-        );
+            // ── Slash stake ──────────────────────────────────────────────────
+            let staking_config = storage::get_staking_config(&env);
+            if proposal.stake_amount > 0 {
+                if let Some(mut stake_record) = storage::get_stake_record(&env, proposal_id) {
+                    if !stake_record.refunded && !stake_record.slashed {
+                        let slashed_stake = if staking_config.enabled {
+                            math::pct_of(proposal.stake_amount, staking_config.slash_percentage)
+                        } else {
+                            0
+                        };
+                        let returned_stake = proposal.stake_amount.saturating_sub(slashed_stake);
 
-        // Reputation boost for approving (credit the effective voter)
-        Self::update_reputation_on_approval(&env, &effective_voter);
+                        if returned_stake > 0 {
+                            token::transfer(
+                                &env,
+                                &proposal.token,
+                                &proposal.proposer,
+                                returned_stake,
+                            );
+                        }
+                        if slashed_stake > 0 {
+                            storage::add_to_stake_pool(&env, &proposal.token, slashed_stake);
+                        }
 
-        Ok(())
-    }
+                        stake_record.slashed = slashed_stake > 0;
+                        stake_record.slashed_amount = slashed_stake;
+                        stake_record.released_at = env.ledger().sequence() as u64;
+                        storage::set_stake_record(&env, &stake_record);
 
-    /// Abstain from a pending proposal explicitly.
-    ///
-    /// The signer's vote counts towards the quorum but does not contribute
-    /// to the total approvals required to meet the threshold.
-    ///
-    /// # Arguments
-    /// * `signer` - The authorized address providing the abstention.
-    /// * `proposal_id` - ID of the proposal to abstain from.
-    pub fn abstain_proposal(env: Env, signer: Address, proposal_id: u64) -> Result<(), VaultError> {
-        // Verify identity
-        signer.require_auth();
+                        events::emit_stake_slashed(
+                            &env,
+                            proposal_id,
+                            &proposal.proposer,
+                            slashed_stake,
+                            returned_stake,
+                        );
+                    }
+                }
+            }
 
-        // Get config and validate signer
-        let config = storage::get_config(&env)?;
-        if !config.signers.contains(&signer) {
-            return Err(VaultError::NotASigner);
-        }
+            storage::create_audit_entry(&env, AuditAction::RejectProposal, &canceller, proposal_id);
+            events::emit_proposal_rejected(&env, proposal_id, &canceller, &proposal.proposer);
+            Self::notify(&env, &proposal.proposer, "rejection", |prefs| {
+                prefs.notify_on_rejection
+            });
+        } else {
+            // ── Proposer-initiated cancellation ─────────────────────────────
 
-        // Get proposal
-        let mut proposal = storage::get_proposal(&env, proposal_id)?;
+            // Refund reserved spending capacity
+            storage::refund_spending_limits(&env, proposal.amount);
+            storage::unlog_daily_proposal(&env, storage::get_day_number(&env), proposal_id);
+            Self::adjust_category_budget(&env, proposal_id, -proposal.amount)?;
 
-        // Snapshot check: voter must have been a signer at proposal creation
-        if !proposal.snapshot_signers.contains(&signer) {
-            return Err(VaultError::VoterNotInSnapshot);
-        }
+            Self::transition_status(
+                &env,
+                &mut proposal,
+                ProposalStatus::Cancelled,
+                &canceller,
+                "cancel",
+            );
+            storage::set_proposal(&env, &proposal);
 
-        // Resolve delegation chain to get effective voter
-        let effective_voter = Self::resolve_delegation_chain(&env, &signer, 0);
-        let is_delegated = effective_voter != signer;
+            storage::remove_from_priority_queue(
+                &env,
+                proposal.priority.clone() as u32,
+                proposal_id,
+            );
 
-        // Validate state
-        if proposal.status != ProposalStatus::Pending {
-            return Err(VaultError::ProposalNotPending);
-        }
+            // Store cancellation record (audit trail)
+            let current_ledger = env.ledger().sequence() as u64;
+            let record = crate::CancellationRecord {
+                proposal_id,
+                cancelled_by: canceller.clone(),
+                reason: reason.clone(),
+                cancelled_at_ledger: current_ledger,
+                refunded_amount: proposal.amount,
+            };
+            storage::set_cancellation_record(&env, &record);
+            storage::add_to_cancellation_history(&env, proposal_id);
+            storage::metrics_on_cancellation(&env);
+            storage::extend_instance_ttl(&env);
 
-        // Prevent double-abstaining or approving then abstaining
-        if proposal.approvals.contains(&effective_voter)
-            || proposal.abstentions.contains(&effective_voter)
-        {
-            return Err(VaultError::AlreadyApproved);
-        }
+            storage::create_audit_entry(&env, AuditAction::RejectProposal, &canceller, proposal_id);
 
-        // Check expiration
-        let current_ledger = env.ledger().sequence() as u64;
-        if proposal.expires_at > 0 && current_ledger > proposal.expires_at {
-            proposal.status = ProposalStatus::Expired;
-            storage::set_proposal(&env, &proposal);
-            storage::metrics_on_expiry(&env);
-            events::emit_proposal_expired(&env, proposal_id, proposal.expires_at);
-            return Err(VaultError::ProposalExpired);
-        }
+            events::emit_proposal_cancelled(
+                &env,
+                proposal_id,
+                &canceller,
+                &reason,
+                proposal.amount,
+            );
 
-        // Check voting deadline
-        if proposal.voting_deadline > 0 && current_ledger > proposal.voting_deadline {
-            proposal.status = ProposalStatus::Rejected;
-            storage::set_proposal(&env, &proposal);
-            storage::metrics_on_rejection(&env);
-            events::emit_proposal_deadline_rejected(&env, proposal_id, proposal.voting_deadline);
-            return Err(VaultError::VotingDeadlinePassed);
+            Self::refund_proposal_deposits(&env, &proposal);
         }
 
-        // Add abstention using effective voter
-        proposal.abstentions.push_back(effective_voter.clone());
+        Ok(())
+    }
 
-        // Emit delegated vote event if voting through delegation
-        if is_delegated {
-            events::emit_delegated_vote(&env, proposal_id, &effective_voter, &signer);
-        }
+    /// Retrieve the cancellation record for a cancelled proposal.
+    ///
+    /// Useful for auditing: returns who cancelled, why, when, and how much was refunded.
+    pub fn get_cancellation_record(
+        env: Env,
+        proposal_id: u64,
+    ) -> Result<crate::CancellationRecord, VaultError> {
+        storage::get_cancellation_record(&env, proposal_id)
+    }
 
-        // Calculate current vote totals
-        let approval_count = proposal.approvals.len();
-        let abstention_count = proposal.abstentions.len();
-        let quorum_votes = approval_count + abstention_count;
-        let previous_quorum_votes = quorum_votes.saturating_sub(1);
-        let was_quorum_reached = config.quorum == 0 || previous_quorum_votes >= config.quorum;
+    /// Retrieve the full cancellation history (list of cancelled proposal IDs).
+    pub fn get_cancellation_history(env: Env) -> soroban_sdk::Vec<u64> {
+        storage::get_cancellation_history(&env)
+    }
 
-        // Check if threshold met AND quorum satisfied
-        let threshold_reached = Self::is_threshold_reached(&env, &config, &proposal);
-        let quorum_reached = config.quorum == 0 || quorum_votes >= config.quorum;
-        if config.quorum > 0 && !was_quorum_reached && quorum_reached {
-            events::emit_quorum_reached(&env, proposal_id, quorum_votes, config.quorum);
+    /// Invalidate a proposal whose threshold/quorum snapshot is considered unsafe.
+    ///
+    /// Admin escape hatch for proposals created under a configuration that has since
+    /// been found to be wrong. Because votes are counted against the snapshot taken
+    /// at creation, lowering the threshold afterwards does not affect an in-flight
+    /// proposal; this lets an Admin void it instead. The proposer is not at fault, so
+    /// reserved spending capacity, insurance, and stake are all refunded in full.
+    pub fn invalidate_proposal(
+        env: Env,
+        admin: Address,
+        proposal_id: u64,
+        reason: Symbol,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
         }
 
-        if threshold_reached && quorum_reached {
-            if proposal.execution_time.is_some() {
-                proposal.status = ProposalStatus::Scheduled;
-                events::emit_proposal_scheduled(
-                    &env,
-                    proposal_id,
-                    proposal.execution_time.unwrap(),
-                    current_ledger,
-                );
-            } else {
-                proposal.status = ProposalStatus::Approved;
-                if proposal.amount >= config.timelock_threshold {
-                    proposal.unlock_ledger = current_ledger + config.timelock_delay;
-                } else {
-                    proposal.unlock_ledger = 0;
-                }
-                events::emit_proposal_ready(&env, proposal_id, proposal.unlock_ledger);
-            }
+        let mut proposal = storage::get_proposal(&env, proposal_id)?;
+        match proposal.status {
+            ProposalStatus::Pending | ProposalStatus::Approved | ProposalStatus::Scheduled => {}
+            ProposalStatus::Executed => return Err(VaultError::ProposalAlreadyExecuted),
+            ProposalStatus::Cancelled => return Err(VaultError::ProposalAlreadyCancelled),
+            _ => return Err(VaultError::ProposalNotPending),
         }
 
+        storage::refund_spending_limits(&env, proposal.amount);
+        storage::unlog_daily_proposal(&env, storage::get_day_number(&env), proposal_id);
+        Self::adjust_category_budget(&env, proposal_id, -proposal.amount)?;
+        Self::transition_status(
+            &env,
+            &mut proposal,
+            ProposalStatus::Cancelled,
+            &admin,
+            "invalidate",
+        );
         storage::set_proposal(&env, &proposal);
-        storage::extend_instance_ttl(&env);
-
-        // Create audit entry
-        storage::create_audit_entry(&env, AuditAction::AbstainProposal, &signer, proposal_id);
+        storage::remove_from_priority_queue(&env, proposal.priority.clone() as u32, proposal_id);
 
-        // Emit event
-        events::emit_proposal_abstained(
-            &env,
+        let record = crate::CancellationRecord {
             proposal_id,
-            &effective_voter,
-            abstention_count as u32,
-            quorum_votes as u32,
-        );
+            cancelled_by: admin.clone(),
+            reason: reason.clone(),
+            cancelled_at_ledger: env.ledger().sequence() as u64,
+            refunded_amount: proposal.amount,
+        };
+        storage::set_cancellation_record(&env, &record);
+        storage::add_to_cancellation_history(&env, proposal_id);
+        storage::extend_instance_ttl(&env);
 
-        // Track participation for abstaining
-        Self::update_reputation_on_abstention(&env, &effective_voter);
+        storage::create_audit_entry(&env, AuditAction::RejectProposal, &admin, proposal_id);
+        events::emit_proposal_invalidated(&env, proposal_id, &admin, &reason);
+
+        Self::refund_proposal_deposits(&env, &proposal);
 
         Ok(())
     }
-    /// Finalizes and executes an approved proposal.
-    ///
-    /// Can be called by anyone (even an automated tool) as long as:
-    /// 1. The proposal status is `Approved`.
-    /// 2. The required approvals threshold and quorum are still satisfied.
-    /// 3. Any applicable timelock has expired.
-    /// 4. The vault has sufficient balance of the target token.
-    ///
-    /// Rollback behavior:
-    /// - A snapshot of execution-critical state is recorded before transfer.
-    /// - If transfer fails, proposal and queue state are restored from snapshot.
-    /// - A rollback event is emitted with the failure reason code.
+
+    /// Amend a pending proposal and require fresh re-approval.
     ///
-    /// # Arguments
-    /// * `executor` - The address triggering the final transfer (must authorize).
-    /// * `proposal_id` - ID of the proposal to execute.
-    pub fn execute_proposal(
+    /// Only the original proposer can amend. Approvals and abstentions are reset,
+    /// and an amendment record is appended to on-chain history for auditing.
+    pub fn amend_proposal(
         env: Env,
-        executor: Address,
+        proposer: Address,
         proposal_id: u64,
+        new_recipient: Address,
+        new_amount: i128,
+        new_memo: Symbol,
     ) -> Result<(), VaultError> {
-        // Executor must authorize (to prevent griefing)
-        executor.require_auth();
+        proposer.require_auth();
 
-        // Get proposal
+        let config = storage::get_config(&env)?;
         let mut proposal = storage::get_proposal(&env, proposal_id)?;
 
-        // Validate state
-        if proposal.status == ProposalStatus::Executed {
-            return Err(VaultError::ProposalAlreadyExecuted);
-        }
-        if proposal.status == ProposalStatus::Vetoed {
-            return Err(VaultError::ProposalNotApproved);
-        }
-        if proposal.status != ProposalStatus::Approved {
-            return Err(VaultError::ProposalNotApproved);
-        }
-
-        // Check expiration (even approved proposals can expire)
-        let current_ledger = env.ledger().sequence() as u64;
-        if current_ledger > proposal.expires_at {
-            proposal.status = ProposalStatus::Expired;
-            storage::set_proposal(&env, &proposal);
-            storage::metrics_on_expiry(&env);
-            events::emit_proposal_expired(&env, proposal_id, proposal.expires_at);
-            return Err(VaultError::ProposalExpired);
+        if proposal.proposer != proposer {
+            return Err(VaultError::Unauthorized);
         }
-
-        // Check Timelock
-        if proposal.unlock_ledger > 0 && current_ledger < proposal.unlock_ledger {
-            return Err(VaultError::TimelockNotExpired);
+        if proposal.status != ProposalStatus::Pending {
+            return Err(VaultError::ProposalNotPending);
         }
+        Self::ensure_memo_allowed(&env, &config, &new_memo)?;
 
-        // Dependencies must be fully executed before this proposal can execute.
-        for dependency_id in proposal.depends_on.iter() {
-            if let Ok(dep_proposal) = storage::get_proposal(&env, dependency_id) {
-                if dep_proposal.status != ProposalStatus::Executed {
-                    return Err(VaultError::ProposalNotApproved);
-                }
-            } else {
-                return Err(VaultError::ProposalNotFound);
-            }
-        }
+        Self::validate_transfer_target(&env, &new_recipient, &proposal.token)?;
 
-        // Enforce retry constraints if this is a retry attempt
-        let config = storage::get_config(&env)?;
-        Self::ensure_vote_requirements_satisfied(&env, &config, &proposal)?;
-        if let Some(retry_state) = storage::get_retry_state(&env, proposal_id) {
-            if retry_state.retry_count > 0 {
-                // Check if max retries exhausted
-                if config.retry_config.enabled
-                    && retry_state.retry_count >= config.retry_config.max_retries
-                {
-                    return Err(VaultError::RetryError);
-                }
-                // Check backoff period
-                if current_ledger < retry_state.next_retry_ledger {
-                    return Err(VaultError::RetryError);
-                }
-            }
+        if new_amount <= 0 {
+            return Err(VaultError::InvalidAmount);
         }
-
-        // Execute pre-hooks
-        for hook in config.pre_execution_hooks.iter() {
-            Self::call_hook(&env, &hook, proposal_id, true);
+        if new_amount > config.spending_limit {
+            return Err(VaultError::ExceedsProposalLimit);
         }
 
-        // Attempt execution — retryable failures are handled below
-        let exec_result =
-            Self::try_execute_transfer(&env, &executor, &mut proposal, current_ledger);
+        // Keep reserved spending in sync with amended amount.
+        use core::cmp::Ordering;
+        match new_amount.cmp(&proposal.amount) {
+            Ordering::Greater => {
+                let increase = new_amount - proposal.amount;
+                let today = storage::get_day_number(&env);
+                let week = storage::get_week_number(&env);
 
-        match exec_result {
-            Ok(()) => {
-                // Execute post-hooks
-                for hook in config.post_execution_hooks.iter() {
-                    Self::call_hook(&env, &hook, proposal_id, false);
+                let spent_today = storage::get_daily_spent(&env, today);
+                if spent_today + increase > config.daily_limit {
+                    return Err(VaultError::ExceedsDailyLimit);
                 }
-
-                // Update proposal status
-                proposal.status = ProposalStatus::Executed;
-                storage::set_proposal(&env, &proposal);
-                storage::extend_instance_ttl(&env);
-
-                // Emit execution event (rich: includes token and ledger)
-                events::emit_proposal_executed(
-                    &env,
-                    proposal_id,
-                    &executor,
-                    &proposal.recipient,
-                    &proposal.token,
-                    proposal.amount,
-                    current_ledger,
-                );
-
-                // Update reputation: proposer +10, each approver +5
-                Self::update_reputation_on_execution(&env, &proposal);
-
-                // Update performance metrics
-                let execution_time = current_ledger.saturating_sub(proposal.created_at);
-                storage::metrics_on_execution(&env, proposal.gas_used, execution_time);
-                events::emit_execution_fee_used(&env, proposal_id, proposal.gas_used);
-                let metrics = storage::get_metrics(&env);
-                events::emit_metrics_updated(
-                    &env,
-                    metrics.executed_count,
-                    metrics.rejected_count,
-                    metrics.expired_count,
-                    metrics.success_rate_bps(),
-                );
-
-                Ok(())
-            }
-            Err(err) if Self::is_retryable_error(&err) => {
-                // Check if retry is configured
-                if !config.retry_config.enabled {
-                    return Err(err);
+                let spent_week = storage::get_weekly_spent(&env, week);
+                if spent_week + increase > config.weekly_limit {
+                    return Err(VaultError::ExceedsWeeklyLimit);
                 }
 
-                // Schedule retry and return Ok — Soroban rolls back state on Err,
-                // so we must return Ok to persist the retry state. The proposal
-                // remains in Approved status, signaling that execution is pending.
-                Self::schedule_retry(
-                    &env,
-                    proposal_id,
-                    &config.retry_config,
-                    current_ledger,
-                    &err,
-                )?;
-                Ok(())
+                storage::add_daily_spent(&env, today, increase);
+                storage::add_weekly_spent(&env, week, increase);
+                storage::log_daily_proposal(&env, today, proposal_id);
+                Self::adjust_category_budget(&env, proposal_id, increase)?;
             }
-            Err(err) => Err(err),
+            Ordering::Less => {
+                let decrease = proposal.amount - new_amount;
+                storage::refund_spending_limits(&env, decrease);
+                Self::adjust_category_budget(&env, proposal_id, -decrease)?;
+            }
+            Ordering::Equal => {}
         }
-    }
-    pub fn get_retry_state(env: Env, proposal_id: u64) -> Option<RetryState> {
-        storage::get_retry_state(&env, proposal_id)
-    }
 
-    pub fn delegate_voting_power(
-        env: Env,
-        delegator: Address,
-        _delegate: Address,
-        _expiry_ledger: u64,
-    ) -> Result<(), VaultError> {
-        delegator.require_auth();
-        let config = storage::get_config(&env)?;
-        if !config.signers.contains(&delegator) {
-            return Err(VaultError::NotASigner);
-        }
-        Err(VaultError::Unauthorized)
-    }
+        let amendment = ProposalAmendment {
+            proposal_id,
+            amended_by: proposer,
+            amended_at_ledger: env.ledger().sequence() as u64,
+            old_recipient: proposal.recipient.clone(),
+            new_recipient: new_recipient.clone(),
+            old_amount: proposal.amount,
+            new_amount,
+            old_memo: proposal.memo.clone(),
+            new_memo: new_memo.clone(),
+        };
+
+        proposal.recipient = new_recipient;
+        proposal.amount = new_amount;
+        proposal.memo = new_memo;
+        proposal.approvals = Vec::new(&env);
+        proposal.abstentions = Vec::new(&env);
+        Self::transition_status(
+            &env,
+            &mut proposal,
+            ProposalStatus::Pending,
+            &amendment.amended_by,
+            "amend",
+        );
+        proposal.unlock_ledger = 0;
+
+        storage::set_proposal(&env, &proposal);
+        storage::add_amendment_record(&env, &amendment);
+        Self::persist_execution_fee_estimate(&env, &proposal);
+        storage::extend_instance_ttl(&env);
 
-    // Delegation currently resolves to self until full delegation flow is restored.
-    fn resolve_delegation_chain(_env: &Env, voter: &Address, _depth: u32) -> Address {
-        voter.clone()
+        events::emit_proposal_amended(&env, &amendment);
+
+        Ok(())
     }
 
-    pub fn revoke_delegation(env: Env, delegator: Address) -> Result<(), VaultError> {
-        delegator.require_auth();
-        let config = storage::get_config(&env)?;
-        if !config.signers.contains(&delegator) {
-            return Err(VaultError::NotASigner);
-        }
-        Err(VaultError::Unauthorized)
+    /// Get amendment history for a proposal.
+    pub fn get_proposal_amendments(env: Env, proposal_id: u64) -> Vec<ProposalAmendment> {
+        storage::get_amendment_history(&env, proposal_id)
     }
-    /// Veto a proposal. Can be called only by configured veto addresses.
+
+    // ========================================================================
+    // Admin Functions
+    // ========================================================================
+    /// Update threshold
     ///
-    /// A veto moves a proposal to `Vetoed` and removes it from the priority queue.
-    /// Vetoed proposals are blocked from execution.
-    pub fn veto_proposal(env: Env, vetoer: Address, proposal_id: u64) -> Result<(), VaultError> {
-        vetoer.require_auth();
+    /// Only Admin can update threshold.
+    pub fn update_threshold(env: Env, admin: Address, threshold: u32) -> Result<(), VaultError> {
+        admin.require_auth();
 
-        if !storage::is_veto_address(&env, &vetoer)? {
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
 
-        let mut proposal = storage::get_proposal(&env, proposal_id)?;
+        let mut config = storage::get_config(&env)?;
 
-        if proposal.status == ProposalStatus::Executed {
-            return Err(VaultError::ProposalAlreadyExecuted);
-        }
-        if proposal.status == ProposalStatus::Vetoed {
-            return Ok(());
+        if threshold < 1 {
+            return Err(VaultError::ThresholdTooLow);
         }
-        if proposal.status != ProposalStatus::Pending && proposal.status != ProposalStatus::Approved
-        {
-            return Err(VaultError::ProposalNotPending);
+        if threshold > config.signers.len() {
+            return Err(VaultError::ThresholdTooHigh);
         }
 
-        proposal.status = ProposalStatus::Vetoed;
-        storage::set_proposal(&env, &proposal);
-        storage::remove_from_priority_queue(&env, proposal.priority.clone() as u32, proposal_id);
+        config.threshold = threshold;
+        storage::set_config(&env, &config);
         storage::extend_instance_ttl(&env);
 
-        events::emit_proposal_vetoed(&env, proposal_id, &vetoer);
+        // Create audit entry
+        storage::create_audit_entry(&env, AuditAction::UpdateThreshold, &admin, 0);
+
+        events::emit_config_updated(&env, &admin);
 
         Ok(())
     }
 
-    /// Cancel a pending proposal and refund reserved spending limits.
+    /// Update the vault spending limits.
     ///
-    /// Only the original proposer or an Admin can cancel. Unlike rejection,
-    /// cancellation **refunds** the reserved daily/weekly spending amounts so
-    /// the capacity is available for future proposals.
+    /// Allows an admin to update the per-proposal, daily, and weekly spending caps
+    /// in a single atomic call. All three values must be positive and internally
+    /// consistent (`spending_limit <= daily_limit <= weekly_limit`).
     ///
     /// # Arguments
-    /// * `canceller` - Address initiating the cancellation (must authorize).
-    /// * `proposal_id` - ID of the proposal to cancel.
-    /// * `reason` - Short symbol describing why the proposal is being cancelled.
+    /// * `admin`         - Caller; must hold the `Admin` role and authorize.
+    /// * `spending_limit` - Maximum amount per individual proposal (in stroops).
+    /// * `daily_limit`   - Maximum aggregate spending per calendar day (in stroops).
+    /// * `weekly_limit`  - Maximum aggregate spending per calendar week (in stroops).
     ///
-    /// # Returns
-    /// `Ok(())` on success, or a `VaultError` on failure.
-    pub fn cancel_proposal(
+    /// # Errors
+    /// - [`VaultError::NotInitialized`] if the vault has not been initialized.
+    /// - [`VaultError::Unauthorized`]   if the caller is not an Admin.
+    /// - [`VaultError::InvalidAmount`]  if any value is non-positive or the hierarchy
+    ///   `spending_limit <= daily_limit <= weekly_limit` is violated.
+    pub fn update_limits(
         env: Env,
-        canceller: Address,
-        proposal_id: u64,
-        reason: Symbol,
+        admin: Address,
+        spending_limit: i128,
+        daily_limit: i128,
+        weekly_limit: i128,
     ) -> Result<(), VaultError> {
-        canceller.require_auth();
+        admin.require_auth();
 
-        let mut proposal = storage::get_proposal(&env, proposal_id)?;
+        // Admin-only
+        if storage::get_role(&env, &admin) != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
 
-        // Guard: already cancelled
-        if proposal.status == ProposalStatus::Cancelled {
-            return Err(VaultError::ProposalAlreadyCancelled);
+        // All values must be positive
+        if spending_limit <= 0 || daily_limit <= 0 || weekly_limit <= 0 {
+            return Err(VaultError::InvalidAmount);
         }
 
-        // Guard: only Pending proposals can be cancelled
-        if proposal.status != ProposalStatus::Pending {
-            return Err(VaultError::ProposalNotPending);
+        // Enforce hierarchy: per-proposal <= daily <= weekly
+        if spending_limit > daily_limit || daily_limit > weekly_limit {
+            return Err(VaultError::InvalidAmount);
         }
 
-        // Authorization: only proposer or Admin
-        let role = storage::get_role(&env, &canceller);
-        if role != Role::Admin && canceller != proposal.proposer {
+        let mut config = storage::get_config(&env)?;
+        config.spending_limit = spending_limit;
+        config.daily_limit = daily_limit;
+        config.weekly_limit = weekly_limit;
+        storage::set_config(&env, &config);
+        storage::extend_instance_ttl(&env);
+
+        // Audit trail
+        storage::create_audit_entry(&env, AuditAction::UpdateLimits, &admin, 0);
+
+        // Event
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Update the quorum requirement.
+    ///
+    /// Quorum is the minimum number of total votes (approvals + abstentions) that must
+    /// be cast before the approval threshold is checked. Set to 0 to disable.
+    ///
+    /// Only Admin can update quorum.
+    pub fn update_quorum(env: Env, admin: Address, quorum: u32) -> Result<(), VaultError> {
+        admin.require_auth();
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
 
-        // Admin acting on *another* proposer's proposal → rejection semantics
-        let is_rejection = role == Role::Admin && canceller != proposal.proposer;
+        let mut config = storage::get_config(&env)?;
+        let old_quorum = config.quorum;
 
-        if is_rejection {
-            proposal.status = ProposalStatus::Rejected;
-            storage::set_proposal(&env, &proposal);
-            storage::remove_from_priority_queue(
-                &env,
-                proposal.priority.clone() as u32,
-                proposal_id,
-            );
-            Self::update_reputation_on_rejection(&env, &proposal.proposer);
+        // Quorum cannot exceed total signers
+        if quorum > config.signers.len() {
+            return Err(VaultError::QuorumTooHigh);
+        }
 
-            // ── Slash insurance ──────────────────────────────────────────────
-            let insurance_config = storage::get_insurance_config(&env);
-            if insurance_config.enabled && proposal.insurance_amount > 0 {
-                let slashed =
-                    proposal.insurance_amount * (insurance_config.slash_percentage as i128) / 100;
-                let kept = proposal.insurance_amount.saturating_sub(slashed);
-                if kept > 0 {
-                    token::transfer(&env, &proposal.token, &proposal.proposer, kept);
-                }
-                if slashed > 0 {
-                    storage::add_to_insurance_pool(&env, &proposal.token, slashed);
-                }
-                events::emit_insurance_slashed(
-                    &env,
-                    proposal_id,
-                    &proposal.proposer,
-                    slashed,
-                    kept,
-                );
-            }
+        config.quorum = quorum;
+        storage::set_config(&env, &config);
+        storage::extend_instance_ttl(&env);
 
-            // ── Slash stake ──────────────────────────────────────────────────
-            let staking_config = storage::get_staking_config(&env);
-            if proposal.stake_amount > 0 {
-                if let Some(mut stake_record) = storage::get_stake_record(&env, proposal_id) {
-                    if !stake_record.refunded && !stake_record.slashed {
-                        let slashed_stake = if staking_config.enabled {
-                            proposal.stake_amount * staking_config.slash_percentage as i128 / 100
-                        } else {
-                            0
-                        };
-                        let returned_stake = proposal.stake_amount.saturating_sub(slashed_stake);
+        events::emit_config_updated(&env, &admin);
+        events::emit_quorum_updated(&env, &admin, old_quorum, quorum);
 
-                        if returned_stake > 0 {
-                            token::transfer(
-                                &env,
-                                &proposal.token,
-                                &proposal.proposer,
-                                returned_stake,
-                            );
-                        }
-                        if slashed_stake > 0 {
-                            storage::add_to_stake_pool(&env, &proposal.token, slashed_stake);
-                        }
+        Ok(())
+    }
 
-                        stake_record.slashed = slashed_stake > 0;
-                        stake_record.slashed_amount = slashed_stake;
-                        stake_record.released_at = env.ledger().sequence() as u64;
-                        storage::set_stake_record(&env, &stake_record);
+    /// Cap the number of open proposals a single proposer may have.
+    ///
+    /// Pending, approved and scheduled proposals count toward the cap; it
+    /// frees up as soon as one of them is executed, rejected, cancelled,
+    /// expired or vetoed. Proposals over the cap fail with
+    /// `ExceedsProposalLimit`. 0 disables the cap. Only Admin can update it.
+    pub fn set_max_pending_per_proposer(
+        env: Env,
+        admin: Address,
+        max_pending: u32,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+
+        let mut config = storage::get_config(&env)?;
+        config.max_pending_per_proposer = max_pending;
+        storage::set_config(&env, &config);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Restrict metrics, fee and volume views to callers holding
+    /// `Permission::ViewMetrics` (Admin only).
+    ///
+    /// While enabled, `get_metrics`, `get_fees_collected` and `get_user_volume`
+    /// fail with `Unauthorized`; use the `_authed` variants instead. Members
+    /// need an explicit grant, since every address defaults to Member.
+    pub fn set_restrict_metrics(
+        env: Env,
+        admin: Address,
+        restrict: bool,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+
+        let mut config = storage::get_config(&env)?;
+        config.restrict_metrics = restrict;
+        storage::set_config(&env, &config);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
+    }
 
-                        events::emit_stake_slashed(
-                            &env,
-                            proposal_id,
-                            &proposal.proposer,
-                            slashed_stake,
-                            returned_stake,
-                        );
-                    }
-                }
-            }
+    /// Require transfer, amendment and recurring payment memos to come from
+    /// the allowed memo list (Admin only).
+    pub fn set_enforce_memo_whitelist(
+        env: Env,
+        admin: Address,
+        enforce: bool,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
 
-            storage::create_audit_entry(&env, AuditAction::RejectProposal, &canceller, proposal_id);
-            events::emit_proposal_rejected(&env, proposal_id, &canceller, &proposal.proposer);
-        } else {
-            // ── Proposer-initiated cancellation ─────────────────────────────
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
 
-            // Refund reserved spending capacity
-            storage::refund_spending_limits(&env, proposal.amount);
+        let mut config = storage::get_config(&env)?;
+        config.enforce_memo_whitelist = enforce;
+        storage::set_config(&env, &config);
+        storage::extend_instance_ttl(&env);
 
-            proposal.status = ProposalStatus::Cancelled;
-            storage::set_proposal(&env, &proposal);
+        events::emit_config_updated(&env, &admin);
 
-            storage::remove_from_priority_queue(
-                &env,
-                proposal.priority.clone() as u32,
-                proposal_id,
-            );
+        Ok(())
+    }
 
-            // Store cancellation record (audit trail)
-            let current_ledger = env.ledger().sequence() as u64;
-            let record = crate::CancellationRecord {
-                proposal_id,
-                cancelled_by: canceller.clone(),
-                reason: reason.clone(),
-                cancelled_at_ledger: current_ledger,
-                refunded_amount: proposal.amount,
-            };
-            storage::set_cancellation_record(&env, &record);
-            storage::add_to_cancellation_history(&env, proposal_id);
-            storage::extend_instance_ttl(&env);
+    /// Add a memo to the approved taxonomy (Admin only).
+    pub fn add_allowed_memo(env: Env, admin: Address, memo: Symbol) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
 
-            storage::create_audit_entry(&env, AuditAction::RejectProposal, &canceller, proposal_id);
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
 
-            events::emit_proposal_cancelled(
-                &env,
-                proposal_id,
-                &canceller,
-                &reason,
-                proposal.amount,
-            );
+        let mut memos = storage::get_allowed_memos(&env);
+        if memos.contains(&memo) {
+            return Err(VaultError::AddressAlreadyOnList);
+        }
+        if memos.len() >= MAX_ALLOWED_MEMOS {
+            return Err(VaultError::BatchTooLarge);
+        }
+        memos.push_back(memo);
+        storage::set_allowed_memos(&env, &memos);
+        storage::extend_instance_ttl(&env);
 
-            // ── Refund insurance in full ─────────────────────────────────────
-            if proposal.insurance_amount > 0 {
-                token::transfer(
-                    &env,
-                    &proposal.token,
-                    &proposal.proposer,
-                    proposal.insurance_amount,
-                );
-                events::emit_insurance_returned(
-                    &env,
-                    proposal_id,
-                    &proposal.proposer,
-                    proposal.insurance_amount,
-                );
-            }
+        events::emit_config_updated(&env, &admin);
 
-            // ── Refund stake in full ─────────────────────────────────────────
-            if proposal.stake_amount > 0 {
-                if let Some(mut stake_record) = storage::get_stake_record(&env, proposal_id) {
-                    if !stake_record.refunded && !stake_record.slashed {
-                        token::transfer(
-                            &env,
-                            &proposal.token,
-                            &proposal.proposer,
-                            proposal.stake_amount,
-                        );
+        Ok(())
+    }
 
-                        stake_record.refunded = true;
-                        stake_record.released_at = env.ledger().sequence() as u64;
-                        storage::set_stake_record(&env, &stake_record);
+    /// Remove a memo from the approved taxonomy (Admin only). Existing
+    /// proposals and schedules keep their memo.
+    pub fn remove_allowed_memo(env: Env, admin: Address, memo: Symbol) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
 
-                        events::emit_stake_refunded(
-                            &env,
-                            proposal_id,
-                            &proposal.proposer,
-                            proposal.stake_amount,
-                        );
-                    }
-                }
-            }
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
         }
 
+        let mut memos = storage::get_allowed_memos(&env);
+        let pos = memos
+            .first_index_of(&memo)
+            .ok_or(VaultError::AddressNotOnList)?;
+        memos.remove(pos);
+        storage::set_allowed_memos(&env, &memos);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_config_updated(&env, &admin);
+
         Ok(())
     }
 
-    /// Retrieve the cancellation record for a cancelled proposal.
+    /// Approval evidence recorded when a proposal executed, for off-chain
+    /// verification. See `ExecutionProof` for how `hash` is built.
+    pub fn get_execution_proof(env: Env, proposal_id: u64) -> Result<ExecutionProof, VaultError> {
+        storage::get_execution_proof(&env, proposal_id).ok_or(VaultError::ProposalNotFound)
+    }
+
+    /// Set a spend category's monthly budget (Admin only); 0 removes the
+    /// category.
     ///
-    /// Useful for auditing: returns who cancelled, why, when, and how much was refunded.
-    pub fn get_cancellation_record(
+    /// While any category is registered, every new proposal must name one
+    /// (via `ProposalOptions::category`) and reserves its amount against that
+    /// category's counter for the current 30-day month.
+    pub fn set_category_budget(
         env: Env,
-        proposal_id: u64,
-    ) -> Result<crate::CancellationRecord, VaultError> {
-        storage::get_cancellation_record(&env, proposal_id)
-    }
+        admin: Address,
+        category: Symbol,
+        monthly_limit: i128,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
 
-    /// Retrieve the full cancellation history (list of cancelled proposal IDs).
-    pub fn get_cancellation_history(env: Env) -> soroban_sdk::Vec<u64> {
-        storage::get_cancellation_history(&env)
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+        if monthly_limit < 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+
+        let mut budgets = storage::get_category_budgets(&env);
+        if monthly_limit == 0 {
+            budgets.remove(category);
+        } else {
+            budgets.set(category, monthly_limit);
+        }
+        storage::set_category_budgets(&env, &budgets);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
     }
 
-    /// Amend a pending proposal and require fresh re-approval.
+    /// Configure the anomaly breaker (Admin only).
     ///
-    /// Only the original proposer can amend. Approvals and abstentions are reset,
-    /// and an amendment record is appended to on-chain history for auditing.
-    pub fn amend_proposal(
+    /// When more than `threshold` execution failures (scheduled retries and
+    /// failed batch entries) fall within the last `window_ledgers` ledgers,
+    /// execution is frozen until an Admin calls `unpause`. 0 disables it.
+    pub fn set_auto_freeze_config(
         env: Env,
-        proposer: Address,
-        proposal_id: u64,
-        new_recipient: Address,
-        new_amount: i128,
-        new_memo: Symbol,
+        admin: Address,
+        config: AutoFreezeConfig,
     ) -> Result<(), VaultError> {
-        proposer.require_auth();
-
-        let config = storage::get_config(&env)?;
-        let mut proposal = storage::get_proposal(&env, proposal_id)?;
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
 
-        if proposal.proposer != proposer {
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
-        if proposal.status != ProposalStatus::Pending {
-            return Err(VaultError::ProposalNotPending);
+        if config.threshold > 0 && config.window_ledgers == 0 {
+            return Err(VaultError::InvalidAmount);
         }
 
-        if new_amount <= 0 {
-            return Err(VaultError::InvalidAmount);
+        storage::set_auto_freeze_config(&env, &config);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    pub fn get_auto_freeze_config(env: Env) -> AutoFreezeConfig {
+        storage::get_auto_freeze_config(&env)
+    }
+
+    /// Whether execution is frozen by the anomaly breaker.
+    pub fn is_paused(env: Env) -> bool {
+        storage::is_paused(&env)
+    }
+
+    /// Lift an execution freeze (Admin only), recording why it is safe to
+    /// resume. Clears the failure window.
+    pub fn unpause(env: Env, admin: Address, reason: Symbol) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
         }
-        if new_amount > config.spending_limit {
-            return Err(VaultError::ExceedsProposalLimit);
+        if !storage::is_paused(&env) {
+            return Err(VaultError::ConditionsNotMet);
         }
 
-        // Keep reserved spending in sync with amended amount.
-        use core::cmp::Ordering;
-        match new_amount.cmp(&proposal.amount) {
-            Ordering::Greater => {
-                let increase = new_amount - proposal.amount;
-                let today = storage::get_day_number(&env);
-                let week = storage::get_week_number(&env);
+        storage::set_paused(&env, false);
+        storage::set_recent_failures(&env, &Vec::new(&env));
+        storage::extend_instance_ttl(&env);
 
-                let spent_today = storage::get_daily_spent(&env, today);
-                if spent_today + increase > config.daily_limit {
-                    return Err(VaultError::ExceedsDailyLimit);
-                }
-                let spent_week = storage::get_weekly_spent(&env, week);
-                if spent_week + increase > config.weekly_limit {
-                    return Err(VaultError::ExceedsWeeklyLimit);
-                }
+        events::emit_vault_unpaused(&env, &admin, &reason);
 
-                storage::add_daily_spent(&env, today, increase);
-                storage::add_weekly_spent(&env, week, increase);
-            }
-            Ordering::Less => {
-                let decrease = proposal.amount - new_amount;
-                storage::refund_spending_limits(&env, decrease);
-            }
-            Ordering::Equal => {}
+        Ok(())
+    }
+
+    /// Limit the proposal amounts a signer may approve (Admin only); 0 removes
+    /// the cap.
+    ///
+    /// Caps are frozen into each proposal when it is created, so changing a
+    /// cap does not affect votes already under way. A capped signer's
+    /// approval of a larger proposal is refused with `InsufficientRole`.
+    pub fn set_signer_approval_cap(
+        env: Env,
+        admin: Address,
+        signer: Address,
+        max_amount: i128,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+        if max_amount < 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+        let config = storage::get_config(&env)?;
+        if !config.signers.contains(&signer) {
+            return Err(VaultError::NotASigner);
         }
 
-        let amendment = ProposalAmendment {
-            proposal_id,
-            amended_by: proposer,
-            amended_at_ledger: env.ledger().sequence() as u64,
-            old_recipient: proposal.recipient.clone(),
-            new_recipient: new_recipient.clone(),
-            old_amount: proposal.amount,
-            new_amount,
-            old_memo: proposal.memo.clone(),
-            new_memo: new_memo.clone(),
-        };
+        let mut caps = storage::get_approval_caps(&env);
+        if max_amount == 0 {
+            caps.remove(signer);
+        } else {
+            caps.set(signer, max_amount);
+        }
+        storage::set_approval_caps(&env, &caps);
+        storage::extend_instance_ttl(&env);
 
-        proposal.recipient = new_recipient;
-        proposal.amount = new_amount;
-        proposal.memo = new_memo;
-        proposal.approvals = Vec::new(&env);
-        proposal.abstentions = Vec::new(&env);
-        proposal.status = ProposalStatus::Pending;
-        proposal.unlock_ledger = 0;
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Mark a signer as an externally owned account or a contract (Admin only).
+    ///
+    /// Contract signers such as other multisigs are skipped by reputation and
+    /// velocity accounting. Signers default to `Eoa`.
+    pub fn set_signer_kind(
+        env: Env,
+        admin: Address,
+        signer: Address,
+        kind: SignerKind,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
 
-        storage::set_proposal(&env, &proposal);
-        storage::add_amendment_record(&env, &amendment);
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+        let config = storage::get_config(&env)?;
+        if !config.signers.contains(&signer) {
+            return Err(VaultError::NotASigner);
+        }
+
+        let mut kinds = storage::get_signer_kinds(&env);
+        match kind {
+            SignerKind::Eoa => {
+                kinds.remove(signer);
+            }
+            SignerKind::Contract => kinds.set(signer, kind),
+        }
+        storage::set_signer_kinds(&env, &kinds);
         storage::extend_instance_ttl(&env);
 
-        events::emit_proposal_amended(&env, &amendment);
+        events::emit_config_updated(&env, &admin);
 
         Ok(())
     }
 
-    /// Get amendment history for a proposal.
-    pub fn get_proposal_amendments(env: Env, proposal_id: u64) -> Vec<ProposalAmendment> {
-        storage::get_amendment_history(&env, proposal_id)
+    /// Kind of a signer (`Eoa` unless registered as a contract).
+    pub fn get_signer_kind(env: Env, signer: Address) -> SignerKind {
+        storage::get_signer_kind(&env, &signer)
     }
 
-    // ========================================================================
-    // Admin Functions
-    // ========================================================================
-    /// Update threshold
+    /// Invite an address to join the signer set (Admin only).
     ///
-    /// Only Admin can update threshold.
-    pub fn update_threshold(env: Env, admin: Address, threshold: u32) -> Result<(), VaultError> {
+    /// The invite must be approved by `threshold` current signers before the
+    /// invitee can accept it with `accept_signer_invite`, so a single admin
+    /// cannot grow the signer set alone and nobody is made responsible for
+    /// approvals without consenting. An admin who is a signer approves the
+    /// invite by creating it. Re-inviting a pending invitee restarts the
+    /// window and clears its approvals.
+    pub fn invite_signer(env: Env, admin: Address, invitee: Address) -> Result<(), VaultError> {
         admin.require_auth();
 
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        let config = storage::get_config(&env)?;
+        if config.signers.contains(&invitee) {
+            return Err(VaultError::SignerAlreadyExists);
+        }
+        if config.signers.len() >= MAX_SIGNERS {
+            return Err(VaultError::BatchTooLarge);
+        }
 
-        let mut config = storage::get_config(&env)?;
+        let now = env.ledger().sequence() as u64;
+        let mut approvals = Vec::new(&env);
+        if config.signers.contains(&admin) {
+            approvals.push_back(admin.clone());
+        }
+        let invite = SignerInvite {
+            invitee: invitee.clone(),
+            invited_by: admin.clone(),
+            created_at: now,
+            expires_at: now + storage::get_signer_invite_window(&env),
+            approvals,
+        };
+        storage::set_signer_invite(&env, &invite);
+        storage::extend_instance_ttl(&env);
 
-        if threshold < 1 {
-            return Err(VaultError::ThresholdTooLow);
+        events::emit_signer_invited(&env, &admin, &invitee, invite.expires_at);
+
+        Ok(())
+    }
+
+    /// Approve a pending signer invite (current signers only).
+    ///
+    /// Fails with `AddressNotOnList` when the address has no live invite.
+    pub fn approve_signer_invite(
+        env: Env,
+        signer: Address,
+        invitee: Address,
+    ) -> Result<(), VaultError> {
+        signer.require_auth();
+
+        let config = storage::get_config(&env)?;
+        if !config.signers.contains(&signer) {
+            return Err(VaultError::NotASigner);
         }
-        if threshold > config.signers.len() {
-            return Err(VaultError::ThresholdTooHigh);
+        let mut invite = Self::live_signer_invite(&env, &invitee)?;
+        if invite.approvals.contains(&signer) {
+            return Err(VaultError::AlreadyApproved);
         }
 
-        config.threshold = threshold;
-        storage::set_config(&env, &config);
+        invite.approvals.push_back(signer.clone());
+        storage::set_signer_invite(&env, &invite);
         storage::extend_instance_ttl(&env);
 
-        // Create audit entry
-        storage::create_audit_entry(&env, AuditAction::UpdateThreshold, &admin, 0);
-
-        events::emit_config_updated(&env, &admin);
+        events::emit_signer_invite_approved(&env, &signer, &invitee, invite.approvals.len());
 
         Ok(())
     }
 
-    /// Update the vault spending limits.
-    ///
-    /// Allows an admin to update the per-proposal, daily, and weekly spending caps
-    /// in a single atomic call. All three values must be positive and internally
-    /// consistent (`spending_limit <= daily_limit <= weekly_limit`).
-    ///
-    /// # Arguments
-    /// * `admin`         - Caller; must hold the `Admin` role and authorize.
-    /// * `spending_limit` - Maximum amount per individual proposal (in stroops).
-    /// * `daily_limit`   - Maximum aggregate spending per calendar day (in stroops).
-    /// * `weekly_limit`  - Maximum aggregate spending per calendar week (in stroops).
-    ///
-    /// # Errors
-    /// - [`VaultError::NotInitialized`] if the vault has not been initialized.
-    /// - [`VaultError::Unauthorized`]   if the caller is not an Admin.
-    /// - [`VaultError::InvalidAmount`]  if any value is non-positive or the hierarchy
-    ///   `spending_limit <= daily_limit <= weekly_limit` is violated.
-    pub fn update_limits(
+    /// Withdraw a pending signer invite (Admin only).
+    pub fn revoke_signer_invite(
         env: Env,
         admin: Address,
-        spending_limit: i128,
-        daily_limit: i128,
-        weekly_limit: i128,
+        invitee: Address,
     ) -> Result<(), VaultError> {
         admin.require_auth();
+        Self::ensure_initialized(&env)?;
 
-        // Admin-only
-        if storage::get_role(&env, &admin) != Role::Admin {
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
-
-        // All values must be positive
-        if spending_limit <= 0 || daily_limit <= 0 || weekly_limit <= 0 {
-            return Err(VaultError::InvalidAmount);
+        if storage::get_signer_invite(&env, &invitee).is_none() {
+            return Err(VaultError::AddressNotOnList);
         }
 
-        // Enforce hierarchy: per-proposal <= daily <= weekly
-        if spending_limit > daily_limit || daily_limit > weekly_limit {
-            return Err(VaultError::InvalidAmount);
-        }
+        storage::remove_signer_invite(&env, &invitee);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_signer_invite_revoked(&env, &admin, &invitee);
 
+        Ok(())
+    }
+
+    /// Accept an approved invite and join the signer set.
+    ///
+    /// Fails with `AddressNotOnList` without a live invite and
+    /// `QuorumNotReached` until `threshold` current signers have approved it.
+    /// The signer cap and every tier of the threshold strategy are checked
+    /// against the grown set. Proposals created before joining keep their
+    /// signer snapshot, so the new signer votes only on later ones.
+    pub fn accept_signer_invite(env: Env, invitee: Address) -> Result<(), VaultError> {
+        invitee.require_auth();
+
+        let invite = Self::live_signer_invite(&env, &invitee)?;
         let mut config = storage::get_config(&env)?;
-        config.spending_limit = spending_limit;
-        config.daily_limit = daily_limit;
-        config.weekly_limit = weekly_limit;
+        if config.signers.contains(&invitee) {
+            return Err(VaultError::SignerAlreadyExists);
+        }
+        let mut approvals = 0u32;
+        for approver in invite.approvals.iter() {
+            if config.signers.contains(&approver) {
+                approvals += 1;
+            }
+        }
+        if approvals < config.threshold {
+            return Err(VaultError::QuorumNotReached);
+        }
+        if config.signers.len() >= MAX_SIGNERS {
+            return Err(VaultError::BatchTooLarge);
+        }
+        config.signers.push_back(invitee.clone());
+        Self::ensure_strategy_reachable(&env, &config, config.signers.len())?;
         storage::set_config(&env, &config);
+        storage::remove_signer_invite(&env, &invitee);
         storage::extend_instance_ttl(&env);
 
-        // Audit trail
-        storage::create_audit_entry(&env, AuditAction::UpdateLimits, &admin, 0);
-
-        // Event
-        events::emit_config_updated(&env, &admin);
+        events::emit_signer_added(&env, &invitee, config.signers.len());
 
         Ok(())
     }
 
-    /// Update the quorum requirement.
-    ///
-    /// Quorum is the minimum number of total votes (approvals + abstentions) that must
-    /// be cast before the approval threshold is checked. Set to 0 to disable.
-    ///
-    /// Only Admin can update quorum.
-    pub fn update_quorum(env: Env, admin: Address, quorum: u32) -> Result<(), VaultError> {
+    /// Pending invite for an address, if any (expired invites included).
+    pub fn get_signer_invite(env: Env, invitee: Address) -> Option<SignerInvite> {
+        storage::get_signer_invite(&env, &invitee)
+    }
+
+    /// Set how many ledgers a signer invite stays acceptable (Admin only).
+    pub fn set_signer_invite_window(
+        env: Env,
+        admin: Address,
+        ledgers: u64,
+    ) -> Result<(), VaultError> {
         admin.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
-
-        let mut config = storage::get_config(&env)?;
-        let old_quorum = config.quorum;
-
-        // Quorum cannot exceed total signers
-        if quorum > config.signers.len() {
-            return Err(VaultError::QuorumTooHigh);
+        if ledgers == 0 {
+            return Err(VaultError::InvalidAmount);
         }
 
-        config.quorum = quorum;
-        storage::set_config(&env, &config);
+        storage::set_signer_invite_window(&env, ledgers);
         storage::extend_instance_ttl(&env);
 
         events::emit_config_updated(&env, &admin);
-        events::emit_quorum_updated(&env, &admin, old_quorum, quorum);
 
         Ok(())
     }
 
+    /// Ledgers a signer invite stays acceptable.
+    pub fn get_signer_invite_window(env: Env) -> u64 {
+        storage::get_signer_invite_window(&env)
+    }
+
+    /// Amount reserved against a category in a month (timestamp / 30 days).
+    pub fn get_category_spent(env: Env, category: Symbol, month: u64) -> i128 {
+        storage::get_category_spent(&env, &category, month)
+    }
+
+    /// Registered spend categories and their monthly limits.
+    pub fn list_category_budgets(env: Env) -> Map<Symbol, i128> {
+        storage::get_category_budgets(&env)
+    }
+
+    /// Memos accepted while the memo whitelist is enforced.
+    pub fn list_allowed_memos(env: Env) -> Vec<Symbol> {
+        storage::get_allowed_memos(&env)
+    }
+
+    /// Ledgers at which a proposal was created, approved, executed, rejected
+    /// or cancelled (0 for stages it has not reached).
+    pub fn get_proposal_timestamps(
+        env: Env,
+        proposal_id: u64,
+    ) -> Result<ProposalTimestamps, VaultError> {
+        let proposal = storage::get_proposal(&env, proposal_id)?;
+        Ok(storage::get_proposal_timestamps(&env, &proposal))
+    }
+
+    /// Status transitions of a proposal, oldest first, capped at the most
+    /// recent 20.
+    pub fn get_status_history(env: Env, proposal_id: u64) -> Vec<StatusTransition> {
+        storage::get_status_history(&env, proposal_id)
+    }
+
+    /// Get the number of open (non-terminal) proposals created by `proposer`.
+    pub fn get_pending_count(env: Env, proposer: Address) -> u32 {
+        storage::get_pending_count(&env, &proposer)
+    }
+
     /// Update the voting strategy used for proposal approvals.
     ///
     /// Only Admin can update voting strategy.
@@ -1698,6 +3975,7 @@ impl VaultDAO {
         strategy: VotingStrategy,
     ) -> Result<(), VaultError> {
         admin.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
@@ -1711,6 +3989,47 @@ impl VaultDAO {
         Ok(())
     }
 
+    /// Replace the threshold strategy.
+    ///
+    /// Validated like at `initialize`. Pending proposals keep the threshold
+    /// snapshotted at creation as a floor, so loosening the strategy only
+    /// affects proposals created afterwards. Only Admin can update it.
+    pub fn update_threshold_strategy(
+        env: Env,
+        admin: Address,
+        strategy: ThresholdStrategy,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+
+        let mut config = storage::get_config(&env)?;
+        Self::validate_threshold_strategy(&strategy, config.signers.len())?;
+
+        let tier_count = match &strategy {
+            ThresholdStrategy::AmountBased(tiers) => tiers.len(),
+            _ => 0,
+        };
+        let strategy_hash = env.crypto().sha256(&strategy.clone().to_xdr(&env));
+
+        config.threshold_strategy = strategy;
+        storage::set_config(&env, &config);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_config_updated(&env, &admin);
+        events::emit_threshold_strategy_updated(
+            &env,
+            &admin,
+            tier_count,
+            &strategy_hash.to_bytes(),
+        );
+
+        Ok(())
+    }
+
     /// Extend voting deadline for a proposal (admin only)
     pub fn extend_voting_deadline(
         env: Env,
@@ -1719,6 +4038,7 @@ impl VaultDAO {
         new_deadline: u64,
     ) -> Result<(), VaultError> {
         admin.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
@@ -1734,6 +4054,7 @@ impl VaultDAO {
         let old_deadline = proposal.voting_deadline;
         proposal.voting_deadline = new_deadline;
         storage::set_proposal(&env, &proposal);
+        storage::index_deadline(&env, &proposal);
         storage::extend_instance_ttl(&env);
 
         events::emit_voting_deadline_extended(
@@ -1748,6 +4069,9 @@ impl VaultDAO {
     }
 
     /// Admin withdraws slashed insurance funds
+    ///
+    /// Only available while direct pool withdrawals are enabled (see
+    /// `set_direct_pool_withdrawal`); otherwise use `propose_pool_withdrawal`.
     pub fn withdraw_insurance_pool(
         env: Env,
         admin: Address,
@@ -1757,11 +4081,15 @@ impl VaultDAO {
     ) -> Result<(), VaultError> {
         // Implementation from original logic before the issue.
         admin.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        if !storage::is_direct_pool_withdrawal(&env) {
+            return Err(VaultError::Unauthorized);
+        }
 
         if amount <= 0 {
             return Err(VaultError::InvalidAmount);
@@ -1782,6 +4110,9 @@ impl VaultDAO {
     }
 
     /// Admin withdraws slashed stake funds
+    ///
+    /// Only available while direct pool withdrawals are enabled (see
+    /// `set_direct_pool_withdrawal`); otherwise use `propose_pool_withdrawal`.
     pub fn withdraw_stake_pool(
         env: Env,
         admin: Address,
@@ -1790,11 +4121,15 @@ impl VaultDAO {
         amount: i128,
     ) -> Result<(), VaultError> {
         admin.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        if !storage::is_direct_pool_withdrawal(&env) {
+            return Err(VaultError::Unauthorized);
+        }
 
         if amount <= 0 {
             return Err(VaultError::InvalidAmount);
@@ -1811,6 +4146,79 @@ impl VaultDAO {
         Ok(())
     }
 
+    /// Propose paying `amount` of `token_addr` out of a slashed-fund pool.
+    ///
+    /// Creates a regular transfer proposal that needs the usual approvals and
+    /// timelock; the pool is debited only when the proposal executes, and
+    /// execution fails with `InsufficientBalance` if the pool has shrunk below
+    /// `amount` by then. Admin only.
+    pub fn propose_pool_withdrawal(
+        env: Env,
+        admin: Address,
+        pool: PoolKind,
+        token_addr: Address,
+        recipient: Address,
+        amount: i128,
+        memo: Symbol,
+    ) -> Result<u64, VaultError> {
+        // `propose_transfer_internal` performs the auth check
+        if storage::get_role(&env, &admin) != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+        if amount <= 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+        if amount > storage::get_pool_balance(&env, &pool, &token_addr) {
+            return Err(VaultError::InsufficientBalance);
+        }
+
+        let proposal_id = Self::propose_transfer_internal(
+            env.clone(),
+            admin,
+            recipient,
+            token_addr,
+            amount,
+            memo,
+            Priority::Normal,
+            Vec::new(&env),
+            ConditionLogic::And,
+            0,
+            Vec::new(&env),
+            None,
+            None,
+            None,
+        )?;
+        storage::set_pool_withdrawal(&env, proposal_id, &pool);
+
+        Ok(proposal_id)
+    }
+
+    /// Allow or forbid single-signature `withdraw_insurance_pool` /
+    /// `withdraw_stake_pool` calls. Disabled by default. Admin only.
+    ///
+    /// Like `import_policy`, enabling is only allowed on a freshly initialized
+    /// vault that has no proposals yet (`AlreadyInitialized` otherwise), so a
+    /// single admin cannot open the pools later. Disabling is always allowed.
+    pub fn set_direct_pool_withdrawal(
+        env: Env,
+        admin: Address,
+        enabled: bool,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        if storage::get_role(&env, &admin) != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+        if enabled && storage::get_next_proposal_id(&env) != 1 {
+            return Err(VaultError::AlreadyInitialized);
+        }
+
+        storage::set_direct_pool_withdrawal(&env, enabled);
+        storage::extend_instance_ttl(&env);
+        Ok(())
+    }
+
     /// Admin updates staking configuration
     pub fn update_staking_config(
         env: Env,
@@ -1818,13 +4226,94 @@ impl VaultDAO {
         config: types::StakingConfig,
     ) -> Result<(), VaultError> {
         admin.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+        Self::validate_staking_config(&config)?;
+
+        storage::set_staking_config(&env, &config);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Snapshot of the vault's policy settings for cloning into a new
+    /// deployment. Signers are left out.
+    pub fn export_policy(env: Env) -> Result<PolicyBundle, VaultError> {
+        let mut config = storage::get_config(&env)?;
+        config.signers = Vec::new(&env);
+        Ok(PolicyBundle {
+            config,
+            insurance: storage::get_insurance_config(&env),
+            insurance_usd: storage::get_insurance_usd_threshold(&env),
+            slash_destination: storage::get_slash_destination(&env),
+            staking: storage::get_staking_config(&env),
+            fees: storage::get_fee_structure(&env),
+            gas: storage::get_gas_config(&env),
+            list_mode: storage::get_list_mode(&env),
+        })
+    }
+
+    /// Apply a bundle from `export_policy` to this vault (Admin only).
+    ///
+    /// Only allowed on a freshly initialized vault that has no proposals yet;
+    /// otherwise fails with `AlreadyInitialized`. The vault keeps its own
+    /// signers, and every setting is validated against them before anything
+    /// is written.
+    pub fn import_policy(env: Env, admin: Address, bundle: PolicyBundle) -> Result<(), VaultError> {
+        admin.require_auth();
 
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        if storage::get_next_proposal_id(&env) != 1 {
+            return Err(VaultError::AlreadyInitialized);
+        }
+
+        let signers = storage::get_config(&env)?.signers;
+        let mut config = bundle.config;
+        config.signers = signers;
+        let signer_count = config.signers.len();
+        if config.threshold < 1 {
+            return Err(VaultError::ThresholdTooLow);
+        }
+        if config.threshold > signer_count {
+            return Err(VaultError::ThresholdTooHigh);
+        }
+        if config.quorum > signer_count {
+            return Err(VaultError::QuorumTooHigh);
+        }
+        if config.quorum_percentage > 100
+            || config.spending_limit <= 0
+            || config.daily_limit <= 0
+            || config.weekly_limit <= 0
+        {
+            return Err(VaultError::InvalidAmount);
+        }
+        Self::validate_threshold_strategy(&config.threshold_strategy, signer_count)?;
+        Self::validate_staking_config(&config.staking_config)?;
+        Self::validate_staking_config(&bundle.staking)?;
+        Self::validate_insurance_config(&bundle.insurance)?;
+        if bundle.insurance_usd.min_amount_usd < 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+        Self::validate_fee_structure(&bundle.fees)?;
+        Self::validate_gas_config(&bundle.gas)?;
 
-        storage::set_staking_config(&env, &config);
+        storage::set_config(&env, &config);
+        storage::set_insurance_config(&env, &bundle.insurance);
+        storage::set_insurance_usd_threshold(&env, &bundle.insurance_usd);
+        storage::set_slash_destination(&env, &bundle.slash_destination);
+        storage::set_staking_config(&env, &bundle.staking);
+        storage::set_fee_structure(&env, &bundle.fees);
+        storage::set_gas_config(&env, &bundle.gas);
+        storage::set_list_mode(&env, bundle.list_mode);
         storage::extend_instance_ttl(&env);
 
         events::emit_config_updated(&env, &admin);
@@ -1841,6 +4330,25 @@ impl VaultDAO {
         storage::get_proposal(&env, proposal_id)
     }
 
+    /// Proposer's reputation-adjusted limits frozen when the proposal was
+    /// approved (`None` until then).
+    pub fn get_approval_limits(env: Env, proposal_id: u64) -> Option<ApprovalLimits> {
+        storage::get_approval_limits(&env, proposal_id)
+    }
+
+    /// Token the proposal's insurance is locked in.
+    pub fn get_insurance_token(env: Env, proposal_id: u64) -> Result<Address, VaultError> {
+        let proposal = storage::get_proposal(&env, proposal_id)?;
+        Ok(storage::get_insurance_token(&env, &proposal))
+    }
+
+    /// Whether a proposal, comment, stream or escrow ID was issued but its
+    /// data has since been archived. Getters return `ProposalNotFound` for
+    /// both archived and never-issued IDs.
+    pub fn is_record_archived(env: Env, kind: RecordKind, id: u64) -> bool {
+        storage::is_record_archived(&env, &kind, id)
+    }
+
     /// List proposal IDs in ascending creation order (paginated).
     ///
     /// Returns up to `limit` proposal IDs, skipping the first `offset` entries.
@@ -1881,6 +4389,33 @@ impl VaultDAO {
         proposals
     }
 
+    /// Number of stored proposals in each status.
+    ///
+    /// Counters are updated on every status change, so this answers
+    /// "how many proposals are pending" without iterating proposal IDs.
+    /// Statuses that never occurred are absent from the map.
+    pub fn get_status_counts(env: Env) -> Map<ProposalStatus, u64> {
+        storage::get_status_counts(&env)
+    }
+
+    /// Total number of stored proposals across all statuses.
+    pub fn get_total_proposals(env: Env) -> u64 {
+        storage::get_status_counts(&env).values().iter().sum()
+    }
+
+    /// Reconcile a token's slashed-fund pools and locked deposits against the
+    /// vault balance. Outstanding amounts come from counters maintained as
+    /// deposits are locked, refunded and slashed.
+    pub fn get_pool_report(env: Env, token_addr: Address) -> PoolReport {
+        PoolReport {
+            insurance_pool: storage::get_insurance_pool(&env, &token_addr),
+            stake_pool: storage::get_stake_pool(&env, &token_addr),
+            locked_insurance_outstanding: storage::get_locked_insurance(&env, &token_addr),
+            locked_stakes_outstanding: storage::get_locked_stakes(&env, &token_addr),
+            vault_balance: token::balance(&env, &token_addr),
+        }
+    }
+
     /// Get current pooled slash insurance balance
     pub fn get_insurance_pool(env: Env, token_addr: Address) -> i128 {
         storage::get_insurance_pool(&env, &token_addr)
@@ -1947,9 +4482,7 @@ impl VaultDAO {
         admin.require_auth();
 
         // Vault must be initialized
-        if !storage::is_initialized(&env) {
-            return Err(VaultError::NotInitialized);
-        }
+        Self::ensure_initialized(&env)?;
 
         // Only Admin may assign roles
         if storage::get_role(&env, &admin) != Role::Admin {
@@ -1984,6 +4517,20 @@ impl VaultDAO {
         storage::get_daily_spent(&env, day)
     }
 
+    /// Spending reserved on a day together with the proposals that reserved
+    /// it. Kept for as long as `get_daily_spent` reports the day.
+    pub fn get_daily_ledger(env: Env, day: u64) -> Result<DailyLedger, VaultError> {
+        let config = storage::get_config(&env)?;
+        let (proposal_ids, overflow_count) = storage::get_daily_proposals(&env, day);
+        Ok(DailyLedger {
+            day,
+            spent: storage::get_daily_spent(&env, day),
+            limit: config.daily_limit,
+            proposal_ids,
+            overflow_count,
+        })
+    }
+
     /// Get today's spending
     pub fn get_today_spent(env: Env) -> i128 {
         let today = storage::get_day_number(&env);
@@ -1996,6 +4543,79 @@ impl VaultDAO {
         Ok(config.signers.contains(&addr))
     }
 
+    /// Whether `proposal_id` was executed paying `recipient` at least `min_amount`.
+    ///
+    /// Stable interface for cross-contract verification: the signature and
+    /// semantics will not change. Never errors; unknown proposals, swaps and
+    /// proposals that have not executed all return `false`. Split transfers
+    /// match if any leg pays `recipient` at least `min_amount`.
+    pub fn verify_execution(
+        env: Env,
+        proposal_id: u64,
+        recipient: Address,
+        min_amount: i128,
+    ) -> bool {
+        let Ok(proposal) = storage::get_proposal(&env, proposal_id) else {
+            return false;
+        };
+        if proposal.status != ProposalStatus::Executed || proposal.is_swap {
+            return false;
+        }
+        match storage::get_split_transfer(&env, proposal_id) {
+            Some(splits) => splits
+                .iter()
+                .any(|(leg, amount)| leg == recipient && amount >= min_amount),
+            None => proposal.recipient == recipient && proposal.amount >= min_amount,
+        }
+    }
+
+    /// Whether `addr` is currently a vault signer.
+    ///
+    /// Stable interface for cross-contract verification: unlike `is_signer`
+    /// it never errors, returning `false` before initialization.
+    pub fn verify_signer(env: Env, addr: Address) -> bool {
+        storage::get_config(&env).is_ok_and(|config| config.signers.contains(&addr))
+    }
+
+    /// Configure the Critical-priority timelock bypass.
+    ///
+    /// A non-zero `bypass_threshold` must exceed the normal approval threshold
+    /// and not exceed the signer count; 0 requires every snapshot signer.
+    /// Admin only.
+    pub fn set_timelock_bypass_config(
+        env: Env,
+        admin: Address,
+        bypass: TimelockBypassConfig,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+        let config = storage::get_config(&env)?;
+        if bypass.bypass_threshold > 0 {
+            if bypass.bypass_threshold <= config.threshold {
+                return Err(VaultError::ThresholdTooLow);
+            }
+            if bypass.bypass_threshold > config.signers.len() {
+                return Err(VaultError::ThresholdTooHigh);
+            }
+        }
+
+        storage::set_timelock_bypass_config(&env, &bypass);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Get the Critical-priority timelock bypass settings.
+    pub fn get_timelock_bypass_config(env: Env) -> TimelockBypassConfig {
+        storage::get_timelock_bypass_config(&env)
+    }
+
     /// Get currently configured voting strategy.
     pub fn get_voting_strategy(env: Env) -> VotingStrategy {
         storage::get_voting_strategy(&env)
@@ -2004,44 +4624,190 @@ impl VaultDAO {
     /// Returns quorum status for a proposal as (quorum_votes, required_quorum, quorum_reached).
     ///
     /// `quorum_votes` = number of approvals + abstentions cast so far.
-    /// `required_quorum` = the vault's configured quorum (0 means disabled).
+    /// `required_quorum` = the stricter of the creation snapshot and the live quorum (0 means disabled).
     /// `quorum_reached` = whether the quorum requirement is currently satisfied.
     pub fn get_quorum_status(env: Env, proposal_id: u64) -> Result<(u32, u32, bool), VaultError> {
         let config = storage::get_config(&env)?;
         let proposal = storage::get_proposal(&env, proposal_id)?;
 
         let quorum_votes = proposal.approvals.len() + proposal.abstentions.len();
-        let required_quorum = config.quorum;
+        let required_quorum = Self::required_quorum(&env, &config, &proposal);
         let quorum_reached = required_quorum == 0 || quorum_votes >= required_quorum;
 
         Ok((quorum_votes, required_quorum, quorum_reached))
     }
 
+    /// Velocity usage for `addr` as `(actions in window, seconds until a slot frees)`.
+    pub fn get_velocity_status(env: Env, addr: Address) -> Result<(u32, u64), VaultError> {
+        let config = storage::get_config(&env)?;
+        Ok(storage::get_velocity_status(
+            &env,
+            &addr,
+            &config.velocity_limit,
+        ))
+    }
+
+    /// Approvals and quorum votes a proposal needs, as `(threshold, quorum)`.
+    ///
+    /// Uses the same strategy evaluation (and creation-time snapshot floor)
+    /// as approval and execution, so clients do not have to reimplement it.
+    pub fn get_effective_threshold(env: Env, proposal_id: u64) -> Result<(u32, u32), VaultError> {
+        let config = storage::get_config(&env)?;
+        let proposal = storage::get_proposal(&env, proposal_id)?;
+        Ok((
+            Self::required_threshold(&env, &config, &proposal),
+            Self::required_quorum(&env, &config, &proposal),
+        ))
+    }
+
+    /// Preview the approval requirements after adding and removing signers.
+    ///
+    /// Evaluates every tier of the active threshold strategy against the
+    /// resulting signer count and flags tiers (and the quorum) that would
+    /// become unreachable. Adding an existing signer or removing a non-signer
+    /// has no effect. Nothing is changed.
+    pub fn preview_signer_change(
+        env: Env,
+        add: Vec<Address>,
+        remove: Vec<Address>,
+    ) -> Result<SignerChangePreview, VaultError> {
+        let config = storage::get_config(&env)?;
+
+        let mut signers = Vec::new(&env);
+        for signer in config.signers.iter() {
+            if !remove.contains(&signer) {
+                signers.push_back(signer);
+            }
+        }
+        for signer in add.iter() {
+            if !signers.contains(&signer) {
+                signers.push_back(signer);
+            }
+        }
+
+        let signer_count = signers.len();
+        let tier_thresholds = Self::strategy_tier_thresholds(&env, &config, signer_count);
+        let unreachable_tiers = Self::unreachable_tiers(&env, &tier_thresholds, signer_count);
+        let quorum_unreachable = config.quorum > signer_count;
+        let reachable = unreachable_tiers.is_empty() && !quorum_unreachable;
+
+        Ok(SignerChangePreview {
+            signer_count,
+            tier_thresholds,
+            unreachable_tiers,
+            quorum_unreachable,
+            reachable,
+        })
+    }
+
+    /// Approvals and quorum votes still missing, as `(approvals, quorum_votes)`.
+    pub fn get_required_remaining(env: Env, proposal_id: u64) -> Result<(u32, u32), VaultError> {
+        let config = storage::get_config(&env)?;
+        let proposal = storage::get_proposal(&env, proposal_id)?;
+        let quorum_votes = proposal.approvals.len() + proposal.abstentions.len();
+        let approvals = Self::threshold_approvals(&env, &proposal);
+        Ok((
+            Self::required_threshold(&env, &config, &proposal).saturating_sub(approvals),
+            Self::required_quorum(&env, &config, &proposal).saturating_sub(quorum_votes),
+        ))
+    }
+
+    /// Each snapshot signer's vote on a proposal, with the signer's kind so
+    /// UIs can render contract signers differently.
+    pub fn get_vote_summary(env: Env, proposal_id: u64) -> Result<Vec<SignerVote>, VaultError> {
+        let proposal = storage::get_proposal(&env, proposal_id)?;
+        let mut summary = Vec::new(&env);
+        for signer in proposal.snapshot_signers.iter() {
+            let (stage, stage_open) = Self::approval_stage(&env, &proposal, &signer);
+            summary.push_back(SignerVote {
+                kind: storage::get_signer_kind(&env, &signer),
+                approved: proposal.approvals.contains(&signer),
+                abstained: proposal.abstentions.contains(&signer),
+                signer,
+                stage,
+                stage_open,
+            });
+        }
+        Ok(summary)
+    }
+
+    /// Heuristic risk signal for approvers. Read-only.
+    ///
+    /// Flags an amount near the spending limit or above half the vault's
+    /// balance, a recipient the vault has never paid, a proposer below neutral
+    /// reputation, a proposal without attachments or metadata, one expiring
+    /// within a day, and a recipient whitelisted within the last day.
+    pub fn get_risk_assessment(env: Env, proposal_id: u64) -> Result<RiskAssessment, VaultError> {
+        let config = storage::get_config(&env)?;
+        let proposal = storage::get_proposal(&env, proposal_id)?;
+        let now = env.ledger().sequence() as u64;
+
+        let balance = token::balance(&env, &proposal.token);
+        let whitelisted_at = storage::get_whitelisted_at(&env, &proposal.recipient);
+        let mut risk = RiskAssessment {
+            score: 0,
+            near_spending_limit: proposal.amount
+                >= math::pct_of(config.spending_limit, RISK_SPENDING_LIMIT_PCT),
+            large_share_of_balance: proposal.amount > math::pct_of(balance, RISK_BALANCE_PCT),
+            new_recipient: !storage::is_paid_recipient(&env, &proposal.recipient),
+            low_reputation: storage::get_reputation(&env, &proposal.proposer).score
+                < RISK_NEUTRAL_REPUTATION,
+            undocumented: proposal.attachments.is_empty() && proposal.metadata.is_empty(),
+            expiring_soon: proposal.expires_at > 0
+                && proposal.expires_at.saturating_sub(now) < RISK_RECENT_LEDGERS,
+            recently_whitelisted: whitelisted_at
+                .is_some_and(|ledger| now.saturating_sub(ledger) < RISK_RECENT_LEDGERS),
+        };
+
+        for (raised, weight) in [
+            (risk.near_spending_limit, RISK_WEIGHT_SPENDING_LIMIT),
+            (risk.large_share_of_balance, RISK_WEIGHT_BALANCE),
+            (risk.new_recipient, RISK_WEIGHT_NEW_RECIPIENT),
+            (risk.low_reputation, RISK_WEIGHT_REPUTATION),
+            (risk.undocumented, RISK_WEIGHT_UNDOCUMENTED),
+            (risk.expiring_soon, RISK_WEIGHT_EXPIRY),
+            (risk.recently_whitelisted, RISK_WEIGHT_WHITELIST),
+        ] {
+            if raised {
+                risk.score += weight;
+            }
+        }
+        Ok(risk)
+    }
+
     /// Return proposal IDs that are currently executable.
     ///
     /// A proposal is considered executable when it is approved, not expired,
     /// timelock has elapsed, and all dependencies have been executed.
-    pub fn get_executable_proposals(env: Env) -> Vec<u64> {
+    /// Approved proposals that can be executed now: unexpired, past their
+    /// timelock and with every dependency executed.
+    ///
+    /// Pages over the approved-proposal index (`start`, at most 50 entries per
+    /// call), so a page may return fewer IDs than it examined.
+    pub fn get_executable_proposals(env: Env, start: u32, limit: u32) -> Vec<u64> {
         let mut executable = Vec::new(&env);
         let current_ledger = env.ledger().sequence() as u64;
-        let next_id = storage::get_next_proposal_id(&env);
+        let approved = storage::get_approved_index(&env);
+        let end = start
+            .saturating_add(limit.min(MAX_EXECUTABLE_PAGE_SIZE))
+            .min(approved.len());
+        if start >= end {
+            return executable;
+        }
 
-        for proposal_id in 1..next_id {
+        for proposal_id in approved.slice(start..end).iter() {
             let proposal = match storage::get_proposal(&env, proposal_id) {
                 Ok(p) => p,
                 Err(_) => continue,
             };
 
-            if proposal.status != ProposalStatus::Approved {
-                continue;
-            }
             if current_ledger > proposal.expires_at {
                 continue;
             }
             if proposal.unlock_ledger > 0 && current_ledger < proposal.unlock_ledger {
                 continue;
             }
-            if Self::ensure_dependencies_executable(&env, &proposal).is_err() {
+            if storage::get_unmet_dependencies(&env, proposal_id) > 0 {
                 continue;
             }
 
@@ -2051,6 +4817,58 @@ impl VaultDAO {
         executable
     }
 
+    // ========================================================================
+    // Contributions
+    // ========================================================================
+
+    /// Pull a committed contribution from `from` into the vault.
+    ///
+    /// Uses the allowance `from` granted the vault when it covers `amount`;
+    /// otherwise `from` must authorize the transfer directly. Requires the
+    /// `ManageFunding` permission. The pulled amount is added to the
+    /// contributor's running total for the token.
+    pub fn pull_contribution(
+        env: Env,
+        caller: Address,
+        from: Address,
+        token_addr: Address,
+        amount: i128,
+    ) -> Result<i128, VaultError> {
+        caller.require_auth();
+
+        if !Self::check_permission(&env, &caller, &types::Permission::ManageFunding) {
+            return Err(VaultError::InsufficientRole);
+        }
+        if amount <= 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+        if from == env.current_contract_address() {
+            return Err(VaultError::RecipientNotWhitelisted);
+        }
+
+        if token::allowance(&env, &token_addr, &from) >= amount {
+            token::transfer_from_allowance(&env, &token_addr, &from, amount);
+        } else {
+            if from != caller {
+                from.require_auth();
+            }
+            token::transfer_to_vault(&env, &token_addr, &from, amount);
+        }
+
+        let total = storage::add_contribution(&env, &from, &token_addr, amount);
+        storage::track_token(&env, &token_addr);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_contribution_pulled(&env, &from, &caller, &token_addr, amount, total);
+
+        Ok(total)
+    }
+
+    /// Total pulled from a contributor in `token_addr` via `pull_contribution`.
+    pub fn get_contribution(env: Env, contributor: Address, token_addr: Address) -> i128 {
+        storage::get_contribution(&env, &contributor, &token_addr)
+    }
+
     // ========================================================================
     // Recurring Payments
     // ========================================================================
@@ -2066,6 +4884,37 @@ impl VaultDAO {
         amount: i128,
         memo: Symbol,
         interval: u64,
+    ) -> Result<u64, VaultError> {
+        let no_conditions = Vec::new(&env);
+        Self::schedule_conditional_payment(
+            env,
+            proposer,
+            recipient,
+            token_addr,
+            amount,
+            memo,
+            interval,
+            no_conditions,
+            ConditionLogic::And,
+        )
+    }
+
+    /// Schedule a recurring payment that only pays while `conditions` hold.
+    ///
+    /// Conditions are evaluated on every run. A period whose conditions are
+    /// unmet is skipped: nothing is paid and the schedule moves forward by one
+    /// interval. An empty list behaves like `schedule_payment`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn schedule_conditional_payment(
+        env: Env,
+        proposer: Address,
+        recipient: Address,
+        token_addr: Address,
+        amount: i128,
+        memo: Symbol,
+        interval: u64,
+        conditions: Vec<Condition>,
+        condition_logic: ConditionLogic,
     ) -> Result<u64, VaultError> {
         proposer.require_auth();
 
@@ -2077,69 +4926,305 @@ impl VaultDAO {
         if amount <= 0 {
             return Err(VaultError::InvalidAmount);
         }
+        let config = storage::get_config(&env)?;
+        Self::ensure_memo_allowed(&env, &config, &memo)?;
 
         // Validate recipient against whitelist/blacklist policies
         Self::validate_recipient(&env, &recipient)?;
+        Self::validate_transfer_target(&env, &recipient, &token_addr)?;
 
         // Minimum interval check (e.g. 1 hour = 720 ledgers)
         if interval < 720 {
             return Err(VaultError::IntervalTooShort);
         }
 
-        let id = storage::increment_recurring_id(&env);
-        let current_ledger = env.ledger().sequence() as u64;
+        let id = storage::increment_recurring_id(&env);
+        let current_ledger = env.ledger().sequence() as u64;
+
+        let payment = crate::RecurringPayment {
+            id,
+            proposer: proposer.clone(),
+            recipient,
+            token: token_addr,
+            amount,
+            memo,
+            interval,
+            next_payment_ledger: current_ledger + interval,
+            payment_count: 0,
+            is_active: true,
+        };
+
+        storage::set_recurring_payment(&env, &payment);
+        storage::set_recurring_conditions(&env, id, &conditions, &condition_logic);
+        storage::index_recurring_payment(&env, &payment);
+
+        Ok(id)
+    }
+
+    /// Stop a scheduled payment that is paying the wrong recipient and record
+    /// an incident (Admin only).
+    ///
+    /// The payment is deactivated immediately so it can no longer execute.
+    /// The incident keeps the recipient and the total paid so far until it is
+    /// closed with `resolve_incident`.
+    pub fn flag_payment_incident(
+        env: Env,
+        admin: Address,
+        kind: IncidentKind,
+        id: u64,
+        note: Symbol,
+    ) -> Result<u64, VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+
+        let mut open = storage::get_open_incidents(&env);
+        if open.len() >= MAX_OPEN_INCIDENTS {
+            return Err(VaultError::BatchTooLarge);
+        }
+
+        let (recipient, token, amount_paid) = match kind {
+            IncidentKind::RecurringPayment => {
+                let mut payment = storage::get_recurring_payment(&env, id)?;
+                payment.is_active = false;
+                storage::set_recurring_payment(&env, &payment);
+                storage::unindex_recurring_payment(&env, id);
+                (
+                    payment.recipient,
+                    payment.token,
+                    payment.amount * payment.payment_count as i128,
+                )
+            }
+        };
+
+        let incident = Incident {
+            id: storage::increment_incident_id(&env),
+            kind,
+            target_id: id,
+            recipient,
+            token,
+            amount_paid,
+            flagged_by: admin,
+            flagged_at: env.ledger().sequence() as u64,
+            note,
+            resolved_at: 0,
+            resolution: Symbol::new(&env, ""),
+        };
+        storage::set_incident(&env, &incident);
+        open.push_back(incident.id);
+        storage::set_open_incidents(&env, &open);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_incident_flagged(&env, &incident);
+
+        Ok(incident.id)
+    }
+
+    /// Close a payment incident with a resolution note (Admin only). The
+    /// payment stays deactivated.
+    pub fn resolve_incident(
+        env: Env,
+        admin: Address,
+        incident_id: u64,
+        resolution: Symbol,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+
+        let mut incident = storage::get_incident(&env, incident_id)?;
+        if incident.resolved_at > 0 {
+            return Err(VaultError::ConditionsNotMet);
+        }
+        incident.resolved_at = env.ledger().sequence() as u64;
+        incident.resolution = resolution.clone();
+        storage::set_incident(&env, &incident);
+
+        let mut open = storage::get_open_incidents(&env);
+        if let Some(pos) = open.first_index_of(incident_id) {
+            open.remove(pos);
+            storage::set_open_incidents(&env, &open);
+        }
+        storage::extend_instance_ttl(&env);
+
+        events::emit_incident_resolved(&env, incident_id, &admin, &resolution);
+
+        Ok(())
+    }
+
+    /// Get a payment incident by ID.
+    pub fn get_incident(env: Env, incident_id: u64) -> Result<Incident, VaultError> {
+        storage::get_incident(&env, incident_id)
+    }
+
+    /// Unresolved payment incidents, oldest first.
+    pub fn list_open_incidents(env: Env) -> Vec<Incident> {
+        let mut incidents = Vec::new(&env);
+        for id in storage::get_open_incidents(&env).iter() {
+            if let Ok(incident) = storage::get_incident(&env, id) {
+                incidents.push_back(incident);
+            }
+        }
+        incidents
+    }
+
+    /// Restrict which keepers may trigger a recurring payment.
+    ///
+    /// Callable by the payment's proposer or an Admin. An empty list lets anyone
+    /// execute the payment once due.
+    ///
+    /// # Arguments
+    /// * `executors` - Keeper addresses, at most 10.
+    pub fn set_recurring_executors(
+        env: Env,
+        caller: Address,
+        payment_id: u64,
+        executors: Vec<Address>,
+    ) -> Result<(), VaultError> {
+        caller.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let payment = storage::get_recurring_payment(&env, payment_id)?;
+        if payment.proposer != caller && storage::get_role(&env, &caller) != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+        if executors.len() > MAX_ALLOWED_EXECUTORS {
+            return Err(VaultError::BatchTooLarge);
+        }
+
+        storage::set_recurring_executors(&env, payment_id, &executors);
+        storage::extend_instance_ttl(&env);
 
-        let payment = crate::RecurringPayment {
-            id,
-            proposer: proposer.clone(),
-            recipient,
-            token: token_addr,
-            amount,
-            memo,
-            interval,
-            next_payment_ledger: current_ledger + interval,
-            payment_count: 0,
-            is_active: true,
-        };
+        Ok(())
+    }
 
-        storage::set_recurring_payment(&env, &payment);
+    /// Get the keepers allowed to trigger a recurring payment (empty = anyone).
+    pub fn get_recurring_executors(env: Env, payment_id: u64) -> Vec<Address> {
+        storage::get_recurring_executors(&env, payment_id)
+    }
 
-        Ok(id)
+    /// Get the conditions a recurring payment checks on each run (empty = none).
+    pub fn get_recurring_conditions(env: Env, payment_id: u64) -> (Vec<Condition>, ConditionLogic) {
+        storage::get_recurring_conditions(&env, payment_id)
     }
 
     /// Execute a scheduled recurring payment
     ///
-    /// Can be called by anyone (keeper/bot) if the schedule is due.
-    pub fn execute_recurring_payment(env: Env, payment_id: u64) -> Result<(), VaultError> {
-        let mut payment = storage::get_recurring_payment(&env, payment_id)?;
+    /// Can be called by any keeper once the schedule is due, unless the payment
+    /// has an executor allow-list. The executor is recorded in the
+    /// `recurring_executed` event.
+    pub fn execute_recurring_payment(
+        env: Env,
+        executor: Address,
+        payment_id: u64,
+    ) -> Result<(), VaultError> {
+        executor.require_auth();
+        Self::execute_recurring_payment_internal(&env, &executor, payment_id)?;
+        storage::extend_instance_ttl(&env);
+        Ok(())
+    }
+
+    /// IDs of active recurring payments that are due now, earliest first.
+    pub fn get_due_recurring_payments(env: Env, limit: u32) -> Vec<u64> {
+        let current_ledger = env.ledger().sequence() as u64;
+        let cap = limit.min(MAX_DUE_PAYMENTS_PER_CALL);
+        let mut due = Vec::new(&env);
+        for (due_ledger, id) in storage::get_recurring_due_index(&env).iter() {
+            if due_ledger > current_ledger || due.len() >= cap {
+                break;
+            }
+            due.push_back(id);
+        }
+        due
+    }
+
+    /// Execute up to `max_count` due recurring payments in one call.
+    ///
+    /// Payments that fail (limits, balance, recipient policy) are skipped and
+    /// reported as `false`; the rest of the run continues.
+    pub fn execute_due_payments(env: Env, executor: Address, max_count: u32) -> Vec<(u64, bool)> {
+        executor.require_auth();
+        let due = Self::get_due_recurring_payments(env.clone(), max_count);
+        let mut outcomes = Vec::new(&env);
+        for id in due.iter() {
+            let ok = Self::execute_recurring_payment_internal(&env, &executor, id) == Ok(true);
+            outcomes.push_back((id, ok));
+        }
+        storage::extend_instance_ttl(&env);
+        outcomes
+    }
+
+    /// Run one due recurring payment. Returns `Ok(false)` when the period was
+    /// skipped because the payment's conditions were not met.
+    fn execute_recurring_payment_internal(
+        env: &Env,
+        executor: &Address,
+        payment_id: u64,
+    ) -> Result<bool, VaultError> {
+        let mut payment = storage::get_recurring_payment(env, payment_id)?;
 
         if !payment.is_active {
             return Err(VaultError::ProposalNotFound); // Or specific "NotActive" error
         }
 
+        let allowed = storage::get_recurring_executors(env, payment_id);
+        if !allowed.is_empty()
+            && !allowed.contains(executor)
+            && storage::get_role(env, executor) != Role::Admin
+        {
+            return Err(VaultError::Unauthorized);
+        }
+
         let current_ledger = env.ledger().sequence() as u64;
         if current_ledger < payment.next_payment_ledger {
             return Err(VaultError::TimelockNotExpired); // Reuse error for "Too Early"
         }
 
+        // Unmet conditions skip this period only: the schedule moves forward by
+        // a single interval and nothing is paid or counted against limits.
+        let (conditions, condition_logic) = storage::get_recurring_conditions(env, payment_id);
+        if !conditions.is_empty()
+            && Self::evaluate_conditions(env, &payment.token, &conditions, &condition_logic)
+                .is_err()
+        {
+            payment.next_payment_ledger += payment.interval;
+            storage::set_recurring_payment(env, &payment);
+            storage::index_recurring_payment(env, &payment);
+            events::emit_recurring_skipped(
+                env,
+                payment_id,
+                Symbol::new(env, "conditions_unmet"),
+                payment.next_payment_ledger,
+            );
+            return Ok(false);
+        }
+
         // Check spending limits (Daily & Weekly)
         // Note: Recurring payments count towards limits!
-        let config = storage::get_config(&env)?;
+        let config = storage::get_config(env)?;
 
-        let today = storage::get_day_number(&env);
-        let spent_today = storage::get_daily_spent(&env, today);
+        let today = storage::get_day_number(env);
+        let spent_today = storage::get_daily_spent(env, today);
         if spent_today + payment.amount > config.daily_limit {
             return Err(VaultError::ExceedsDailyLimit);
         }
 
-        let week = storage::get_week_number(&env);
-        let spent_week = storage::get_weekly_spent(&env, week);
+        let week = storage::get_week_number(env);
+        let spent_week = storage::get_weekly_spent(env, week);
         if spent_week + payment.amount > config.weekly_limit {
             return Err(VaultError::ExceedsWeeklyLimit);
         }
 
         // Check balance
-        let balance = token::balance(&env, &payment.token);
+        let balance = token::balance(env, &payment.token);
         if balance < payment.amount {
             return Err(VaultError::InsufficientBalance);
         }
@@ -2147,22 +5232,39 @@ impl VaultDAO {
         // Revalidate recipient against current whitelist/blacklist policies.
         // Policies may have changed since scheduling; block execution if the
         // recipient is no longer permitted.
-        Self::validate_recipient(&env, &payment.recipient)?;
+        Self::validate_recipient_at_execution(env, &payment.recipient)?;
 
         // Execute
-        token::transfer(&env, &payment.token, &payment.recipient, payment.amount);
+        token::transfer(env, &payment.token, &payment.recipient, payment.amount);
+        storage::record_outflow(env, &payment.token, payment.amount);
 
         // Update limits
-        storage::add_daily_spent(&env, today, payment.amount);
-        storage::add_weekly_spent(&env, week, payment.amount);
+        storage::add_daily_spent(env, today, payment.amount);
+        storage::add_weekly_spent(env, week, payment.amount);
 
         // Update payment schedule
+        let due_ledger = payment.next_payment_ledger;
         payment.next_payment_ledger += payment.interval;
         payment.payment_count += 1;
-        storage::set_recurring_payment(&env, &payment);
-        storage::extend_instance_ttl(&env);
+        storage::set_recurring_payment(env, &payment);
+        storage::index_recurring_payment(env, &payment);
 
-        Ok(())
+        // Credit the keeper and count the run like a proposal execution
+        Self::update_reputation_on_keeper_run(env, executor);
+        storage::metrics_on_execution(env, 0, current_ledger.saturating_sub(due_ledger));
+        storage::mark_recipient_paid(env, &payment.recipient);
+
+        events::emit_recurring_executed(
+            env,
+            payment_id,
+            executor,
+            payment.amount,
+            payment.payment_count,
+            payment.next_payment_ledger,
+            storage::get_total_outflow(env, &payment.token),
+        );
+
+        Ok(true)
     }
 
     /// Get a recurring payment by ID
@@ -2227,12 +5329,14 @@ impl VaultDAO {
         duration: u64,
     ) -> Result<u64, VaultError> {
         sender.require_auth();
+        Self::ensure_initialized(&env)?;
 
         if amount <= 0 || duration == 0 {
             return Err(VaultError::InvalidAmount);
         }
 
         // Validate recipient against lists
+        Self::validate_transfer_target(&env, &recipient, &token_addr)?;
 
         let id = storage::increment_stream_id(&env);
         let now = env.ledger().timestamp();
@@ -2249,6 +5353,7 @@ impl VaultDAO {
             rate,
             total_amount: amount,
             claimed_amount: 0,
+            escrow_remaining: amount,
             start_timestamp: now,
             end_timestamp: now + duration,
             last_update_timestamp: now,
@@ -2256,21 +5361,474 @@ impl VaultDAO {
             status: StreamStatus::Active,
         };
 
-        storage::set_streaming_payment(&env, &stream);
+        storage::set_streaming_payment(&env, &stream);
+        storage::add_sender_stream(&env, &sender, id);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_stream_created(
+            &env,
+            id,
+            &sender,
+            &stream.recipient,
+            &token_addr,
+            amount,
+            rate,
+        );
+
+        Ok(id)
+    }
+
+    /// Claim the tokens a stream has vested so far.
+    ///
+    /// Only the stream recipient may claim. Vesting runs at the stream rate
+    /// while the stream is active and the full amount is vested at its end.
+    /// Recipient lists are re-checked as for any other payout; fails with
+    /// `InvalidAmount` when nothing is claimable.
+    ///
+    /// # Returns
+    /// The amount transferred to the recipient.
+    pub fn claim_stream(env: Env, recipient: Address, stream_id: u64) -> Result<i128, VaultError> {
+        recipient.require_auth();
+        Self::ensure_initialized(&env)?;
+        Self::ensure_not_paused(&env)?;
+
+        let mut stream = storage::get_streaming_payment(&env, stream_id)?;
+        if stream.recipient != recipient {
+            return Err(VaultError::Unauthorized);
+        }
+        if !matches!(stream.status, StreamStatus::Active | StreamStatus::Paused) {
+            return Err(VaultError::InvalidAmount);
+        }
+        Self::validate_recipient_at_execution(&env, &recipient)?;
+
+        let vested = Self::accrue_stream(&env, &mut stream);
+        let amount = vested - stream.claimed_amount;
+        if amount <= 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+
+        storage::debit_stream_escrow(&env, &mut stream, amount)?;
+        token::transfer(&env, &stream.token_addr, &recipient, amount);
+        stream.claimed_amount = vested;
+        if stream.claimed_amount == stream.total_amount {
+            stream.status = StreamStatus::Completed;
+            events::emit_stream_status_updated(
+                &env,
+                stream_id,
+                StreamStatus::Completed as u32,
+                &recipient,
+            );
+        }
+        storage::set_streaming_payment(&env, &stream);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_stream_claimed(&env, stream_id, &recipient, amount);
+
+        Ok(amount)
+    }
+
+    /// Cancel a stream, paying the recipient what has vested and refunding
+    /// the rest of the escrow to the sender.
+    ///
+    /// Only the sender may cancel. If the recipient lists no longer allow the
+    /// recipient, the vested part is refunded to the sender as well.
+    ///
+    /// # Returns
+    /// The amount refunded to the sender.
+    pub fn cancel_stream(env: Env, sender: Address, stream_id: u64) -> Result<i128, VaultError> {
+        sender.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let mut stream = storage::get_streaming_payment(&env, stream_id)?;
+        if stream.sender != sender {
+            return Err(VaultError::Unauthorized);
+        }
+        if !matches!(stream.status, StreamStatus::Active | StreamStatus::Paused) {
+            return Err(VaultError::ProposalAlreadyCancelled);
+        }
+
+        let vested = Self::accrue_stream(&env, &mut stream);
+        let owed = vested - stream.claimed_amount;
+        if owed > 0 && Self::validate_recipient_at_execution(&env, &stream.recipient).is_ok() {
+            storage::debit_stream_escrow(&env, &mut stream, owed)?;
+            token::transfer(&env, &stream.token_addr, &stream.recipient, owed);
+            stream.claimed_amount = vested;
+            events::emit_stream_claimed(&env, stream_id, &stream.recipient, owed);
+        }
+        let refund = stream.escrow_remaining;
+        storage::debit_stream_escrow(&env, &mut stream, refund)?;
+        if refund > 0 {
+            token::transfer(&env, &stream.token_addr, &sender, refund);
+        }
+
+        stream.status = StreamStatus::Cancelled;
+        storage::set_streaming_payment(&env, &stream);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_stream_status_updated(
+            &env,
+            stream_id,
+            StreamStatus::Cancelled as u32,
+            &sender,
+        );
+
+        Ok(refund)
+    }
+
+    /// Bring a stream's active time up to now and return the total vested so far.
+    fn accrue_stream(env: &Env, stream: &mut StreamingPayment) -> i128 {
+        let now = env.ledger().timestamp();
+        if stream.status == StreamStatus::Active {
+            let elapsed = now
+                .min(stream.end_timestamp)
+                .saturating_sub(stream.last_update_timestamp);
+            stream.accumulated_seconds += elapsed;
+            stream.last_update_timestamp = now;
+        }
+        let duration = stream.end_timestamp - stream.start_timestamp;
+        if stream.accumulated_seconds >= duration {
+            stream.total_amount
+        } else {
+            (stream.rate * stream.accumulated_seconds as i128).min(stream.total_amount)
+        }
+    }
+
+    /// Get a stream by ID.
+    pub fn get_stream(env: Env, stream_id: u64) -> Result<StreamingPayment, VaultError> {
+        storage::get_streaming_payment(&env, stream_id)
+    }
+
+    /// Total escrow still held for `sender`'s streams of `token_addr`.
+    ///
+    /// Claims and cancellations debit each stream's `escrow_remaining`, so
+    /// cancelled and fully claimed streams contribute nothing.
+    pub fn get_sender_liability(env: Env, sender: Address, token_addr: Address) -> i128 {
+        let mut total = 0i128;
+        for id in storage::get_sender_streams(&env, &sender).iter() {
+            if let Ok(stream) = storage::get_streaming_payment(&env, id) {
+                if stream.token_addr == token_addr {
+                    total = total.saturating_add(stream.escrow_remaining);
+                }
+            }
+        }
+        total
+    }
+
+    // ========================================================================
+    // Subscriptions
+    // ========================================================================
+
+    /// Subscribe to a service provider, paid every `interval_ledgers`.
+    ///
+    /// With `SubscriptionFundingSource::Vault` the vault pays, so only
+    /// Treasurer or Admin can subscribe and each period counts towards the
+    /// daily and weekly limits. With `Subscriber` each renewal pulls the
+    /// period from the subscriber's allowance to the vault and passes it on
+    /// to the provider, so no role is needed. The provider must pass the
+    /// recipient lists. The first period is due immediately; periods are
+    /// collected by `renew_subscription`. Without `auto_renew` only the
+    /// subscriber may renew.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_subscription(
+        env: Env,
+        subscriber: Address,
+        service_provider: Address,
+        tier: SubscriptionTier,
+        token_addr: Address,
+        amount_per_period: i128,
+        interval_ledgers: u64,
+        auto_renew: bool,
+        funding_source: SubscriptionFundingSource,
+    ) -> Result<u64, VaultError> {
+        subscriber.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        if funding_source == SubscriptionFundingSource::Vault {
+            let role = storage::get_role(&env, &subscriber);
+            if role != Role::Treasurer && role != Role::Admin {
+                return Err(VaultError::InsufficientRole);
+            }
+        }
+        if amount_per_period <= 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+        if interval_ledgers < 720 {
+            return Err(VaultError::IntervalTooShort);
+        }
+        Self::validate_recipient(&env, &service_provider)?;
+        Self::validate_transfer_target(&env, &service_provider, &token_addr)?;
+        Self::ensure_token_contract(&env, &token_addr)?;
+
+        let current_ledger = env.ledger().sequence() as u64;
+        let subscription = Subscription {
+            id: storage::increment_subscription_id(&env),
+            subscriber: subscriber.clone(),
+            service_provider: service_provider.clone(),
+            tier: tier.clone(),
+            token: token_addr,
+            amount_per_period,
+            interval_ledgers,
+            next_renewal_ledger: current_ledger,
+            created_at: current_ledger,
+            status: SubscriptionStatus::Active,
+            total_payments: 0,
+            last_payment_ledger: 0,
+            auto_renew,
+            funding_source: funding_source.clone(),
+        };
+        storage::set_subscription(&env, &subscription);
+        storage::index_subscription(&env, &subscription);
+        storage::add_provider_subscription(&env, &service_provider, subscription.id);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_subscription_created(
+            &env,
+            subscription.id,
+            &subscriber,
+            tier as u32,
+            amount_per_period,
+            funding_source as u32,
+        );
+
+        Ok(subscription.id)
+    }
+
+    /// Pay the provider for the subscription's next period once it is due.
+    ///
+    /// Any keeper may renew an auto-renewing subscription; otherwise only the
+    /// subscriber may. Fails with `TimelockNotExpired` before the renewal
+    /// ledger and `ProposalAlreadyCancelled` once the subscription is no
+    /// longer active. The provider is re-checked against the recipient lists
+    /// unless execution-time list checks are disabled.
+    pub fn renew_subscription(
+        env: Env,
+        caller: Address,
+        subscription_id: u64,
+    ) -> Result<(), VaultError> {
+        caller.require_auth();
+        Self::ensure_initialized(&env)?;
+        Self::renew_subscription_internal(&env, Some(&caller), subscription_id)?;
+        storage::extend_instance_ttl(&env);
+        Ok(())
+    }
+
+    /// IDs of keeper-renewable subscriptions that are due now, earliest first.
+    ///
+    /// Only active subscriptions with `auto_renew` are listed.
+    pub fn get_due_subscriptions(env: Env, limit: u32) -> Vec<u64> {
+        let current_ledger = env.ledger().sequence() as u64;
+        let cap = limit.min(MAX_DUE_SUBSCRIPTIONS_PER_CALL);
+        let mut due = Vec::new(&env);
+        for (due_ledger, id) in storage::get_subscription_due_index(&env).iter() {
+            if due_ledger > current_ledger || due.len() >= cap {
+                break;
+            }
+            due.push_back(id);
+        }
+        due
+    }
+
+    /// Renew up to `max_count` due subscriptions in one call. Permissionless.
+    ///
+    /// Renewals that fail (limits, balance, recipient policy) are reported as
+    /// `false` and stay due; the rest of the run continues.
+    pub fn renew_due_subscriptions(env: Env, max_count: u32) -> Vec<(u64, bool)> {
+        let due = Self::get_due_subscriptions(env.clone(), max_count);
+        let mut outcomes = Vec::new(&env);
+        for id in due.iter() {
+            let ok = Self::renew_subscription_internal(&env, None, id).is_ok();
+            outcomes.push_back((id, ok));
+        }
+        storage::extend_instance_ttl(&env);
+        outcomes
+    }
+
+    /// Renew one subscription. `caller` is `None` for the keeper crank, which
+    /// may only renew auto-renewing subscriptions.
+    fn renew_subscription_internal(
+        env: &Env,
+        caller: Option<&Address>,
+        subscription_id: u64,
+    ) -> Result<(), VaultError> {
+        let mut subscription = storage::get_subscription(env, subscription_id)?;
+        if subscription.status != SubscriptionStatus::Active {
+            return Err(VaultError::ProposalAlreadyCancelled);
+        }
+        if !subscription.auto_renew && caller != Some(&subscription.subscriber) {
+            return Err(VaultError::Unauthorized);
+        }
+        let current_ledger = env.ledger().sequence() as u64;
+        if current_ledger < subscription.next_renewal_ledger {
+            return Err(VaultError::TimelockNotExpired);
+        }
+        Self::validate_recipient_at_execution(env, &subscription.service_provider)?;
+
+        let amount = subscription.amount_per_period;
+        match subscription.funding_source {
+            SubscriptionFundingSource::Vault => {
+                // Vault-funded renewals count towards limits like recurring payments
+                let config = storage::get_config(env)?;
+                let today = storage::get_day_number(env);
+                if storage::get_daily_spent(env, today) + amount > config.daily_limit {
+                    return Err(VaultError::ExceedsDailyLimit);
+                }
+                let week = storage::get_week_number(env);
+                if storage::get_weekly_spent(env, week) + amount > config.weekly_limit {
+                    return Err(VaultError::ExceedsWeeklyLimit);
+                }
+                if token::balance(env, &subscription.token) < amount {
+                    return Err(VaultError::InsufficientBalance);
+                }
+                token::transfer(
+                    env,
+                    &subscription.token,
+                    &subscription.service_provider,
+                    amount,
+                );
+                storage::record_outflow(env, &subscription.token, amount);
+                storage::add_daily_spent(env, today, amount);
+                storage::add_weekly_spent(env, week, amount);
+            }
+            SubscriptionFundingSource::Subscriber => {
+                // Passes through the vault without touching its own funds
+                token::try_transfer_from_allowance(
+                    env,
+                    &subscription.token,
+                    &subscription.subscriber,
+                    amount,
+                )
+                .map_err(|_| VaultError::InsufficientBalance)?;
+                token::transfer(
+                    env,
+                    &subscription.token,
+                    &subscription.service_provider,
+                    amount,
+                );
+            }
+        }
+        storage::mark_recipient_paid(env, &subscription.service_provider);
+
+        subscription.total_payments += 1;
+        subscription.last_payment_ledger = current_ledger;
+        subscription.next_renewal_ledger += subscription.interval_ledgers;
+        storage::set_subscription(env, &subscription);
+        storage::index_subscription(env, &subscription);
+        let revenue = storage::add_provider_revenue(
+            env,
+            &subscription.service_provider,
+            &subscription.token,
+            amount,
+        );
+
+        events::emit_subscription_renewed(
+            env,
+            subscription_id,
+            subscription.total_payments,
+            amount,
+            revenue,
+            subscription.funding_source as u32,
+        );
+
+        Ok(())
+    }
+
+    /// Cancel a subscription. Callable by the subscriber or an Admin.
+    ///
+    /// The subscription stays listed under its provider.
+    pub fn cancel_subscription(
+        env: Env,
+        caller: Address,
+        subscription_id: u64,
+    ) -> Result<(), VaultError> {
+        caller.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let mut subscription = storage::get_subscription(&env, subscription_id)?;
+        if caller != subscription.subscriber && storage::get_role(&env, &caller) != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+        if subscription.status == SubscriptionStatus::Cancelled {
+            return Err(VaultError::ProposalAlreadyCancelled);
+        }
+
+        subscription.status = SubscriptionStatus::Cancelled;
+        storage::set_subscription(&env, &subscription);
+        storage::index_subscription(&env, &subscription);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_subscription_cancelled(&env, subscription_id, &caller);
+
+        Ok(())
+    }
+
+    /// Suspend renewals for an active subscription. Callable by the subscriber or an Admin.
+    pub fn pause_subscription(
+        env: Env,
+        caller: Address,
+        subscription_id: u64,
+    ) -> Result<(), VaultError> {
+        caller.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let mut subscription = storage::get_subscription(&env, subscription_id)?;
+        if caller != subscription.subscriber && storage::get_role(&env, &caller) != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+        if subscription.status != SubscriptionStatus::Active {
+            return Err(VaultError::ProposalAlreadyCancelled);
+        }
+
+        subscription.status = SubscriptionStatus::Suspended;
+        storage::set_subscription(&env, &subscription);
+        storage::index_subscription(&env, &subscription);
+        storage::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Resume a suspended subscription. Periods missed while suspended are not
+    /// charged; the next renewal is due no earlier than now.
+    pub fn resume_subscription(
+        env: Env,
+        caller: Address,
+        subscription_id: u64,
+    ) -> Result<(), VaultError> {
+        caller.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let mut subscription = storage::get_subscription(&env, subscription_id)?;
+        if caller != subscription.subscriber && storage::get_role(&env, &caller) != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+        if subscription.status != SubscriptionStatus::Suspended {
+            return Err(VaultError::ProposalAlreadyCancelled);
+        }
+
+        let current_ledger = env.ledger().sequence() as u64;
+        subscription.status = SubscriptionStatus::Active;
+        subscription.next_renewal_ledger = subscription.next_renewal_ledger.max(current_ledger);
+        storage::set_subscription(&env, &subscription);
+        storage::index_subscription(&env, &subscription);
         storage::extend_instance_ttl(&env);
 
-        events::emit_stream_created(
-            &env,
-            id,
-            &sender,
-            &stream.recipient,
-            &token_addr,
-            amount,
-            rate,
-        );
+        Ok(())
+    }
 
-        Ok(id)
+    /// Get a subscription by ID.
+    pub fn get_subscription(env: Env, subscription_id: u64) -> Result<Subscription, VaultError> {
+        storage::get_subscription(&env, subscription_id)
+    }
+
+    /// Get all subscription IDs billed to a service provider, including cancelled ones.
+    pub fn get_provider_subscriptions(env: Env, provider: Address) -> Vec<u64> {
+        storage::get_provider_subscriptions(&env, &provider)
+    }
+
+    /// Get the lifetime revenue a provider has received in `token_addr` from renewals.
+    pub fn get_provider_revenue(env: Env, provider: Address, token_addr: Address) -> i128 {
+        storage::get_provider_revenue(&env, &provider, &token_addr)
     }
+
     // ========================================================================
     // Recipient List Management
     // ========================================================================
@@ -2280,6 +5838,7 @@ impl VaultDAO {
     /// Only Admin can change the list mode.
     pub fn set_list_mode(env: Env, admin: Address, mode: ListMode) -> Result<(), VaultError> {
         admin.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
@@ -2302,6 +5861,7 @@ impl VaultDAO {
     /// Only Admin can add to whitelist.
     pub fn add_to_whitelist(env: Env, admin: Address, addr: Address) -> Result<(), VaultError> {
         admin.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
@@ -2327,6 +5887,7 @@ impl VaultDAO {
         addr: Address,
     ) -> Result<(), VaultError> {
         admin.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
@@ -2348,55 +5909,500 @@ impl VaultDAO {
         storage::is_whitelisted(&env, &addr)
     }
 
+    /// Record `addr` under `label` in the address book (Admin only).
+    ///
+    /// Labels are unique; re-using one fails with `AddressAlreadyOnList`.
+    pub fn add_address_book_entry(
+        env: Env,
+        admin: Address,
+        addr: Address,
+        label: Symbol,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+
+        let mut entries = storage::get_address_book(&env);
+        if entries.iter().any(|entry| entry.label == label) {
+            return Err(VaultError::AddressAlreadyOnList);
+        }
+        if entries.len() >= MAX_ADDRESS_BOOK_ENTRIES {
+            return Err(VaultError::BatchTooLarge);
+        }
+        entries.push_back(AddressBookEntry {
+            label,
+            address: addr,
+            added_at: env.ledger().sequence() as u64,
+        });
+        storage::set_address_book(&env, &entries);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Remove the entry recorded under `label` (Admin only). Proposals already
+    /// created keep the resolved address.
+    pub fn remove_address_book_entry(
+        env: Env,
+        admin: Address,
+        label: Symbol,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+
+        let mut entries = storage::get_address_book(&env);
+        let pos = entries
+            .iter()
+            .position(|entry| entry.label == label)
+            .ok_or(VaultError::AddressNotOnList)?;
+        entries.remove(pos as u32);
+        storage::set_address_book(&env, &entries);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Get address book entries in insertion order (paginated, up to 50).
+    pub fn get_address_book(env: Env, start: u32, limit: u32) -> Vec<AddressBookEntry> {
+        let entries = storage::get_address_book(&env);
+        let end = start
+            .saturating_add(limit.min(MAX_ADDRESS_BOOK_ENTRIES))
+            .min(entries.len());
+        if start >= end {
+            return Vec::new(&env);
+        }
+        entries.slice(start..end)
+    }
+
+    /// Treat address book entries as whitelisted while in Whitelist mode
+    /// (Admin only). Off by default.
+    pub fn set_address_book_whitelisted(
+        env: Env,
+        admin: Address,
+        enabled: bool,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+
+        storage::set_address_book_whitelisted(&env, enabled);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
     /// Add an address to the blacklist
     ///
     /// Only Admin can add to blacklist.
     pub fn add_to_blacklist(env: Env, admin: Address, addr: Address) -> Result<(), VaultError> {
         admin.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+
+        if storage::is_blacklisted(&env, &addr) {
+            return Err(VaultError::AddressAlreadyOnList);
+        }
+
+        storage::add_to_blacklist(&env, &addr);
+        storage::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Remove an address from the blacklist
+    ///
+    /// Only Admin can remove from blacklist.
+    pub fn remove_from_blacklist(
+        env: Env,
+        admin: Address,
+        addr: Address,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+
+        if !storage::is_blacklisted(&env, &addr) {
+            return Err(VaultError::AddressNotOnList);
+        }
+
+        storage::remove_from_blacklist(&env, &addr);
+        storage::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Check if an address is blacklisted
+    pub fn is_blacklisted(env: Env, addr: Address) -> bool {
+        storage::is_blacklisted(&env, &addr)
+    }
+
+    /// Add an address to the whitelist until `expires_at_ledger`.
+    ///
+    /// Only Admin can add. Once the ledger passes the expiry the entry is treated
+    /// as absent; re-adding an expired entry overwrites it.
+    pub fn add_to_whitelist_with_expiry(
+        env: Env,
+        admin: Address,
+        addr: Address,
+        expires_at_ledger: u64,
+    ) -> Result<(), VaultError> {
+        Self::add_list_entry_with_expiry(
+            &env,
+            &admin,
+            ListMode::Whitelist,
+            &addr,
+            expires_at_ledger,
+        )
+    }
+
+    /// Add an address to the blacklist until `expires_at_ledger` (temporary sanction).
+    ///
+    /// Only Admin can add.
+    pub fn add_to_blacklist_with_expiry(
+        env: Env,
+        admin: Address,
+        addr: Address,
+        expires_at_ledger: u64,
+    ) -> Result<(), VaultError> {
+        Self::add_list_entry_with_expiry(
+            &env,
+            &admin,
+            ListMode::Blacklist,
+            &addr,
+            expires_at_ledger,
+        )
+    }
+
+    fn add_list_entry_with_expiry(
+        env: &Env,
+        admin: &Address,
+        mode: ListMode,
+        addr: &Address,
+        expires_at_ledger: u64,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+
+        let role = storage::get_role(env, admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+
+        if expires_at_ledger <= env.ledger().sequence() as u64 {
+            return Err(VaultError::InvalidAmount);
+        }
+
+        let already_listed = match mode {
+            ListMode::Blacklist => storage::is_blacklisted(env, addr),
+            _ => storage::is_whitelisted(env, addr),
+        };
+        if already_listed {
+            return Err(VaultError::AddressAlreadyOnList);
+        }
+
+        storage::add_list_entry(env, &mode, addr, expires_at_ledger);
+        storage::extend_instance_ttl(env);
+
+        events::emit_list_entry_added(env, &mode, addr, expires_at_ledger);
+
+        Ok(())
+    }
+
+    /// Get the ledger at which a list entry expires (0 = permanent or not listed).
+    pub fn get_list_entry_expiry(env: Env, mode: ListMode, addr: Address) -> u64 {
+        storage::get_list_expiry(&env, &mode, &addr)
+    }
+
+    /// Get all addresses stored on a list, including entries not yet pruned.
+    pub fn get_list_entries(env: Env, mode: ListMode) -> Vec<Address> {
+        storage::get_list_index(&env, &mode)
+    }
+
+    /// Remove expired whitelist/blacklist entries for the given addresses.
+    ///
+    /// Permissionless housekeeping: expired entries already behave as absent, this
+    /// just clears them from storage and the enumerable index. Returns the number
+    /// of entries removed.
+    pub fn prune_expired_list_entries(env: Env, addrs: Vec<Address>) -> u32 {
+        let mut pruned = 0u32;
+        for addr in addrs.iter() {
+            for mode in [ListMode::Whitelist, ListMode::Blacklist] {
+                if storage::prune_list_entry(&env, &mode, &addr) {
+                    events::emit_list_entry_pruned(&env, &mode, &addr);
+                    pruned += 1;
+                }
+            }
+        }
+        if pruned > 0 {
+            storage::extend_instance_ttl(&env);
+        }
+        pruned
+    }
+
+    /// Choose whether recipient lists are re-checked at execution time.
+    ///
+    /// Enabled by default: a recipient blacklisted (or removed from the whitelist)
+    /// after a proposal or recurring payment was created can no longer be paid.
+    /// Vaults that prefer proposal-time-only checks can disable it. Admin only.
+    pub fn set_execution_list_check(
+        env: Env,
+        admin: Address,
+        enabled: bool,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+
+        storage::set_execution_list_check(&env, enabled);
+        storage::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Allow the vault or a token contract to be used as a transfer recipient.
+    ///
+    /// Transfers to the vault itself or to the token being moved are rejected
+    /// by default; this opts a specific address back in for intentional
+    /// self-transfers. Admin only.
+    pub fn allow_self_transfer(env: Env, admin: Address, addr: Address) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+
+        let mut allowlist = storage::get_self_transfer_allowlist(&env);
+        if allowlist.contains(&addr) {
+            return Err(VaultError::AddressAlreadyOnList);
+        }
+        allowlist.push_back(addr);
+        storage::set_self_transfer_allowlist(&env, &allowlist);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Revoke an address previously allowed via `allow_self_transfer`. Admin only.
+    pub fn disallow_self_transfer(
+        env: Env,
+        admin: Address,
+        addr: Address,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
 
-        if storage::is_blacklisted(&env, &addr) {
-            return Err(VaultError::AddressAlreadyOnList);
-        }
+        let mut allowlist = storage::get_self_transfer_allowlist(&env);
+        let index = allowlist
+            .first_index_of(&addr)
+            .ok_or(VaultError::AddressNotOnList)?;
+        allowlist.remove(index);
+        storage::set_self_transfer_allowlist(&env, &allowlist);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Addresses allowed as recipients even though they are the vault or a token.
+    pub fn get_self_transfer_allowlist(env: Env) -> Vec<Address> {
+        storage::get_self_transfer_allowlist(&env)
+    }
+
+    /// Whether recipient lists are re-checked at execution time.
+    pub fn get_execution_list_check(env: Env) -> bool {
+        storage::get_execution_list_check(&env)
+    }
+
+    /// Re-run recipient list validation at execution time, if enabled.
+    fn validate_recipient_at_execution(env: &Env, recipient: &Address) -> Result<(), VaultError> {
+        if storage::get_execution_list_check(env) {
+            Self::validate_recipient(env, recipient)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Re-validate every recipient a proposal will pay (all splits, if any).
+    fn validate_payout_recipients(env: &Env, proposal: &Proposal) -> Result<(), VaultError> {
+        match storage::get_split_transfer(env, proposal.id) {
+            Some(splits) => {
+                for (recipient, _) in splits.iter() {
+                    Self::validate_recipient_at_execution(env, &recipient)?;
+                }
+                Ok(())
+            }
+            None => Self::validate_recipient_at_execution(env, &proposal.recipient),
+        }
+    }
+
+    /// Pay out a proposal: one transfer, or one per split for split proposals.
+    fn transfer_payout(env: &Env, proposal: &Proposal) -> Result<(), VaultError> {
+        if proposal.is_swap {
+            return Self::perform_swap(env, proposal);
+        }
+        match storage::get_split_transfer(env, proposal.id) {
+            Some(splits) => {
+                for (recipient, amount) in splits.iter() {
+                    if token::try_transfer(env, &proposal.token, &recipient, amount).is_err() {
+                        return Err(VaultError::TransferFailed);
+                    }
+                }
+                Ok(())
+            }
+            None => {
+                if token::try_transfer(env, &proposal.token, &proposal.recipient, proposal.amount)
+                    .is_err()
+                {
+                    return Err(VaultError::TransferFailed);
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Whether the proposal is large enough to need a second look.
+    fn second_look_required(env: &Env, config: &Config, proposal: &Proposal) -> bool {
+        let multiple = storage::get_second_look_multiple(env);
+        multiple > 0
+            && proposal.amount >= config.timelock_threshold.saturating_mul(multiple as i128)
+    }
 
-        storage::add_to_blacklist(&env, &addr);
-        storage::extend_instance_ttl(&env);
+    /// Whether execution is still waiting for a required second look.
+    fn second_look_pending(env: &Env, config: &Config, proposal: &Proposal) -> bool {
+        storage::get_second_look(env, proposal.id).is_none()
+            && Self::second_look_required(env, config, proposal)
+    }
 
-        Ok(())
+    /// Enforce a proposal's executor allow-list. Admins may always execute.
+    fn ensure_executor_allowed(
+        env: &Env,
+        proposal_id: u64,
+        executor: &Address,
+    ) -> Result<(), VaultError> {
+        let allowed = storage::get_allowed_executors(env, proposal_id);
+        if allowed.is_empty()
+            || allowed.contains(executor)
+            || storage::get_role(env, executor) == Role::Admin
+        {
+            Ok(())
+        } else {
+            Err(VaultError::Unauthorized)
+        }
     }
 
-    /// Remove an address from the blacklist
+    /// Reject transfers to the vault itself or to the token contract being moved.
     ///
-    /// Only Admin can remove from blacklist.
-    pub fn remove_from_blacklist(
-        env: Env,
-        admin: Address,
-        addr: Address,
+    /// Such proposals are no-ops or lock funds, so they are refused unless the
+    /// address was explicitly allowed by an admin. Returns
+    /// `RecipientNotWhitelisted` for a disallowed target.
+    fn validate_transfer_target(
+        env: &Env,
+        recipient: &Address,
+        token_addr: &Address,
     ) -> Result<(), VaultError> {
-        admin.require_auth();
-
-        let role = storage::get_role(&env, &admin);
-        if role != Role::Admin {
-            return Err(VaultError::Unauthorized);
+        let is_self = *recipient == env.current_contract_address() || recipient == token_addr;
+        if is_self && !storage::get_self_transfer_allowlist(env).contains(recipient) {
+            return Err(VaultError::RecipientNotWhitelisted);
         }
+        Ok(())
+    }
 
-        if !storage::is_blacklisted(&env, &addr) {
-            return Err(VaultError::AddressNotOnList);
+    /// Check an approval order: at most `MAX_APPROVAL_STAGES` non-empty stages
+    /// of current signers, each signer in at most one stage.
+    fn validate_approval_order(env: &Env, stages: &Vec<Vec<Address>>) -> Result<(), VaultError> {
+        if stages.len() > MAX_APPROVAL_STAGES {
+            return Err(VaultError::BatchTooLarge);
+        }
+        let config = storage::get_config(env)?;
+        let mut seen: Vec<Address> = Vec::new(env);
+        for stage in stages.iter() {
+            if stage.is_empty() {
+                return Err(VaultError::InvalidAmount);
+            }
+            for signer in stage.iter() {
+                if !config.signers.contains(&signer) {
+                    return Err(VaultError::NotASigner);
+                }
+                if seen.contains(&signer) {
+                    return Err(VaultError::AddressAlreadyOnList);
+                }
+                seen.push_back(signer);
+            }
         }
-
-        storage::remove_from_blacklist(&env, &addr);
-        storage::extend_instance_ttl(&env);
-
         Ok(())
     }
 
-    /// Check if an address is blacklisted
-    pub fn is_blacklisted(env: Env, addr: Address) -> bool {
-        storage::is_blacklisted(&env, &addr)
+    /// Stage of `signer` in the proposal's approval order (from 1, 0 =
+    /// unstaged) and whether every earlier stage already has an approval.
+    fn approval_stage(env: &Env, proposal: &Proposal, signer: &Address) -> (u32, bool) {
+        let stages = storage::get_approval_order(env, proposal.id);
+        for (i, stage) in stages.iter().enumerate() {
+            if stage.contains(signer) {
+                let open = stages.iter().take(i).all(|earlier| {
+                    earlier
+                        .iter()
+                        .any(|member| proposal.approvals.contains(&member))
+                });
+                return (i as u32 + 1, open);
+            }
+        }
+        (0, true)
+    }
+
+    /// Probe a proposal's token with `decimals()` so a mis-pasted address is
+    /// refused at proposal time instead of failing at execution. Positive
+    /// results are cached, so each token is probed once. Returns
+    /// `InvalidAmount`, a validation error, for an address that does not
+    /// answer like a token.
+    fn ensure_token_contract(env: &Env, token_addr: &Address) -> Result<(), VaultError> {
+        if storage::is_token_verified(env, token_addr) {
+            return Ok(());
+        }
+        if !token::is_token_contract(env, token_addr) {
+            return Err(VaultError::InvalidAmount);
+        }
+        storage::set_token_verified(env, token_addr);
+        Ok(())
     }
 
     /// Validate if a recipient is allowed based on current list mode
@@ -2406,7 +6412,7 @@ impl VaultDAO {
         match mode {
             ListMode::Disabled => Ok(()),
             ListMode::Whitelist => {
-                if storage::is_whitelisted(env, recipient) {
+                if storage::is_whitelisted_recipient(env, recipient) {
                     Ok(())
                 } else {
                     Err(VaultError::RecipientNotWhitelisted)
@@ -2427,26 +6433,80 @@ impl VaultDAO {
     // ========================================================================
 
     /// Add a comment to a proposal
+    ///
+    /// `mentions` may name up to 5 signers or the proposal's proposer (empty for
+    /// none). Each mention is recorded in the address's mention index and, unless
+    /// they muted mentions in their notification preferences, emits a
+    /// `mentioned` event addressed to them.
     pub fn add_comment(
         env: Env,
         author: Address,
         proposal_id: u64,
         text: Symbol,
         parent_id: u64,
+        mentions: Vec<Address>,
     ) -> Result<u64, VaultError> {
         author.require_auth();
 
         // Verify proposal exists
-        let _ = storage::get_proposal(&env, proposal_id)?;
+        let proposal = storage::get_proposal(&env, proposal_id)?;
+
+        if mentions.len() > MAX_COMMENT_MENTIONS {
+            return Err(VaultError::BatchTooLarge);
+        }
+        let signers = storage::get_config(&env)?.signers;
+        let mut unique_mentions = Vec::new(&env);
+        for mentioned in mentions.iter() {
+            if !signers.contains(&mentioned) && mentioned != proposal.proposer {
+                return Err(VaultError::NotASigner);
+            }
+            if !unique_mentions.contains(&mentioned) {
+                unique_mentions.push_back(mentioned);
+            }
+        }
 
         // Symbol is capped at 32 chars by the Soroban SDK — length check is not needed.
-        // If parent_id is provided, verify parent comment exists
+        // If parent_id is provided, verify the parent exists on the same proposal and
+        // walk the chain to bound thread depth.
         if parent_id > 0 {
-            let _ = storage::get_comment(&env, parent_id)?;
+            let parent = storage::get_comment(&env, parent_id)?;
+            if parent.proposal_id != proposal_id {
+                return Err(VaultError::ProposalNotFound);
+            }
+            let mut depth = 2u32;
+            let mut ancestor_id = parent.parent_id;
+            while ancestor_id > 0 {
+                depth += 1;
+                if depth > MAX_COMMENT_DEPTH {
+                    return Err(VaultError::ExceedsProposalLimit);
+                }
+                ancestor_id = storage::get_comment(&env, ancestor_id)?.parent_id;
+            }
+        }
+
+        if storage::get_proposal_comments(&env, proposal_id).len()
+            >= storage::get_comment_limit(&env)
+        {
+            return Err(VaultError::ExceedsProposalLimit);
         }
 
-        let comment_id = storage::increment_comment_id(&env);
         let current_ledger = env.ledger().sequence() as u64;
+        if storage::get_role(&env, &author) != Role::Admin {
+            let quota = storage::get_comment_quota(&env);
+            let mut stats = storage::get_comment_author_stats(&env, proposal_id, &author);
+            if quota.max_per_author > 0 && stats.count >= quota.max_per_author {
+                return Err(VaultError::ExceedsProposalLimit);
+            }
+            if stats.count > 0 && current_ledger < stats.last_comment_ledger + quota.min_gap_ledgers
+            {
+                return Err(VaultError::VelocityLimitExceeded);
+            }
+            stats.count += 1;
+            stats.last_comment_ledger = current_ledger;
+            storage::set_comment_author_stats(&env, proposal_id, &author, &stats);
+        }
+
+        let comment_id = storage::increment_comment_id(&env);
 
         let comment = Comment {
             id: comment_id,
@@ -2456,6 +6516,8 @@ impl VaultDAO {
             parent_id,
             created_at: current_ledger,
             edited_at: 0,
+            deleted_at: 0,
+            mentions: unique_mentions.clone(),
         };
 
         storage::set_comment(&env, &comment);
@@ -2463,6 +6525,15 @@ impl VaultDAO {
         storage::extend_instance_ttl(&env);
 
         events::emit_comment_added(&env, comment_id, proposal_id, &author);
+        for mentioned in unique_mentions.iter() {
+            storage::add_mention(&env, &mentioned, comment_id);
+            let prefs = storage::get_notification_prefs(&env, &mentioned);
+            if prefs.notify_on_mention {
+                let route = prefs.route_to.unwrap_or_else(|| mentioned.clone());
+                events::emit_mentioned(&env, &route, &mentioned, comment_id, proposal_id, &author);
+                storage::bump_notification_digest(&env, &mentioned);
+            }
+        }
 
         Ok(comment_id)
     }
@@ -2475,11 +6546,12 @@ impl VaultDAO {
         new_text: Symbol,
     ) -> Result<(), VaultError> {
         author.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let mut comment = storage::get_comment(&env, comment_id)?;
 
-        // Only author can edit
-        if comment.author != author {
+        // Only author can edit, and deleted comments stay tombstoned
+        if comment.author != author || comment.deleted_at > 0 {
             return Err(VaultError::Unauthorized);
         }
 
@@ -2494,12 +6566,99 @@ impl VaultDAO {
         Ok(())
     }
 
-    /// Get all comments for a proposal
-    pub fn get_proposal_comments(env: Env, proposal_id: u64) -> Vec<Comment> {
+    /// Delete a comment.
+    ///
+    /// The author or an Admin may delete. The comment keeps its ID so replies
+    /// still resolve their parent, but its text is replaced with a tombstone.
+    pub fn delete_comment(env: Env, caller: Address, comment_id: u64) -> Result<(), VaultError> {
+        caller.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let mut comment = storage::get_comment(&env, comment_id)?;
+        if comment.author != caller && storage::get_role(&env, &caller) != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+        if comment.deleted_at > 0 {
+            return Err(VaultError::ProposalNotFound);
+        }
+
+        comment.text = Symbol::new(&env, "deleted");
+        comment.deleted_at = env.ledger().sequence() as u64;
+
+        storage::set_comment(&env, &comment);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_comment_deleted(&env, comment_id, &caller);
+
+        Ok(())
+    }
+
+    /// React to a comment with a short symbol (e.g. `ack`, `plus1`).
+    ///
+    /// Each address may react once per comment. The number of distinct reaction
+    /// kinds and total reactions per comment are bounded.
+    pub fn react_to_comment(
+        env: Env,
+        addr: Address,
+        comment_id: u64,
+        reaction: Symbol,
+    ) -> Result<(), VaultError> {
+        addr.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let comment = storage::get_comment(&env, comment_id)?;
+        if comment.deleted_at > 0 {
+            return Err(VaultError::ProposalNotFound);
+        }
+
+        let mut reactors = storage::get_comment_reactors(&env, comment_id);
+        if reactors.contains(&addr) {
+            return Err(VaultError::AlreadyApproved);
+        }
+        if reactors.len() >= MAX_COMMENT_REACTIONS {
+            return Err(VaultError::ExceedsProposalLimit);
+        }
+
+        let mut reactions = storage::get_comment_reactions(&env, comment_id);
+        let count = reactions.get(reaction.clone()).unwrap_or(0);
+        if count == 0 && reactions.len() >= MAX_REACTION_KINDS {
+            return Err(VaultError::ExceedsProposalLimit);
+        }
+        reactions.set(reaction.clone(), count + 1);
+        reactors.push_back(addr.clone());
+
+        storage::set_comment_reactions(&env, comment_id, &reactions);
+        storage::set_comment_reactors(&env, comment_id, &reactors);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_comment_reaction(&env, comment_id, &addr, &reaction, count + 1);
+
+        Ok(())
+    }
+
+    /// Get reaction counts for a comment.
+    pub fn get_comment_reactions(env: Env, comment_id: u64) -> Map<Symbol, u32> {
+        storage::get_comment_reactions(&env, comment_id)
+    }
+
+    /// Get a page of comments for a proposal.
+    ///
+    /// Returns up to `limit` comments (capped at 50) starting at `start_index`
+    /// in creation order, together with the total number of comments.
+    pub fn get_proposal_comments(
+        env: Env,
+        proposal_id: u64,
+        start_index: u32,
+        limit: u32,
+    ) -> (Vec<Comment>, u32) {
         let comment_ids = storage::get_proposal_comments(&env, proposal_id);
+        let total = comment_ids.len();
+        let end = start_index
+            .saturating_add(limit.min(MAX_COMMENT_PAGE_SIZE))
+            .min(total);
         let mut comments = Vec::new(&env);
 
-        for i in 0..comment_ids.len() {
+        for i in start_index..end {
             if let Some(comment_id) = comment_ids.get(i) {
                 if let Ok(comment) = storage::get_comment(&env, comment_id) {
                     comments.push_back(comment);
@@ -2507,7 +6666,83 @@ impl VaultDAO {
             }
         }
 
-        comments
+        (comments, total)
+    }
+
+    /// Get comment IDs that mention `addr` (paginated).
+    ///
+    /// Reads the per-address mention index, which keeps the 200 most recent
+    /// mentions oldest first. Returns up to `limit` IDs (capped at 50) starting
+    /// at position `start`.
+    pub fn get_mentions(env: Env, addr: Address, start: u32, limit: u32) -> Vec<u64> {
+        let ids = storage::get_mentions(&env, &addr);
+        let end = start
+            .saturating_add(limit.min(MAX_COMMENT_PAGE_SIZE))
+            .min(ids.len());
+        if start >= end {
+            return Vec::new(&env);
+        }
+        ids.slice(start..end)
+    }
+
+    /// Get the first page of comments for a proposal.
+    pub fn get_comments_first_page(env: Env, proposal_id: u64) -> Vec<Comment> {
+        Self::get_proposal_comments(env, proposal_id, 0, MAX_COMMENT_PAGE_SIZE).0
+    }
+
+    /// Set the maximum number of comments allowed per proposal (Admin only).
+    pub fn set_comment_limit(env: Env, admin: Address, limit: u32) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+        if limit == 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+
+        storage::set_comment_limit(&env, limit);
+        storage::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Get the maximum number of comments allowed per proposal.
+    pub fn get_comment_limit(env: Env) -> u32 {
+        storage::get_comment_limit(&env)
+    }
+
+    /// Set how many comments a non-admin author may post per proposal and the
+    /// minimum ledger gap between their comments (Admin only).
+    pub fn set_comment_quota(
+        env: Env,
+        admin: Address,
+        max_per_author: u32,
+        min_gap_ledgers: u64,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+
+        storage::set_comment_quota(
+            &env,
+            &CommentQuota {
+                max_per_author,
+                min_gap_ledgers,
+            },
+        );
+        storage::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Get the per-author comment limits.
+    pub fn get_comment_quota(env: Env) -> CommentQuota {
+        storage::get_comment_quota(&env)
     }
 
     /// Get a single comment by ID
@@ -2573,13 +6808,25 @@ impl VaultDAO {
     /// Execute multiple approved proposals in a single transaction.
     ///
     /// Gas-optimized batch execution. Skips proposals that fail validation.
-    /// Returns the list of successfully executed proposal IDs and the count of failures.
+    /// Repeated IDs are processed once, at their first position, and are
+    /// neither executed again nor counted as failures.
+    /// Returns the list of successfully executed proposal IDs, the count of
+    /// failures and the duplicate IDs that were skipped.
+    ///
+    /// Returns [`VaultError::BatchTooLarge`] for more than `MAX_BATCH_SIZE`
+    /// IDs, repeats included.
     pub fn batch_execute_proposals(
         env: Env,
         executor: Address,
         proposal_ids: Vec<u64>,
-    ) -> Result<(Vec<u64>, u32), VaultError> {
+    ) -> Result<(Vec<u64>, u32, Vec<u64>), VaultError> {
         executor.require_auth();
+        Self::ensure_not_paused(&env)?;
+        // Bound the input before the quadratic de-duplication
+        if proposal_ids.len() > MAX_BATCH_SIZE {
+            return Err(VaultError::BatchTooLarge);
+        }
+        let (proposal_ids, duplicates) = Self::dedup_ids(&env, &proposal_ids);
         // Load config once (gas optimization — avoids repeated storage reads)
         let config = storage::get_config(&env)?;
 
@@ -2588,6 +6835,11 @@ impl VaultDAO {
         let mut failed_count: u32 = 0;
 
         for i in 0..proposal_ids.len() {
+            // A freeze tripped by an earlier entry stops the rest of the batch
+            if storage::is_paused(&env) {
+                failed_count += proposal_ids.len() - i;
+                break;
+            }
             let proposal_id = proposal_ids.get(i).unwrap();
             let proposal_result = storage::get_proposal(&env, proposal_id);
             let mut proposal = match proposal_result {
@@ -2598,41 +6850,82 @@ impl VaultDAO {
                 }
             };
 
-            // Skip if not in approved state
-            if proposal.status != ProposalStatus::Approved {
+            // Skip if not in approved state or still an unrevealed commitment
+            if proposal.status != ProposalStatus::Approved
+                || storage::get_commitment(&env, proposal_id).is_some()
+            {
+                failed_count += 1;
+                continue;
+            }
+            // Skip if the executor is not on the proposal's allow-list
+            if Self::ensure_executor_allowed(&env, proposal_id, &executor).is_err() {
                 failed_count += 1;
                 continue;
             }
-            // Skip if approvals/quorum are no longer satisfied
-            if Self::ensure_vote_requirements_satisfied(&env, &config, &proposal).is_err() {
+            // Skip if approvals/quorum are no longer satisfied, ending any
+            // pending retry for good
+            if let Err(err) = Self::ensure_vote_requirements_satisfied(&env, &config, &proposal) {
+                if let Some(retry_state) = storage::get_retry_state(&env, proposal_id)
+                    .filter(|state| state.retry_count > 0)
+                {
+                    Self::invalidate_retry(&env, &mut proposal, &executor, &retry_state, err);
+                }
                 failed_count += 1;
                 continue;
             }
 
             // Skip if expired
             if current_ledger > proposal.expires_at {
-                proposal.status = ProposalStatus::Expired;
+                Self::transition_status(
+                    &env,
+                    &mut proposal,
+                    ProposalStatus::Expired,
+                    &executor,
+                    "expired",
+                );
                 storage::set_proposal(&env, &proposal);
                 failed_count += 1;
                 continue;
             }
 
-            // Skip if still timelocked
-            if proposal.unlock_ledger > 0 && current_ledger < proposal.unlock_ledger {
+            // Expire if it missed its execution window
+            if Self::execution_window_lapsed(&env, &proposal, current_ledger) {
+                Self::lapse_execution_window(&env, &mut proposal, &executor);
+                failed_count += 1;
+                continue;
+            }
+
+            // Skip if still timelocked or awaiting a second look
+            if (proposal.unlock_ledger > 0 && current_ledger < proposal.unlock_ledger)
+                || Self::second_look_pending(&env, &config, &proposal)
+            {
                 failed_count += 1;
                 continue;
             }
 
-            // Skip if dependencies are not satisfied or graph is invalid.
+            // Skip if dependencies are not satisfied
             if Self::ensure_dependencies_executable(&env, &proposal).is_err() {
                 failed_count += 1;
                 continue;
             }
 
+            // Skip if a recipient is no longer permitted by the recipient lists
+            if Self::validate_payout_recipients(&env, &proposal).is_err() {
+                failed_count += 1;
+                continue;
+            }
+
             // Skip if conditions not satisfied
             if !proposal.conditions.is_empty()
-                && Self::evaluate_conditions(&env, &proposal).is_err()
+                && Self::evaluate_conditions(
+                    &env,
+                    &proposal.token,
+                    &proposal.conditions,
+                    &proposal.condition_logic,
+                )
+                .is_err()
             {
+                Self::record_execution_failure(&env, &VaultError::ConditionsNotMet);
                 failed_count += 1;
                 continue;
             }
@@ -2640,26 +6933,38 @@ impl VaultDAO {
             // Skip if gas limit would be exceeded
             let fee_estimate = Self::calculate_execution_fee(&env, &proposal);
             if proposal.gas_limit > 0 && fee_estimate.total_fee > proposal.gas_limit {
+                Self::record_execution_failure(&env, &VaultError::GasLimitExceeded);
                 failed_count += 1;
                 continue;
             }
 
             // Skip if insufficient balance (check proposal amount + stake to refund)
-            let balance = token::balance(&env, &proposal.token);
-            let required_balance = proposal.amount + proposal.stake_amount;
-            if balance < required_balance {
+            let required_balance = proposal.amount
+                + proposal.stake_amount
+                + storage::get_executor_tip(&env, proposal_id);
+            if required_balance > 0 && token::balance(&env, &proposal.token) < required_balance {
+                Self::record_execution_failure(&env, &VaultError::InsufficientBalance);
                 failed_count += 1;
                 continue;
             }
+            let Ok(withdrawal_pool) = Self::withdrawal_pool(&env, &proposal) else {
+                failed_count += 1;
+                continue;
+            };
 
-            // Execute the transfer
-            token::transfer(&env, &proposal.token, &proposal.recipient, proposal.amount);
+            // Execute the transfer (a failed split aborts the whole call so no
+            // proposal is ever partially paid)
+            Self::transfer_payout(&env, &proposal)?;
+            if let Some(pool) = withdrawal_pool {
+                storage::subtract_from_pool(&env, &pool, &proposal.token, proposal.amount);
+            }
+            Self::pay_executor_tip(&env, &proposal, &executor);
 
             // Return insurance on success
             if proposal.insurance_amount > 0 {
                 token::transfer(
                     &env,
-                    &proposal.token,
+                    &storage::get_insurance_token(&env, &proposal),
                     &proposal.proposer,
                     proposal.insurance_amount,
                 );
@@ -2697,8 +7002,17 @@ impl VaultDAO {
             }
 
             proposal.gas_used = fee_estimate.total_fee;
-            proposal.status = ProposalStatus::Executed;
+            Self::transition_status(
+                &env,
+                &mut proposal,
+                ProposalStatus::Executed,
+                &executor,
+                "execute",
+            );
             storage::set_proposal(&env, &proposal);
+            Self::record_execution_proof(&env, &config, &proposal, current_ledger);
+
+            storage::mark_recipient_paid(&env, &proposal.recipient);
 
             events::emit_proposal_executed(
                 &env,
@@ -2708,11 +7022,14 @@ impl VaultDAO {
                 &proposal.token,
                 proposal.amount,
                 current_ledger,
+                storage::get_executor_tip(&env, proposal_id),
+                storage::get_total_outflow(&env, &proposal.token),
             );
+            Self::notify(&env, &proposal.proposer, "execution", |prefs| {
+                prefs.notify_on_execution
+            });
             Self::update_reputation_on_execution(&env, &proposal);
-            let exec_time = current_ledger.saturating_sub(proposal.created_at);
-            storage::metrics_on_execution(&env, fee_estimate.total_fee, exec_time);
-            events::emit_execution_fee_used(&env, proposal_id, fee_estimate.total_fee);
+            Self::record_execution_stats(&env, &proposal, current_ledger);
             executed.push_back(proposal_id);
         }
 
@@ -2721,7 +7038,21 @@ impl VaultDAO {
 
         events::emit_batch_executed(&env, &executor, executed.len(), failed_count);
 
-        Ok((executed, failed_count))
+        Ok((executed, failed_count, duplicates))
+    }
+
+    /// Split `ids` into first occurrences (in input order) and repeats.
+    fn dedup_ids(env: &Env, ids: &Vec<u64>) -> (Vec<u64>, Vec<u64>) {
+        let mut unique = Vec::new(env);
+        let mut duplicates = Vec::new(env);
+        for id in ids.iter() {
+            if unique.contains(id) {
+                duplicates.push_back(id);
+            } else {
+                unique.push_back(id);
+            }
+        }
+        (unique, duplicates)
     }
 
     // ========================================================================
@@ -2756,6 +7087,7 @@ impl VaultDAO {
 
         proposal.priority = new_priority;
         storage::set_proposal(&env, &proposal);
+        Self::persist_execution_fee_estimate(&env, &proposal);
         storage::extend_instance_ttl(&env);
 
         Ok(())
@@ -2766,11 +7098,140 @@ impl VaultDAO {
         storage::get_priority_queue(&env, priority as u32)
     }
 
+    // ========================================================================
+    // Invariant Monitoring
+    // ========================================================================
+
+    /// Check accounting invariants and report any violations.
+    ///
+    /// Read-only and intended for monitoring bots via simulation; it scans every
+    /// proposal, so it is not meant to be submitted on-chain. Checks:
+    /// - per tracked token, insurance pool + stake pool + deposits locked on live
+    ///   proposals + approved payouts do not exceed the vault balance;
+    /// - no proposal has more approvals than snapshot signers;
+    /// - today's reserved spending is within the maximum boosted daily limit;
+    /// - retry states exist only for Approved proposals;
+    /// - per tracked token, the pools plus the maintained outstanding insurance
+    ///   and stake counters do not exceed the vault balance.
+    pub fn check_invariants(env: Env) -> Result<Vec<InvariantViolation>, VaultError> {
+        let config = storage::get_config(&env)?;
+        let mut violations = Vec::new(&env);
+
+        let tokens = storage::get_tracked_tokens(&env);
+        let mut committed: Map<Address, i128> = Map::new(&env);
+        for token_addr in tokens.iter() {
+            let pools = storage::get_insurance_pool(&env, &token_addr)
+                .saturating_add(storage::get_stake_pool(&env, &token_addr));
+            committed.set(token_addr, pools);
+        }
+
+        let next_id = storage::get_next_proposal_id(&env);
+        for proposal_id in 1..next_id {
+            let proposal = match storage::get_proposal(&env, proposal_id) {
+                Ok(p) => p,
+                Err(_) => continue,
+            };
+
+            if proposal.approvals.len() > proposal.snapshot_signers.len() {
+                violations.push_back(InvariantViolation {
+                    kind: InvariantKind::ApprovalsExceedSnapshot,
+                    proposal_id,
+                    token: None,
+                    expected: proposal.snapshot_signers.len() as i128,
+                    actual: proposal.approvals.len() as i128,
+                });
+            }
+
+            if storage::get_retry_state(&env, proposal_id).is_some()
+                && proposal.status != ProposalStatus::Approved
+            {
+                violations.push_back(InvariantViolation {
+                    kind: InvariantKind::OrphanRetryState,
+                    proposal_id,
+                    token: None,
+                    expected: 0,
+                    actual: 1,
+                });
+            }
+
+            let (held, insurance_held) = match proposal.status {
+                ProposalStatus::Pending => (proposal.stake_amount, proposal.insurance_amount),
+                ProposalStatus::Approved | ProposalStatus::Scheduled => (
+                    proposal.amount + proposal.stake_amount,
+                    proposal.insurance_amount,
+                ),
+                _ => (0, 0),
+            };
+            for (token_addr, amount) in [
+                (proposal.token.clone(), held),
+                (
+                    storage::get_insurance_token(&env, &proposal),
+                    insurance_held,
+                ),
+            ] {
+                if amount > 0 {
+                    if let Some(total) = committed.get(token_addr.clone()) {
+                        committed.set(token_addr, total.saturating_add(amount));
+                    }
+                }
+            }
+        }
+
+        for (token_addr, total) in committed.iter() {
+            let report = Self::get_pool_report(env.clone(), token_addr.clone());
+            let balance = report.vault_balance;
+            if total > balance {
+                violations.push_back(InvariantViolation {
+                    kind: InvariantKind::BalanceShortfall,
+                    proposal_id: 0,
+                    token: Some(token_addr.clone()),
+                    expected: balance,
+                    actual: total,
+                });
+            }
+
+            let pooled = report
+                .insurance_pool
+                .saturating_add(report.stake_pool)
+                .saturating_add(report.locked_insurance_outstanding)
+                .saturating_add(report.locked_stakes_outstanding);
+            if pooled > balance {
+                violations.push_back(InvariantViolation {
+                    kind: InvariantKind::PoolShortfall,
+                    proposal_id: 0,
+                    token: Some(token_addr),
+                    expected: balance,
+                    actual: pooled,
+                });
+            }
+        }
+
+        // Reputation can raise the daily limit by at most 1.5x.
+        let max_daily = (config.daily_limit * 3) / 2;
+        let spent_today = storage::get_daily_spent(&env, storage::get_day_number(&env));
+        if spent_today > max_daily {
+            violations.push_back(InvariantViolation {
+                kind: InvariantKind::DailyLimitExceeded,
+                proposal_id: 0,
+                token: None,
+                expected: max_daily,
+                actual: spent_today,
+            });
+        }
+
+        Ok(violations)
+    }
+
+    /// Tokens the vault has created transfer proposals in (bounded list).
+    pub fn get_tracked_tokens(env: Env) -> Vec<Address> {
+        storage::get_tracked_tokens(&env)
+    }
+
     // ========================================================================
     // Attachment Management
     // ========================================================================
 
-    /// Add an IPFS attachment hash to a proposal.
+    /// Add an IPFS attachment hash to a proposal (max 10 per proposal).
     pub fn add_attachment(
         env: Env,
         caller: Address,
@@ -2778,6 +7239,7 @@ impl VaultDAO {
         attachment: String,
     ) -> Result<(), VaultError> {
         caller.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let proposal = storage::get_proposal(&env, proposal_id)?;
 
@@ -2786,10 +7248,8 @@ impl VaultDAO {
             return Err(VaultError::Unauthorized);
         }
 
-        // IPFS CID v0 is 46 chars; CIDv1 base32 is 59+ chars; reject anything
-        // outside the valid range with a dedicated error code.
-        let alen = attachment.len();
-        if !(MIN_ATTACHMENT_LEN..=MAX_ATTACHMENT_LEN).contains(&alen) {
+        // Only well-formed CIDv0 / base32 CIDv1 identifiers are accepted.
+        if !is_valid_ipfs_cid(&attachment) {
             return Err(VaultError::AttachmentHashInvalid);
         }
 
@@ -2815,6 +7275,7 @@ impl VaultDAO {
         index: u32,
     ) -> Result<(), VaultError> {
         caller.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let proposal = storage::get_proposal(&env, proposal_id)?;
 
@@ -2834,6 +7295,17 @@ impl VaultDAO {
         Ok(())
     }
 
+    /// Get the IPFS attachment hashes for a proposal.
+    ///
+    /// This is the canonical view; `get_proposal(..).attachments` is populated
+    /// from the same storage slot.
+    pub fn get_attachments(env: Env, proposal_id: u64) -> Result<Vec<String>, VaultError> {
+        if !storage::proposal_exists(&env, proposal_id) {
+            return Err(VaultError::ProposalNotFound);
+        }
+        Ok(storage::get_attachments(&env, proposal_id))
+    }
+
     // ========================================================================
     // Metadata Management
     // ========================================================================
@@ -2849,6 +7321,7 @@ impl VaultDAO {
         value: String,
     ) -> Result<(), VaultError> {
         caller.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let mut proposal = storage::get_proposal(&env, proposal_id)?;
 
@@ -2885,6 +7358,7 @@ impl VaultDAO {
         key: Symbol,
     ) -> Result<(), VaultError> {
         caller.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let mut proposal = storage::get_proposal(&env, proposal_id)?;
 
@@ -2919,13 +7393,77 @@ impl VaultDAO {
         Ok(proposal.metadata)
     }
 
+    /// Set a vault-level metadata entry (Admin only), e.g. the DAO name,
+    /// logo IPFS hash or governance forum URL.
+    ///
+    /// Same bounds as proposal metadata: non-empty values up to
+    /// `MAX_METADATA_VALUE_LEN` bytes and at most `MAX_METADATA_ENTRIES` keys.
+    pub fn set_vault_metadata(
+        env: Env,
+        admin: Address,
+        key: Symbol,
+        value: String,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+
+        let value_len = value.len();
+        if value_len == 0 || value_len > MAX_METADATA_VALUE_LEN {
+            return Err(VaultError::MetadataValueInvalid);
+        }
+
+        let mut metadata = storage::get_vault_metadata(&env);
+        if !metadata.contains_key(key.clone()) && metadata.len() >= MAX_METADATA_ENTRIES {
+            return Err(VaultError::ExceedsProposalLimit);
+        }
+
+        metadata.set(key.clone(), value.clone());
+        storage::set_vault_metadata(&env, &metadata);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_vault_metadata_updated(&env, &admin, &key, &Some(value));
+
+        Ok(())
+    }
+
+    /// Remove a vault-level metadata entry (Admin only).
+    pub fn remove_vault_metadata(env: Env, admin: Address, key: Symbol) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+
+        let mut metadata = storage::get_vault_metadata(&env);
+        if metadata.remove(key.clone()).is_some() {
+            storage::set_vault_metadata(&env, &metadata);
+            events::emit_vault_metadata_updated(&env, &admin, &key, &None);
+        }
+        storage::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Get the full vault-level metadata map.
+    pub fn get_vault_metadata(env: Env) -> Map<Symbol, String> {
+        storage::get_vault_metadata(&env)
+    }
+
     // ========================================================================
     // Tag Management
     // ========================================================================
 
     /// Add a tag to a proposal.
     ///
-    /// Only Admin or the original proposer can add tags.
+    /// Only Admin or the original proposer can add tags. A tag the proposal
+    /// already carries fails with `AlreadyApproved`.
     pub fn add_proposal_tag(
         env: Env,
         caller: Address,
@@ -2933,6 +7471,7 @@ impl VaultDAO {
         tag: Symbol,
     ) -> Result<(), VaultError> {
         caller.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let mut proposal = storage::get_proposal(&env, proposal_id)?;
 
@@ -2949,8 +7488,9 @@ impl VaultDAO {
             return Err(VaultError::TooManyTags);
         }
 
-        proposal.tags.push_back(tag);
+        proposal.tags.push_back(tag.clone());
         storage::set_proposal(&env, &proposal);
+        storage::add_to_tag_index(&env, &tag, proposal_id);
         storage::extend_instance_ttl(&env);
 
         Ok(())
@@ -2966,6 +7506,7 @@ impl VaultDAO {
         tag: Symbol,
     ) -> Result<(), VaultError> {
         caller.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let mut proposal = storage::get_proposal(&env, proposal_id)?;
 
@@ -2988,6 +7529,7 @@ impl VaultDAO {
         }
 
         storage::set_proposal(&env, &proposal);
+        storage::remove_from_tag_index(&env, &tag, proposal_id);
         storage::extend_instance_ttl(&env);
 
         Ok(())
@@ -2999,20 +7541,131 @@ impl VaultDAO {
         Ok(proposal.tags)
     }
 
-    /// Get proposal IDs that include a specific tag.
-    pub fn get_proposals_by_tag(env: Env, tag: Symbol) -> Vec<u64> {
-        let mut proposal_ids = Vec::new(&env);
-        let next_id = storage::get_next_proposal_id(&env);
+    /// Replace the rules that tag proposals automatically at creation (Admin only).
+    ///
+    /// Up to `MAX_TAGS` rules. Automatic tags are ordinary tags: they are
+    /// indexed and can be removed like any other.
+    pub fn set_auto_tag_rules(
+        env: Env,
+        admin: Address,
+        rules: Vec<AutoTagRule>,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
 
-        for proposal_id in 1..next_id {
-            if let Ok(proposal) = storage::get_proposal(&env, proposal_id) {
-                if proposal.tags.contains(&tag) {
-                    proposal_ids.push_back(proposal_id);
-                }
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+        if rules.len() > MAX_TAGS {
+            return Err(VaultError::BatchTooLarge);
+        }
+        for rule in rules.iter() {
+            if rule.min_amount < 0
+                || rule.max_amount < 0
+                || (rule.max_amount > 0 && rule.max_amount < rule.min_amount)
+            {
+                return Err(VaultError::InvalidAmount);
+            }
+        }
+
+        storage::set_auto_tag_rules(&env, &rules);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Rules that tag proposals automatically at creation.
+    pub fn get_auto_tag_rules(env: Env) -> Vec<AutoTagRule> {
+        storage::get_auto_tag_rules(&env)
+    }
+
+    /// Set the tags copied onto every proposal created from a template (Admin only).
+    pub fn set_template_tags(
+        env: Env,
+        admin: Address,
+        template_id: u64,
+        tags: Vec<Symbol>,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::InsufficientRole);
+        }
+        if tags.len() > MAX_TAGS {
+            return Err(VaultError::TooManyTags);
+        }
+
+        let mut template = storage::get_template(&env, template_id)?;
+        template.default_tags = tags;
+        template.updated_at = env.ledger().sequence() as u64;
+        template.version += 1;
+
+        storage::set_template(&env, &template);
+        storage::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Tag a new proposal with its category and every matching auto-tag rule.
+    /// Tags beyond `MAX_TAGS` are dropped.
+    fn apply_auto_tags(env: &Env, proposal: &mut Proposal, category: &Option<Symbol>) {
+        if let Some(category) = category {
+            Self::push_auto_tag(env, proposal, category.clone());
+        }
+        for rule in storage::get_auto_tag_rules(env).iter() {
+            let matches = proposal.amount >= rule.min_amount
+                && (rule.max_amount == 0 || proposal.amount <= rule.max_amount)
+                && rule
+                    .recipient
+                    .as_ref()
+                    .is_none_or(|recipient| *recipient == proposal.recipient);
+            if matches {
+                Self::push_auto_tag(env, proposal, rule.tag);
             }
         }
+    }
+
+    /// Copy a template's default tags onto a proposal created from it.
+    fn apply_template_tags(
+        env: &Env,
+        proposal_id: u64,
+        template: &ProposalTemplate,
+    ) -> Result<(), VaultError> {
+        if template.default_tags.is_empty() {
+            return Ok(());
+        }
+        let mut proposal = storage::get_proposal(env, proposal_id)?;
+        for tag in template.default_tags.iter() {
+            Self::push_auto_tag(env, &mut proposal, tag);
+        }
+        storage::set_proposal(env, &proposal);
+        Ok(())
+    }
+
+    fn push_auto_tag(env: &Env, proposal: &mut Proposal, tag: Symbol) {
+        if !proposal.tags.contains(&tag) && proposal.tags.len() < MAX_TAGS {
+            proposal.tags.push_back(tag.clone());
+            storage::add_to_tag_index(env, &tag, proposal.id);
+        }
+    }
 
-        proposal_ids
+    /// Get proposal IDs that include a specific tag (paginated).
+    ///
+    /// Reads the per-tag index, which keeps the 200 most recently tagged
+    /// proposals in tagging order. Returns up to `limit` IDs (capped at 100)
+    /// starting at position `start`.
+    pub fn get_proposals_by_tag(env: Env, tag: Symbol, start: u32, limit: u32) -> Vec<u64> {
+        let ids = storage::get_tag_index(&env, &tag);
+        let end = start.saturating_add(limit.min(100)).min(ids.len());
+        if start >= end {
+            return Vec::new(&env);
+        }
+        ids.slice(start..end)
     }
 
     // ========================================================================
@@ -3028,11 +7681,13 @@ impl VaultDAO {
         config: InsuranceConfig,
     ) -> Result<(), VaultError> {
         admin.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
+        Self::validate_insurance_config(&config)?;
 
         storage::set_insurance_config(&env, &config);
         storage::extend_instance_ttl(&env);
@@ -3042,6 +7697,129 @@ impl VaultDAO {
         Ok(())
     }
 
+    /// Set the USD value above which proposals need insurance (Admin only).
+    ///
+    /// Only used while an oracle is configured; `min_amount_usd` of 0 keeps
+    /// the token-unit `min_amount` threshold.
+    pub fn set_insurance_usd_threshold(
+        env: Env,
+        admin: Address,
+        threshold: InsuranceUsdThreshold,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+        if threshold.min_amount_usd < 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+
+        storage::set_insurance_usd_threshold(&env, &threshold);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_insurance_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Current USD insurance threshold.
+    pub fn get_insurance_usd_threshold(env: Env) -> InsuranceUsdThreshold {
+        storage::get_insurance_usd_threshold(&env)
+    }
+
+    /// Choose where slashed insurance goes (Admin only). Defaults to the pool.
+    pub fn set_slash_destination(
+        env: Env,
+        admin: Address,
+        destination: SlashDestination,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+
+        storage::set_slash_destination(&env, &destination);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_insurance_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Current destination for slashed insurance.
+    pub fn get_slash_destination(env: Env) -> SlashDestination {
+        storage::get_slash_destination(&env)
+    }
+
+    /// Exempt a recipient (e.g. the DAO's own ops wallet) from proposal insurance.
+    ///
+    /// Only Admin can manage exemptions.
+    pub fn add_insurance_exemption(
+        env: Env,
+        admin: Address,
+        recipient: Address,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+
+        let mut exemptions = storage::get_insurance_exemptions(&env);
+        if exemptions.contains(&recipient) {
+            return Err(VaultError::AddressAlreadyOnList);
+        }
+        exemptions.push_back(recipient);
+        storage::set_insurance_exemptions(&env, &exemptions);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_insurance_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Remove a recipient's insurance exemption.
+    ///
+    /// Only Admin can manage exemptions.
+    pub fn remove_insurance_exemption(
+        env: Env,
+        admin: Address,
+        recipient: Address,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+
+        let mut exemptions = storage::get_insurance_exemptions(&env);
+        let index = exemptions
+            .first_index_of(&recipient)
+            .ok_or(VaultError::AddressNotOnList)?;
+        exemptions.remove(index);
+        storage::set_insurance_exemptions(&env, &exemptions);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_insurance_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Whether proposals paying `addr` skip the insurance requirement.
+    pub fn is_insurance_exempt(env: Env, addr: Address) -> bool {
+        storage::is_insurance_exempt(&env, &addr)
+    }
+
     /// Get the current insurance configuration.
     pub fn get_insurance_config(env: Env) -> InsuranceConfig {
         storage::get_insurance_config(&env)
@@ -3064,32 +7842,14 @@ impl VaultDAO {
         fee_structure: types::FeeStructure,
     ) -> Result<(), VaultError> {
         admin.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
 
-        // Validate fee structure
-        if fee_structure.base_fee_bps > 10_000 {
-            return Err(VaultError::InvalidAmount);
-        }
-
-        // Validate tiers are sorted by min_volume
-        for i in 1..fee_structure.tiers.len() {
-            let prev = fee_structure.tiers.get(i - 1).unwrap();
-            let curr = fee_structure.tiers.get(i).unwrap();
-            if curr.min_volume <= prev.min_volume {
-                return Err(VaultError::InvalidAmount);
-            }
-            if curr.fee_bps > 10_000 {
-                return Err(VaultError::InvalidAmount);
-            }
-        }
-
-        if fee_structure.reputation_discount_percentage > 100 {
-            return Err(VaultError::InvalidAmount);
-        }
+        Self::validate_fee_structure(&fee_structure)?;
 
         storage::set_fee_structure(&env, &fee_structure);
         storage::extend_instance_ttl(&env);
@@ -3123,13 +7883,40 @@ impl VaultDAO {
     }
 
     /// Get total fees collected for a specific token.
-    pub fn get_fees_collected(env: Env, token: Address) -> i128 {
-        storage::get_fees_collected(&env, &token)
+    ///
+    /// Fails with `Unauthorized` while metrics are restricted.
+    pub fn get_fees_collected(env: Env, token: Address) -> Result<i128, VaultError> {
+        Self::ensure_metrics_public(&env)?;
+        Ok(storage::get_fees_collected(&env, &token))
+    }
+
+    /// Get total fees collected, for a caller holding `ViewMetrics`.
+    pub fn get_fees_collected_authed(
+        env: Env,
+        caller: Address,
+        token: Address,
+    ) -> Result<i128, VaultError> {
+        Self::ensure_can_view_metrics(&env, &caller)?;
+        Ok(storage::get_fees_collected(&env, &token))
     }
 
     /// Get user's total transaction volume for a specific token.
-    pub fn get_user_volume(env: Env, user: Address, token: Address) -> i128 {
-        storage::get_user_volume(&env, &user, &token)
+    ///
+    /// Fails with `Unauthorized` while metrics are restricted.
+    pub fn get_user_volume(env: Env, user: Address, token: Address) -> Result<i128, VaultError> {
+        Self::ensure_metrics_public(&env)?;
+        Ok(storage::get_user_volume(&env, &user, &token))
+    }
+
+    /// Get a user's transaction volume, for a caller holding `ViewMetrics`.
+    pub fn get_user_volume_authed(
+        env: Env,
+        caller: Address,
+        user: Address,
+        token: Address,
+    ) -> Result<i128, VaultError> {
+        Self::ensure_can_view_metrics(&env, &caller)?;
+        Ok(storage::get_user_volume(&env, &user, &token))
     }
 
     // ========================================================================
@@ -3155,6 +7942,97 @@ impl VaultDAO {
         )
     }
 
+    /// Move a signer's reputation to a rotated key.
+    ///
+    /// Requires both the admin's and the old address's authorization. The old
+    /// record is zeroed. If the new address already has a nonzero score the
+    /// migration is refused (`AddressAlreadyOnList`) unless `merge` is set, in
+    /// which case scores are summed (capped at 1000) and counters added.
+    pub fn migrate_reputation(
+        env: Env,
+        admin: Address,
+        old_addr: Address,
+        new_addr: Address,
+        merge: bool,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
+        // An admin rotating their own key signs once
+        if old_addr != admin {
+            old_addr.require_auth();
+        }
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+        if old_addr == new_addr {
+            return Err(VaultError::InvalidAmount);
+        }
+
+        let mut old_rep = storage::get_reputation(&env, &old_addr);
+        storage::apply_reputation_decay(&env, &mut old_rep);
+        let mut new_rep = storage::get_reputation(&env, &new_addr);
+        storage::apply_reputation_decay(&env, &mut new_rep);
+
+        let has_score = storage::has_reputation(&env, &new_addr) && new_rep.score > 0;
+        let migrated = if has_score {
+            if !merge {
+                return Err(VaultError::AddressAlreadyOnList);
+            }
+            Reputation {
+                score: old_rep.score.saturating_add(new_rep.score).min(1000),
+                proposals_executed: old_rep
+                    .proposals_executed
+                    .saturating_add(new_rep.proposals_executed),
+                proposals_rejected: old_rep
+                    .proposals_rejected
+                    .saturating_add(new_rep.proposals_rejected),
+                proposals_created: old_rep
+                    .proposals_created
+                    .saturating_add(new_rep.proposals_created),
+                approvals_given: old_rep
+                    .approvals_given
+                    .saturating_add(new_rep.approvals_given),
+                abstentions_given: old_rep
+                    .abstentions_given
+                    .saturating_add(new_rep.abstentions_given),
+                participation_count: old_rep
+                    .participation_count
+                    .saturating_add(new_rep.participation_count),
+                last_participation_ledger: old_rep
+                    .last_participation_ledger
+                    .max(new_rep.last_participation_ledger),
+                last_decay_ledger: old_rep.last_decay_ledger.max(new_rep.last_decay_ledger),
+            }
+        } else {
+            old_rep.clone()
+        };
+
+        storage::set_reputation(&env, &new_addr, &migrated);
+        storage::set_reputation(
+            &env,
+            &old_addr,
+            &Reputation {
+                score: 0,
+                proposals_executed: 0,
+                proposals_rejected: 0,
+                proposals_created: 0,
+                approvals_given: 0,
+                abstentions_given: 0,
+                participation_count: 0,
+                last_participation_ledger: 0,
+                last_decay_ledger: old_rep.last_decay_ledger,
+            },
+        );
+        storage::create_audit_entry(&env, AuditAction::MigrateReputation, &admin, 0);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_reputation_migrated(&env, &old_addr, &new_addr, migrated.score, has_score);
+
+        Ok(())
+    }
+
     // ========================================================================
     // Notification Preferences (Issue: feature/execution-notifications)
     // ========================================================================
@@ -3166,6 +8044,7 @@ impl VaultDAO {
         prefs: NotificationPreferences,
     ) -> Result<(), VaultError> {
         caller.require_auth();
+        Self::ensure_initialized(&env)?;
 
         storage::set_notification_prefs(&env, &caller, &prefs);
         storage::extend_instance_ttl(&env);
@@ -3180,6 +8059,23 @@ impl VaultDAO {
         storage::get_notification_prefs(&env, &addr)
     }
 
+    /// Get the unread notification count and last notification ledger for an address.
+    pub fn get_notification_digest(env: Env, addr: Address) -> NotificationDigest {
+        storage::get_notification_digest(&env, &addr)
+    }
+
+    /// Reset the caller's unread notification count.
+    pub fn mark_notifications_read(env: Env, addr: Address) -> Result<(), VaultError> {
+        addr.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let mut digest = storage::get_notification_digest(&env, &addr);
+        digest.pending_count = 0;
+        storage::set_notification_digest(&env, &addr, &digest);
+
+        Ok(())
+    }
+
     // ========================================================================
     // Gas Limit Configuration (Issue: feature/gas-limits)
     // ========================================================================
@@ -3189,12 +8085,15 @@ impl VaultDAO {
     /// Only Admin can change gas settings.
     pub fn set_gas_config(env: Env, admin: Address, config: GasConfig) -> Result<(), VaultError> {
         admin.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
             return Err(VaultError::Unauthorized);
         }
 
+        Self::validate_gas_config(&config)?;
+
         storage::set_gas_config(&env, &config);
         storage::extend_instance_ttl(&env);
 
@@ -3203,6 +8102,40 @@ impl VaultDAO {
         Ok(())
     }
 
+    /// Set the largest executor tip a proposal may carry (Admin only).
+    ///
+    /// Tips are paid from the vault in the proposal's token. 0 (the default)
+    /// disables tips.
+    pub fn set_max_executor_tip(env: Env, admin: Address, amount: i128) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+        if amount < 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+
+        storage::set_max_executor_tip(&env, amount);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Get the largest executor tip a proposal may carry.
+    pub fn get_max_executor_tip(env: Env) -> i128 {
+        storage::get_max_executor_tip(&env)
+    }
+
+    /// Tip a proposal pays to whoever executes it (0 = none).
+    pub fn get_executor_tip(env: Env, proposal_id: u64) -> i128 {
+        storage::get_executor_tip(&env, proposal_id)
+    }
+
     /// Get the current gas configuration.
     pub fn get_gas_config(env: Env) -> GasConfig {
         storage::get_gas_config(&env)
@@ -3227,21 +8160,236 @@ impl VaultDAO {
     // ========================================================================
 
     /// Get vault-wide performance metrics.
-    pub fn get_metrics(env: Env) -> VaultMetrics {
-        storage::get_metrics(&env)
+    ///
+    /// Fails with `Unauthorized` while metrics are restricted.
+    pub fn get_metrics(env: Env) -> Result<VaultMetrics, VaultError> {
+        Self::ensure_metrics_public(&env)?;
+        Ok(storage::get_metrics(&env))
+    }
+
+    /// Get vault-wide performance metrics, for a caller holding `ViewMetrics`.
+    pub fn get_metrics_authed(env: Env, caller: Address) -> Result<VaultMetrics, VaultError> {
+        Self::ensure_can_view_metrics(&env, &caller)?;
+        Ok(storage::get_metrics(&env))
+    }
+
+    /// Summarize everything that currently needs an operator or keeper.
+    ///
+    /// Open proposals are read from the priority queues and recurring
+    /// payments from their due index. Streams and escrows
+    /// have no vault-wide index, so only the most recent `MAX_HEALTH_SCAN`
+    /// IDs of each are inspected. Every source is capped at `MAX_HEALTH_SCAN`
+    /// entries, so counts are lower bounds on very busy vaults.
+    pub fn get_vault_health(env: Env) -> VaultHealth {
+        let now = env.ledger().sequence() as u64;
+        let mut stale_pending = Self::empty_health_bucket(&env);
+        let mut executable_approved = Self::empty_health_bucket(&env);
+        let mut due_retries = Self::empty_health_bucket(&env);
+
+        let mut scanned = 0u32;
+        'queues: for priority in (Priority::Low as u32)..=(Priority::Critical as u32) {
+            for id in storage::get_priority_queue(&env, priority).iter() {
+                if scanned >= MAX_HEALTH_SCAN {
+                    break 'queues;
+                }
+                scanned += 1;
+                let Ok(proposal) = storage::get_proposal(&env, id) else {
+                    continue;
+                };
+                match proposal.status {
+                    ProposalStatus::Pending => {
+                        let halfway = proposal.created_at
+                            + proposal.expires_at.saturating_sub(proposal.created_at) / 2;
+                        if proposal.expires_at > 0 && now >= halfway {
+                            Self::record_health_item(&mut stale_pending, id);
+                        }
+                    }
+                    ProposalStatus::Approved => {
+                        if now >= proposal.unlock_ledger {
+                            Self::record_health_item(&mut executable_approved, id);
+                        }
+                        if let Some(retry) = storage::get_retry_state(&env, id) {
+                            if retry.retry_count > 0 && now >= retry.next_retry_ledger {
+                                Self::record_health_item(&mut due_retries, id);
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let mut due_recurring = Self::empty_health_bucket(&env);
+        for (due, id) in storage::get_recurring_due_index(&env).iter() {
+            if due > now || due_recurring.count >= MAX_HEALTH_SCAN {
+                break;
+            }
+            Self::record_health_item(&mut due_recurring, id);
+        }
+
+        let timestamp = env.ledger().timestamp();
+        let mut vested_streams = Self::empty_health_bucket(&env);
+        let next_stream = storage::get_next_stream_id(&env);
+        for id in next_stream.saturating_sub(MAX_HEALTH_SCAN as u64).max(1)..next_stream {
+            if let Ok(stream) = storage::get_streaming_payment(&env, id) {
+                if stream.status == StreamStatus::Active
+                    && timestamp >= stream.end_timestamp
+                    && stream.escrow_remaining > 0
+                {
+                    Self::record_health_item(&mut vested_streams, id);
+                }
+            }
+        }
+
+        let mut expired_escrows = Self::empty_health_bucket(&env);
+        for (expires_at, id) in storage::get_escrow_expiry_index(&env)
+            .iter()
+            .take(MAX_HEALTH_SCAN as usize)
+        {
+            if expires_at > now {
+                break;
+            }
+            Self::record_health_item(&mut expired_escrows, id);
+        }
+
+        VaultHealth {
+            stale_pending,
+            executable_approved,
+            due_recurring,
+            vested_streams,
+            due_retries,
+            expired_escrows,
+            checked_at: now,
+        }
+    }
+
+    /// Emit a treasury checkpoint event for the current reporting period.
+    ///
+    /// Permissionless. Periods are `interval` days long (see
+    /// `set_checkpoint_interval`); a second call in the same period fails with
+    /// `TimelockNotExpired`. The event carries the executed count, outflow per
+    /// tracked token since the previous checkpoint, a metrics snapshot and the
+    /// number of pending and approved proposals. Returns the period number.
+    pub fn emit_treasury_checkpoint(env: Env) -> Result<u64, VaultError> {
+        Self::ensure_initialized(&env)?;
+
+        let today = storage::get_day_number(&env);
+        let interval = storage::get_checkpoint_interval(&env);
+        let period = today / interval;
+        if storage::get_last_checkpoint(&env).is_some_and(|last| last / interval >= period) {
+            return Err(VaultError::TimelockNotExpired);
+        }
+
+        // Every tracked token (zero if idle), plus any other token paid out
+        let mut period_outflow = storage::get_period_outflow(&env);
+        let mut outflow = Vec::new(&env);
+        for token_addr in storage::get_tracked_tokens(&env).iter() {
+            let amount = period_outflow.get(token_addr.clone()).unwrap_or(0);
+            period_outflow.remove(token_addr.clone());
+            outflow.push_back((token_addr, amount));
+        }
+        for (token_addr, amount) in period_outflow.iter() {
+            outflow.push_back((token_addr, amount));
+        }
+        let counts = storage::get_status_counts(&env);
+
+        events::emit_treasury_checkpoint(
+            &env,
+            period,
+            &storage::get_metrics(&env),
+            &outflow,
+            counts.get(ProposalStatus::Pending).unwrap_or(0),
+            counts.get(ProposalStatus::Approved).unwrap_or(0),
+        );
+
+        storage::set_last_checkpoint(&env, today);
+        storage::reset_period_outflow(&env);
+        storage::extend_instance_ttl(&env);
+
+        Ok(period)
+    }
+
+    /// Set the treasury checkpoint period length in days (at least 1). Admin only.
+    pub fn set_checkpoint_interval(env: Env, admin: Address, days: u64) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+        if days == 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+
+        storage::set_checkpoint_interval(&env, days);
+        storage::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Outflow per token since the last treasury checkpoint.
+    pub fn get_period_outflow(env: Env) -> Map<Address, i128> {
+        storage::get_period_outflow(&env)
+    }
+
+    /// Everything the vault has ever sent out of `token`: payouts, refunds,
+    /// fees, tips and burns.
+    pub fn get_total_outflow(env: Env, token: Address) -> i128 {
+        storage::get_total_outflow(&env, &token)
+    }
+
+    /// Everything the vault has ever pulled in of `token`: contributions,
+    /// escrow and stream funding, insurance and stakes. Direct transfers to
+    /// the vault address are not seen by the contract and are not counted.
+    pub fn get_total_inflow(env: Env, token: Address) -> i128 {
+        storage::get_total_inflow(&env, &token)
+    }
+
+    /// Inflow, outflow and executed proposals of `token` over the last
+    /// `window_days` days, today included. At most `FLOW_WINDOW_DAYS` (30)
+    /// days are kept, so longer windows are capped. Inflow counts the same
+    /// movements as `get_total_inflow`.
+    pub fn get_flow_summary(env: Env, token: Address, window_days: u32) -> FlowSummary {
+        let today = storage::get_day_number(&env);
+        let days = window_days.min(storage::FLOW_WINDOW_DAYS) as u64;
+        let mut summary = FlowSummary::default();
+        for day in (today + 1).saturating_sub(days)..=today {
+            let flow = storage::get_daily_flow(&env, &token, day);
+            summary.inflow = summary.inflow.saturating_add(flow.inflow);
+            summary.outflow = summary.outflow.saturating_add(flow.outflow);
+            summary.executed_proposals += flow.executed_proposals;
+        }
+        summary.net = summary.inflow.saturating_sub(summary.outflow);
+        summary
     }
 
     // ========================================================================
     // Private Helpers
     // ========================================================================
 
-    /// Validate dependency IDs for a new proposal.
+    /// Validate dependency IDs for a new proposal and record its flattened
+    /// dependency set.
+    ///
+    /// Each dependency's transitive set was fixed when it was created, so the
+    /// new set is their union and cycle detection is a membership test. More
+    /// than `MAX_DEPENDENCIES` direct dependencies, a chain deeper than
+    /// `MAX_DEPENDENCY_DEPTH`, or a flattened set larger than
+    /// `MAX_TRANSITIVE_DEPENDENCIES` is rejected with `BatchTooLarge`.
     fn validate_dependencies(
         env: &Env,
         proposal_id: u64,
         depends_on: &Vec<u64>,
     ) -> Result<(), VaultError> {
-        let mut seen = Vec::new(env);
+        if depends_on.is_empty() {
+            return Ok(());
+        }
+        if depends_on.len() > MAX_DEPENDENCIES {
+            return Err(VaultError::BatchTooLarge);
+        }
+
+        let mut transitive: Vec<u64> = Vec::new(env);
+        let mut depth = 0;
 
         for i in 0..depends_on.len() {
             let dependency_id = depends_on.get(i).unwrap();
@@ -3249,75 +8397,96 @@ impl VaultDAO {
             if dependency_id == proposal_id {
                 return Err(VaultError::InvalidAmount);
             }
-            if seen.contains(dependency_id) {
+            if depends_on.first_index_of(dependency_id) != Some(i) {
                 return Err(VaultError::InvalidAmount);
             }
             if !storage::proposal_exists(env, dependency_id) {
                 return Err(VaultError::ProposalNotFound);
             }
 
+            let ancestors = storage::get_transitive_dependencies(env, dependency_id);
             // If any dependency can reach this proposal ID, adding the edge would form a cycle.
-            let mut visited = Vec::new(env);
-            if Self::has_dependency_path(env, dependency_id, proposal_id, &mut visited)? {
+            if ancestors.contains(proposal_id) {
                 return Err(VaultError::InvalidAmount);
             }
 
-            seen.push_back(dependency_id);
-        }
-
-        Ok(())
-    }
-
-    /// Ensure all dependencies are executed and no circular references exist.
-    fn ensure_dependencies_executable(env: &Env, proposal: &Proposal) -> Result<(), VaultError> {
-        for i in 0..proposal.depends_on.len() {
-            let dependency_id = proposal.depends_on.get(i).unwrap();
-
-            if dependency_id == proposal.id {
-                return Err(VaultError::InvalidAmount);
+            depth = depth.max(storage::get_dependency_depth(env, dependency_id) + 1);
+            if depth > MAX_DEPENDENCY_DEPTH {
+                return Err(VaultError::BatchTooLarge);
             }
 
-            let mut visited = Vec::new(env);
-            if Self::has_dependency_path(env, dependency_id, proposal.id, &mut visited)? {
-                return Err(VaultError::InvalidAmount);
+            if !transitive.contains(dependency_id) {
+                transitive.push_back(dependency_id);
             }
-
-            let dependency = storage::get_proposal(env, dependency_id)
-                .map_err(|_| VaultError::ProposalNotFound)?;
-            if dependency.status != ProposalStatus::Executed {
-                return Err(VaultError::ProposalNotApproved);
+            for ancestor in ancestors.iter() {
+                if !transitive.contains(ancestor) {
+                    transitive.push_back(ancestor);
+                }
+            }
+            if transitive.len() > MAX_TRANSITIVE_DEPENDENCIES {
+                return Err(VaultError::BatchTooLarge);
             }
         }
 
+        storage::set_dependency_closure(env, proposal_id, &transitive, depth);
         Ok(())
     }
 
-    /// DFS reachability check used for dependency cycle detection.
-    fn has_dependency_path(
-        env: &Env,
-        from_id: u64,
-        target_id: u64,
-        visited: &mut Vec<u64>,
-    ) -> Result<bool, VaultError> {
-        if from_id == target_id {
-            return Ok(true);
+    /// Ensure all dependencies have executed.
+    ///
+    /// `depends_on` is immutable and validated acyclic at creation, so this
+    /// reads the unmet-dependency counter maintained by `storage::set_proposal`.
+    fn ensure_dependencies_executable(env: &Env, proposal: &Proposal) -> Result<(), VaultError> {
+        if storage::get_unmet_dependencies(env, proposal.id) > 0 {
+            return Err(VaultError::ProposalNotApproved);
         }
-        if visited.contains(from_id) {
-            return Ok(false);
+        Ok(())
+    }
+
+    /// Return a proposal's insurance and stake to the proposer in full.
+    fn refund_proposal_deposits(env: &Env, proposal: &Proposal) {
+        let proposal_id = proposal.id;
+
+        // ── Refund insurance in full ─────────────────────────────────────
+        if proposal.insurance_amount > 0 {
+            token::transfer(
+                env,
+                &storage::get_insurance_token(env, proposal),
+                &proposal.proposer,
+                proposal.insurance_amount,
+            );
+            events::emit_insurance_returned(
+                env,
+                proposal_id,
+                &proposal.proposer,
+                proposal.insurance_amount,
+            );
         }
 
-        visited.push_back(from_id);
+        // ── Refund stake in full ─────────────────────────────────────────
+        if proposal.stake_amount > 0 {
+            if let Some(mut stake_record) = storage::get_stake_record(env, proposal_id) {
+                if !stake_record.refunded && !stake_record.slashed {
+                    token::transfer(
+                        env,
+                        &proposal.token,
+                        &proposal.proposer,
+                        proposal.stake_amount,
+                    );
+
+                    stake_record.refunded = true;
+                    stake_record.released_at = env.ledger().sequence() as u64;
+                    storage::set_stake_record(env, &stake_record);
 
-        let proposal =
-            storage::get_proposal(env, from_id).map_err(|_| VaultError::ProposalNotFound)?;
-        for i in 0..proposal.depends_on.len() {
-            let next_id = proposal.depends_on.get(i).unwrap();
-            if Self::has_dependency_path(env, next_id, target_id, visited)? {
-                return Ok(true);
+                    events::emit_stake_refunded(
+                        env,
+                        proposal_id,
+                        &proposal.proposer,
+                        proposal.stake_amount,
+                    );
+                }
             }
         }
-
-        Ok(false)
     }
 
     /// Calculate effective threshold based on the configured ThresholdStrategy.
@@ -3359,7 +8528,194 @@ impl VaultDAO {
             x = y;
             y = (x + ((value as u128) / x)) / 2;
         }
-        x as u32
+        x as u32
+    }
+
+    /// Approvals each tier of the active threshold strategy requires with
+    /// `signer_count` signers (see `SignerChangePreview::tier_thresholds`).
+    fn strategy_tier_thresholds(env: &Env, config: &Config, signer_count: u32) -> Vec<u32> {
+        let mut thresholds = Vec::new(env);
+        match &config.threshold_strategy {
+            ThresholdStrategy::Fixed => thresholds.push_back(config.threshold),
+            ThresholdStrategy::Percentage(pct) => {
+                let required = (u64::from(signer_count) * u64::from(*pct))
+                    .div_ceil(100)
+                    .max(1);
+                thresholds.push_back(required as u32);
+            }
+            ThresholdStrategy::AmountBased(tiers) => {
+                thresholds.push_back(config.threshold);
+                for tier in tiers.iter() {
+                    thresholds.push_back(tier.approvals);
+                }
+            }
+            ThresholdStrategy::TimeBased(tb) => {
+                thresholds.push_back(tb.initial_threshold);
+                thresholds.push_back(tb.reduced_threshold);
+            }
+        }
+        thresholds
+    }
+
+    /// Indexes of `thresholds` that more than `signer_count` signers would be needed for.
+    fn unreachable_tiers(env: &Env, thresholds: &Vec<u32>, signer_count: u32) -> Vec<u32> {
+        let mut unreachable = Vec::new(env);
+        for (i, required) in thresholds.iter().enumerate() {
+            if required > signer_count {
+                unreachable.push_back(i as u32);
+            }
+        }
+        unreachable
+    }
+
+    /// Pending invite for `invitee` that is still inside its window.
+    fn live_signer_invite(env: &Env, invitee: &Address) -> Result<SignerInvite, VaultError> {
+        let invite =
+            storage::get_signer_invite(env, invitee).ok_or(VaultError::AddressNotOnList)?;
+        if env.ledger().sequence() as u64 > invite.expires_at {
+            return Err(VaultError::AddressNotOnList);
+        }
+        Ok(invite)
+    }
+
+    /// Reject a signer set under which some tier of the active strategy could never pass.
+    fn ensure_strategy_reachable(
+        env: &Env,
+        config: &Config,
+        signer_count: u32,
+    ) -> Result<(), VaultError> {
+        let thresholds = Self::strategy_tier_thresholds(env, config, signer_count);
+        if Self::unreachable_tiers(env, &thresholds, signer_count).is_empty() {
+            Ok(())
+        } else {
+            Err(VaultError::ThresholdTooHigh)
+        }
+    }
+
+    /// Amount tiers must be strictly ascending and require 1..=signers approvals.
+    fn validate_threshold_strategy(
+        strategy: &ThresholdStrategy,
+        signer_count: u32,
+    ) -> Result<(), VaultError> {
+        if let ThresholdStrategy::AmountBased(tiers) = strategy {
+            let mut previous: Option<i128> = None;
+            for tier in tiers.iter() {
+                if tier.approvals < 1 {
+                    return Err(VaultError::ThresholdTooLow);
+                }
+                if tier.approvals > signer_count {
+                    return Err(VaultError::ThresholdTooHigh);
+                }
+                if tier.amount < 0 || previous.is_some_and(|prev| tier.amount <= prev) {
+                    return Err(VaultError::InvalidAmount);
+                }
+                previous = Some(tier.amount);
+            }
+        }
+        Ok(())
+    }
+
+    /// Send slashed insurance to the configured destination.
+    fn distribute_insurance_slash(
+        env: &Env,
+        proposal: &Proposal,
+        rejector: &Address,
+        destination: &SlashDestination,
+        amount: i128,
+    ) {
+        let insurance_token = storage::get_insurance_token(env, proposal);
+        match destination {
+            SlashDestination::Pool => {
+                storage::add_to_insurance_pool(env, &insurance_token, amount);
+            }
+            SlashDestination::Burn => {
+                if token::try_burn(env, &insurance_token, amount).is_err() {
+                    storage::add_to_insurance_pool(env, &insurance_token, amount);
+                }
+            }
+            SlashDestination::RejectingAdmins => {
+                let mut rejectors = Vec::new(env);
+                rejectors.push_back(rejector.clone());
+                Self::split_slash(env, &insurance_token, &rejectors, amount);
+            }
+            SlashDestination::Approvers => {
+                Self::split_slash(env, &insurance_token, &proposal.approvals, amount);
+            }
+        }
+    }
+
+    /// Split `amount` equally between `recipients`; dust (or everything, if
+    /// there is nobody to pay) stays in the insurance pool.
+    fn split_slash(env: &Env, token_addr: &Address, recipients: &Vec<Address>, amount: i128) {
+        let (share, dust) = math::split_with_remainder(amount, recipients.len());
+        if share > 0 {
+            for recipient in recipients.iter() {
+                token::transfer(env, token_addr, &recipient, share);
+            }
+        }
+        if dust > 0 {
+            storage::add_to_insurance_pool(env, token_addr, dust);
+        }
+    }
+
+    /// Reject insurance settings that would make the deposit/slash math meaningless.
+    fn validate_insurance_config(config: &InsuranceConfig) -> Result<(), VaultError> {
+        if config.min_amount < 0
+            || config.min_insurance_bps > 10_000
+            || config.slash_percentage > 100
+        {
+            return Err(VaultError::InvalidAmount);
+        }
+        Ok(())
+    }
+
+    /// Reject staking settings outside their bps/percentage ranges.
+    fn validate_staking_config(config: &types::StakingConfig) -> Result<(), VaultError> {
+        if config.min_amount < 0
+            || config.max_stake_amount <= 0
+            || config.base_stake_bps > 10_000
+            || config.slash_percentage > 100
+            || config.reputation_discount_percentage > 100
+        {
+            return Err(VaultError::InvalidAmount);
+        }
+        Ok(())
+    }
+
+    /// Reject fee rates above 100% and tiers not sorted by volume.
+    fn validate_fee_structure(fee_structure: &types::FeeStructure) -> Result<(), VaultError> {
+        if fee_structure.base_fee_bps > 10_000 {
+            return Err(VaultError::InvalidAmount);
+        }
+
+        // Validate tiers are sorted by min_volume
+        for i in 1..fee_structure.tiers.len() {
+            let prev = fee_structure.tiers.get(i - 1).unwrap();
+            let curr = fee_structure.tiers.get(i).unwrap();
+            if curr.min_volume <= prev.min_volume {
+                return Err(VaultError::InvalidAmount);
+            }
+            if curr.fee_bps > 10_000 {
+                return Err(VaultError::InvalidAmount);
+            }
+        }
+
+        if fee_structure.reputation_discount_percentage > 100 {
+            return Err(VaultError::InvalidAmount);
+        }
+        Ok(())
+    }
+
+    fn validate_gas_config(config: &GasConfig) -> Result<(), VaultError> {
+        // An enforced limit needs a real base cost, and a default limit below the
+        // base cost would make every new proposal unexecutable.
+        if config.enabled && config.base_cost == 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+        if config.default_gas_limit > 0 && config.default_gas_limit < config.base_cost {
+            return Err(VaultError::InvalidAmount);
+        }
+        Ok(())
     }
 
     fn validate_voting_strategy(strategy: &VotingStrategy) -> Result<(), VaultError> {
@@ -3371,28 +8727,107 @@ impl VaultDAO {
         }
     }
 
+    /// Approval threshold that applies to a proposal.
+    ///
+    /// The value frozen at creation is used as-is, so config changes in either
+    /// direction do not move the goalposts mid-vote. Legacy proposals without
+    /// a snapshot use the live configuration.
+    fn required_threshold(env: &Env, config: &Config, proposal: &Proposal) -> u32 {
+        match storage::get_vote_snapshot(env, proposal.id) {
+            Some((threshold, _)) => threshold,
+            None => Self::calculate_threshold(config, &proposal.amount),
+        }
+    }
+
+    /// Quorum requirement that applies to a proposal (snapshot first, see `required_threshold`).
+    fn required_quorum(env: &Env, config: &Config, proposal: &Proposal) -> u32 {
+        match storage::get_vote_snapshot(env, proposal.id) {
+            Some((_, quorum)) => quorum,
+            None => config.quorum,
+        }
+    }
+
+    /// Book an executed proposal into the performance metrics, the checkpoint
+    /// outflow and today's flow bucket. Shared by every execution path.
+    fn record_execution_stats(env: &Env, proposal: &Proposal, current_ledger: u64) {
+        let execution_time = current_ledger.saturating_sub(proposal.created_at);
+        storage::metrics_on_execution(env, proposal.gas_used, execution_time);
+        storage::record_outflow(env, &proposal.token, proposal.amount);
+        storage::record_daily_execution(env, &proposal.token);
+        events::emit_execution_fee_used(env, proposal.id, proposal.gas_used);
+    }
+
+    /// Freeze the approval evidence for a just-executed proposal so later
+    /// config changes cannot alter what `get_execution_proof` reports.
+    fn record_execution_proof(env: &Env, config: &Config, proposal: &Proposal, executed_at: u64) {
+        let mut approval_ledgers = Vec::new(env);
+        for approver in proposal.approvals.iter() {
+            approval_ledgers
+                .push_back(storage::get_approval_ledger(env, proposal.id, &approver).unwrap_or(0));
+        }
+        let threshold = Self::required_threshold(env, config, proposal);
+        let quorum = Self::required_quorum(env, config, proposal);
+        let hash = env.crypto().sha256(
+            &(
+                proposal.id,
+                proposal.snapshot_signers.clone(),
+                proposal.approvals.clone(),
+                approval_ledgers.clone(),
+                threshold,
+                quorum,
+                executed_at,
+            )
+                .to_xdr(env),
+        );
+        storage::set_execution_proof(
+            env,
+            &ExecutionProof {
+                proposal_id: proposal.id,
+                snapshot_signers: proposal.snapshot_signers.clone(),
+                approvals: proposal.approvals.clone(),
+                approval_ledgers,
+                threshold,
+                quorum,
+                executed_at,
+                hash: hash.into(),
+            },
+        );
+    }
+
+    /// Approvals that count toward the threshold.
+    ///
+    /// When self-approval is disabled, a proposer approval cast before the
+    /// switch is ignored, as is an approval from a signer whose cap (frozen at
+    /// creation) is below the proposal amount. Quorum still counts every vote.
+    fn threshold_approvals(env: &Env, proposal: &Proposal) -> u32 {
+        let skip_proposer = !storage::get_proposer_can_approve(env);
+        let mut approvals = 0;
+        for approver in proposal.approvals.iter() {
+            if skip_proposer && approver == proposal.proposer {
+                continue;
+            }
+            let cap = storage::get_proposal_approval_cap(env, proposal.id, &approver);
+            if cap > 0 && proposal.amount > cap {
+                continue;
+            }
+            approvals += 1;
+        }
+        approvals
+    }
+
     fn is_threshold_reached(env: &Env, config: &Config, proposal: &Proposal) -> bool {
         let strategy = storage::get_voting_strategy(env);
+        let required = Self::required_threshold(env, config, proposal);
+        let approvals = Self::threshold_approvals(env, proposal);
         match strategy {
-            VotingStrategy::Simple => {
-                proposal.approvals.len() >= Self::calculate_threshold(config, &proposal.amount)
-            }
-            VotingStrategy::Weighted => {
-                let required = Self::calculate_threshold(config, &proposal.amount);
-                proposal.approvals.len() >= required
-            }
-            VotingStrategy::Quadratic => {
-                let required = Self::calculate_threshold(config, &proposal.amount);
-                proposal.approvals.len() >= required
-            }
-            VotingStrategy::Conviction => {
-                let required = Self::calculate_threshold(config, &proposal.amount);
-                proposal.approvals.len() >= required
-            }
+            VotingStrategy::Simple => approvals >= required,
+            VotingStrategy::Weighted => approvals >= required,
+            VotingStrategy::Quadratic => approvals >= required,
+            VotingStrategy::Conviction => approvals >= required,
         }
     }
 
-    /// Validate that approvals and quorum participation both satisfy current requirements.
+    /// Validate that approvals and quorum participation both satisfy the proposal's requirements.
     fn ensure_vote_requirements_satisfied(
         env: &Env,
         config: &Config,
@@ -3400,8 +8835,9 @@ impl VaultDAO {
     ) -> Result<(), VaultError> {
         let approval_count = proposal.approvals.len();
         let quorum_votes = approval_count + proposal.abstentions.len();
+        let quorum = Self::required_quorum(env, config, proposal);
         let threshold_reached = Self::is_threshold_reached(env, config, proposal);
-        let quorum_reached = config.quorum == 0 || quorum_votes >= config.quorum;
+        let quorum_reached = quorum == 0 || quorum_votes >= quorum;
         if !threshold_reached {
             return Err(VaultError::ProposalNotApproved);
         }
@@ -3412,15 +8848,20 @@ impl VaultDAO {
     }
 
     /// Evaluate whether all/any execution conditions are satisfied.
-    fn evaluate_conditions(env: &Env, proposal: &Proposal) -> Result<(), VaultError> {
+    fn evaluate_conditions(
+        env: &Env,
+        token_addr: &Address,
+        conditions: &Vec<Condition>,
+        condition_logic: &ConditionLogic,
+    ) -> Result<(), VaultError> {
         let current_ledger = env.ledger().sequence() as u64;
         let mut results = Vec::new(env);
 
-        for i in 0..proposal.conditions.len() {
-            if let Some(cond) = proposal.conditions.get(i) {
+        for i in 0..conditions.len() {
+            if let Some(cond) = conditions.get(i) {
                 let satisfied = match cond {
                     Condition::BalanceAbove(min_balance) => {
-                        token::balance(env, &proposal.token) > min_balance
+                        token::balance(env, token_addr) > min_balance
                     }
                     Condition::DateAfter(after_ledger) => current_ledger > after_ledger,
                     Condition::DateBefore(before_ledger) => current_ledger < before_ledger,
@@ -3443,7 +8884,7 @@ impl VaultDAO {
             }
         }
 
-        let all_passed = match proposal.condition_logic {
+        let all_passed = match condition_logic {
             ConditionLogic::And => {
                 let mut all = true;
                 for i in 0..results.len() {
@@ -3480,6 +8921,7 @@ impl VaultDAO {
         oracle_config: crate::VaultOracleConfig,
     ) -> Result<(), VaultError> {
         admin.require_auth();
+        Self::ensure_initialized(&env)?;
         if storage::get_role(&env, &admin) != Role::Admin {
             return Err(VaultError::InsufficientRole);
         }
@@ -3498,6 +8940,11 @@ impl VaultDAO {
             crate::OptionalVaultOracleConfig::None => return Err(VaultError::NotInitialized),
         };
 
+        #[cfg(test)]
+        if crate::test_hooks::is_oracle_outage_forced(env, &asset) {
+            return Err(VaultError::RetryError);
+        }
+
         // Interface with standard Oracle contract
         // lastprice(asset: Address) -> Option<VaultPriceData>
         let price_data: Option<VaultPriceData> = env.invoke_contract(
@@ -3542,6 +8989,9 @@ impl VaultDAO {
 
     /// Award small reputation boost when a proposal is created.
     fn update_reputation_on_propose(env: &Env, proposer: &Address) {
+        if storage::is_contract_signer(env, proposer) {
+            return;
+        }
         let mut rep = storage::get_reputation(env, proposer);
         storage::apply_reputation_decay(env, &mut rep);
         rep.proposals_created += 1;
@@ -3550,6 +9000,9 @@ impl VaultDAO {
 
     /// Award small reputation boost when a signer approves a proposal.
     fn update_reputation_on_approval(env: &Env, signer: &Address) {
+        if storage::is_contract_signer(env, signer) {
+            return;
+        }
         let mut rep = storage::get_reputation(env, signer);
         storage::apply_reputation_decay(env, &mut rep);
         let old_score = rep.score;
@@ -3570,8 +9023,23 @@ impl VaultDAO {
         }
     }
 
+    /// Track participation for a keeper that triggered a recurring payment.
+    fn update_reputation_on_keeper_run(env: &Env, executor: &Address) {
+        if storage::is_contract_signer(env, executor) {
+            return;
+        }
+        let mut rep = storage::get_reputation(env, executor);
+        storage::apply_reputation_decay(env, &mut rep);
+        rep.participation_count = rep.participation_count.saturating_add(1);
+        rep.last_participation_ledger = env.ledger().sequence() as u64;
+        storage::set_reputation(env, executor, &rep);
+    }
+
     /// Track signer participation for abstentions.
     fn update_reputation_on_abstention(env: &Env, signer: &Address) {
+        if storage::is_contract_signer(env, signer) {
+            return;
+        }
         let mut rep = storage::get_reputation(env, signer);
         storage::apply_reputation_decay(env, &mut rep);
         rep.abstentions_given = rep.abstentions_given.saturating_add(1);
@@ -3583,7 +9051,7 @@ impl VaultDAO {
     /// Reward proposer and all approvers on successful execution.
     fn update_reputation_on_execution(env: &Env, proposal: &Proposal) {
         // Reward proposer
-        {
+        if !storage::is_contract_signer(env, &proposal.proposer) {
             let mut rep = storage::get_reputation(env, &proposal.proposer);
             storage::apply_reputation_decay(env, &mut rep);
             let old_score = rep.score;
@@ -3605,6 +9073,9 @@ impl VaultDAO {
         // Reward each approver
         for i in 0..proposal.approvals.len() {
             if let Some(approver) = proposal.approvals.get(i) {
+                if storage::is_contract_signer(env, &approver) {
+                    continue;
+                }
                 let mut rep = storage::get_reputation(env, &approver);
                 storage::apply_reputation_decay(env, &mut rep);
                 let old_score = rep.score;
@@ -3626,6 +9097,9 @@ impl VaultDAO {
 
     /// Penalize proposer reputation when rejection occurs.
     fn update_reputation_on_rejection(env: &Env, proposer: &Address) {
+        if storage::is_contract_signer(env, proposer) {
+            return;
+        }
         let mut rep = storage::get_reputation(env, proposer);
         storage::apply_reputation_decay(env, &mut rep);
         let old_score = rep.score;
@@ -3692,7 +9166,7 @@ impl VaultDAO {
         }
 
         // Calculate base fee
-        let base_fee = (amount * fee_bps as i128) / 10_000;
+        let base_fee = math::bps_of(amount, fee_bps);
 
         // Check for reputation discount
         let rep = storage::get_reputation(env, user);
@@ -3700,7 +9174,7 @@ impl VaultDAO {
         let mut reputation_discount_applied = false;
 
         if rep.score >= fee_structure.reputation_discount_threshold {
-            discount = (base_fee * fee_structure.reputation_discount_percentage as i128) / 100;
+            discount = math::pct_of(base_fee, fee_structure.reputation_discount_percentage);
             reputation_discount_applied = true;
         }
 
@@ -3715,26 +9189,25 @@ impl VaultDAO {
         }
     }
 
-    /// Collect fee from a transaction and distribute to treasury.
+    /// Collect a fee computed by `calculate_fee_internal` and distribute it to
+    /// the treasury. Callers collect only after the payout succeeded, so a
+    /// failed attempt that schedules a retry never charges the fee.
     ///
     /// # Arguments
     /// * `env` - The environment
     /// * `user` - The user making the transaction
     /// * `token` - The token being transferred
     /// * `amount` - The transaction amount
-    ///
-    /// # Returns
-    /// The fee amount collected
+    /// * `fee_calc` - The fee computed for this transaction
     fn collect_and_distribute_fee(
         env: &Env,
         user: &Address,
         token: &Address,
         amount: i128,
-    ) -> Result<i128, VaultError> {
-        let fee_calc = Self::calculate_fee_internal(env, user, token, amount);
-
+        fee_calc: &types::FeeCalculation,
+    ) {
         if fee_calc.final_fee == 0 {
-            return Ok(0);
+            return;
         }
 
         let fee_structure = storage::get_fee_structure(env);
@@ -3758,8 +9231,6 @@ impl VaultDAO {
             fee_calc.fee_bps,
             fee_calc.reputation_discount_applied,
         );
-
-        Ok(fee_calc.final_fee)
     }
 
     // ============================================================================
@@ -3772,6 +9243,7 @@ impl VaultDAO {
         dex_config: DexConfig,
     ) -> Result<(), VaultError> {
         admin.require_auth();
+        Self::ensure_initialized(&env)?;
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
             return Err(VaultError::Unauthorized);
@@ -3814,15 +9286,62 @@ impl VaultDAO {
         if !dex_config.enabled_dexs.contains(dex_addr) {
             return Err(VaultError::DexError);
         }
+        Self::check_pending_cap(&env, &config, &proposer, 1)?;
+
+        // Swaps spend vault funds just like transfers, so they go through the
+        // same velocity and spending-limit checks and reserve capacity.
+        if !storage::check_and_update_velocity(
+            &env,
+            &proposer,
+            &config.velocity_limit,
+            VelocityAction::Propose,
+        ) {
+            return Err(VaultError::VelocityLimitExceeded);
+        }
+        let (spend_token, amount) = Self::swap_spend(&env, &swap_op);
+        if amount < 0 || insurance_amount < 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+        if amount > 0 {
+            Self::check_spending_limits(&env, &config, &proposer, amount)?;
+            Self::reserve_spending(&env, amount);
+        }
+        // Insurance is locked in the spent token, so zero-spend swaps carry none
+        if insurance_amount > 0 {
+            if amount == 0 {
+                return Err(VaultError::InvalidAmount);
+            }
+            token::transfer_to_vault(&env, &spend_token, &proposer, insurance_amount);
+        }
 
         let current_ledger = env.ledger().sequence() as u64;
+        let unlock_ledger = if amount > 0 && amount >= config.timelock_threshold {
+            current_ledger + config.timelock_delay
+        } else {
+            0
+        };
+        let gas_cfg = storage::get_gas_config(&env);
+        let proposal_gas_limit = if gas_cfg.enabled {
+            gas_cfg.default_gas_limit
+        } else {
+            0
+        };
+
+        // Zero-spend swaps (reward claims) have no real spend token to track
+        if amount > 0 {
+            storage::track_token(&env, &spend_token);
+        }
         let proposal_id = storage::increment_proposal_id(&env);
+        if amount > 0 {
+            Self::reserve_category_budget(&env, &None, proposal_id, amount)?;
+            storage::log_daily_proposal(&env, storage::get_day_number(&env), proposal_id);
+        }
         let proposal = Proposal {
             id: proposal_id,
             proposer: proposer.clone(),
             recipient: env.current_contract_address(),
-            token: env.current_contract_address(),
-            amount: 0,
+            token: spend_token.clone(),
+            amount,
             memo: Symbol::new(&env, "swap"),
             metadata: Map::new(&env),
             tags: Vec::new(&env),
@@ -3835,11 +9354,11 @@ impl VaultDAO {
             condition_logic,
             created_at: current_ledger,
             expires_at: calculate_expiration_ledger(&config, &priority, current_ledger),
-            unlock_ledger: 0,
+            unlock_ledger,
             execution_time: None,
             insurance_amount,
             stake_amount: 0,
-            gas_limit: 0,
+            gas_limit: proposal_gas_limit,
             gas_used: 0,
             snapshot_ledger: current_ledger,
             snapshot_signers: config.signers.clone(),
@@ -3853,18 +9372,22 @@ impl VaultDAO {
         };
 
         storage::set_proposal(&env, &proposal);
-        Self::persist_execution_fee_estimate(&env, &proposal);
-        storage::set_swap_proposal(&env, proposal_id, &swap_op);
-        storage::add_to_priority_queue(&env, priority as u32, proposal_id);
-        events::emit_proposal_created(
+        Self::record_proposal_created(&env, &proposal);
+        storage::set_vote_snapshot(
             &env,
             proposal_id,
-            &proposer,
-            &env.current_contract_address(),
-            &env.current_contract_address(),
-            0,
-            0,
+            Self::calculate_threshold(&config, &amount),
+            config.quorum,
         );
+        // Tips are paid in the spent token; reward claims spend nothing.
+        if amount > 0 {
+            storage::set_executor_tip(&env, proposal_id, Self::default_executor_tip(&env));
+        }
+        Self::persist_execution_fee_estimate(&env, &proposal);
+        storage::set_swap_proposal(&env, proposal_id, &swap_op);
+        storage::add_to_priority_queue(&env, priority as u32, proposal_id);
+        events::emit_proposal_created(&env, &proposal);
+        Self::notify_signers_of_proposal(&env, &config, &proposer);
         Self::update_reputation_on_propose(&env, &proposer);
         storage::metrics_on_proposal(&env);
 
@@ -3987,6 +9510,118 @@ impl VaultDAO {
     pub fn get_swap_result(env: Env, proposal_id: u64) -> Option<SwapResult> {
         storage::get_swap_result(&env, proposal_id)
     }
+
+    /// Execute an approved swap proposal.
+    ///
+    /// Runs the standard execution pipeline (timelock, hooks, fees, retries);
+    /// the payout step performs the DEX operation instead of a transfer. The
+    /// outcome is available from `get_swap_result` once executed.
+    pub fn execute_swap_proposal(
+        env: Env,
+        executor: Address,
+        proposal_id: u64,
+    ) -> Result<(), VaultError> {
+        let proposal = storage::get_proposal(&env, proposal_id)?;
+        if !proposal.is_swap {
+            return Err(VaultError::DexError);
+        }
+        Self::execute_proposal(env, executor, proposal_id)
+    }
+
+    /// Perform a swap proposal's DEX operation. Inputs are pushed to the DEX
+    /// or farm before the call; outputs are paid back to the vault.
+    fn perform_swap(env: &Env, proposal: &Proposal) -> Result<(), VaultError> {
+        let swap_op =
+            storage::get_swap_proposal(env, proposal.id).ok_or(VaultError::ProposalNotFound)?;
+        let vault = env.current_contract_address();
+        let push = |token_addr: &Address, to: &Address, amount: i128| {
+            if amount > 0 && token::try_transfer(env, token_addr, to, amount).is_err() {
+                return Err(VaultError::TransferFailed);
+            }
+            Ok(())
+        };
+
+        let (amount_in, amount_out) = match swap_op {
+            SwapProposal::Swap(dex, token_in, token_out, amount_in, min_out) => {
+                push(&token_in, &dex, amount_in)?;
+                let amount_out: i128 = env.invoke_contract(
+                    &dex,
+                    &Symbol::new(env, "swap"),
+                    (vault, token_in, token_out, amount_in, min_out).into_val(env),
+                );
+                if amount_out < min_out {
+                    return Err(VaultError::DexError);
+                }
+                events::emit_swap_executed(env, proposal.id, &dex, amount_in, amount_out);
+                (amount_in, amount_out)
+            }
+            SwapProposal::AddLiquidity(dex, token_a, token_b, amount_a, amount_b, min_lp) => {
+                push(&token_a, &dex, amount_a)?;
+                push(&token_b, &dex, amount_b)?;
+                let lp_tokens: i128 = env.invoke_contract(
+                    &dex,
+                    &Symbol::new(env, "add_liquidity"),
+                    (vault, token_a, token_b, amount_a, amount_b, min_lp).into_val(env),
+                );
+                if lp_tokens < min_lp {
+                    return Err(VaultError::DexError);
+                }
+                events::emit_liquidity_added(env, proposal.id, &dex, lp_tokens);
+                (amount_a, lp_tokens)
+            }
+            SwapProposal::RemoveLiquidity(dex, lp_token, amount, min_a, min_b) => {
+                push(&lp_token, &dex, amount)?;
+                // The DEX enforces both minimums and reports the combined output.
+                let amount_out: i128 = env.invoke_contract(
+                    &dex,
+                    &Symbol::new(env, "remove_liquidity"),
+                    (vault, lp_token, amount, min_a, min_b).into_val(env),
+                );
+                events::emit_liquidity_removed(env, proposal.id, &dex, amount);
+                (amount, amount_out)
+            }
+            SwapProposal::StakeLp(farm, lp_token, amount) => {
+                push(&lp_token, &farm, amount)?;
+                env.invoke_contract::<()>(
+                    &farm,
+                    &Symbol::new(env, "stake"),
+                    (vault, lp_token, amount).into_val(env),
+                );
+                events::emit_lp_staked(env, proposal.id, &farm, amount);
+                (amount, 0)
+            }
+            SwapProposal::UnstakeLp(farm, lp_token, amount) => {
+                env.invoke_contract::<()>(
+                    &farm,
+                    &Symbol::new(env, "unstake"),
+                    (vault, lp_token, amount).into_val(env),
+                );
+                events::emit_lp_unstaked(env, proposal.id, &farm, amount);
+                (0, amount)
+            }
+            SwapProposal::ClaimRewards(farm) => {
+                let rewards: i128 = env.invoke_contract(
+                    &farm,
+                    &Symbol::new(env, "claim_rewards"),
+                    (vault,).into_val(env),
+                );
+                events::emit_rewards_claimed(env, proposal.id, &farm, rewards);
+                (0, rewards)
+            }
+        };
+
+        storage::set_swap_result(
+            env,
+            proposal.id,
+            &SwapResult {
+                amount_in,
+                amount_out,
+                price_impact_bps: 0,
+                executed_at: env.ledger().sequence() as u64,
+            },
+        );
+        Ok(())
+    }
     // ========================================================================
     // Retry Helpers (private)
     // ========================================================================
@@ -3995,13 +9630,21 @@ impl VaultDAO {
     /// so that retryable failures can be caught and handled.
     fn try_execute_transfer(
         env: &Env,
-        _executor: &Address,
+        executor: &Address,
         proposal: &mut Proposal,
         _current_ledger: u64,
     ) -> Result<(), VaultError> {
+        // Recipient lists may have changed since the proposal was created
+        Self::validate_payout_recipients(env, proposal)?;
+
         // Evaluate execution conditions (if any) before balance check
         if !proposal.conditions.is_empty() {
-            Self::evaluate_conditions(env, proposal)?;
+            Self::evaluate_conditions(
+                env,
+                &proposal.token,
+                &proposal.conditions,
+                &proposal.condition_logic,
+            )?;
         }
 
         // Gas limit check
@@ -4016,32 +9659,53 @@ impl VaultDAO {
             return Err(VaultError::GasLimitExceeded);
         }
 
-        // Calculate fee for this transaction
-        let fee_amount = Self::collect_and_distribute_fee(
-            env,
-            &proposal.proposer,
-            &proposal.token,
-            proposal.amount,
-        )?;
+        // Calculate fee for this transaction; it is collected after the payout
+        let fee_calc =
+            Self::calculate_fee_internal(env, &proposal.proposer, &proposal.token, proposal.amount);
+        let fee_amount = fee_calc.final_fee;
 
-        // Check vault balance (account for insurance amount and fee)
-        let balance = token::balance(env, &proposal.token);
-        let total_required = proposal.amount + proposal.insurance_amount + fee_amount;
-        if balance < total_required {
+        // Check vault balance (account for insurance amount, fee and executor tip)
+        let insurance_token = storage::get_insurance_token(env, proposal);
+        let mut total_required =
+            proposal.amount + fee_amount + storage::get_executor_tip(env, proposal.id);
+        if insurance_token == proposal.token {
+            total_required += proposal.insurance_amount;
+        }
+        // Zero-spend swaps (reward claims) carry no real token to check.
+        if total_required > 0 && token::balance(env, &proposal.token) < total_required {
             return Err(VaultError::InsufficientBalance);
         }
+        let withdrawal_pool = Self::withdrawal_pool(env, proposal)?;
+
+        // Pre-hooks run immediately before the transfer, post-hooks right after
+        let config = storage::get_config(env)?;
+        for hook in config.pre_execution_hooks.iter() {
+            Self::call_hook(env, &hook, proposal.id, true);
+        }
 
         // Execute transfer
-        if token::try_transfer(env, &proposal.token, &proposal.recipient, proposal.amount).is_err()
-        {
-            return Err(VaultError::TransferFailed);
+        Self::transfer_payout(env, proposal)?;
+        if let Some(pool) = withdrawal_pool {
+            storage::subtract_from_pool(env, &pool, &proposal.token, proposal.amount);
+        }
+        Self::collect_and_distribute_fee(
+            env,
+            &proposal.proposer,
+            &proposal.token,
+            proposal.amount,
+            &fee_calc,
+        );
+
+        for hook in config.post_execution_hooks.iter() {
+            Self::call_hook(env, &hook, proposal.id, false);
         }
+        Self::pay_executor_tip(env, proposal, executor);
 
         // Return insurance to proposer on success
         if proposal.insurance_amount > 0 {
             token::transfer(
                 env,
-                &proposal.token,
+                &insurance_token,
                 &proposal.proposer,
                 proposal.insurance_amount,
             );
@@ -4132,25 +9796,172 @@ impl VaultDAO {
 
     fn calculate_execution_fee(env: &Env, proposal: &Proposal) -> ExecutionFeeEstimate {
         let gas_cfg = storage::get_gas_config(env);
-        let mut operation_count: u32 = 1; // Core transfer step.
-        operation_count = operation_count.saturating_add(proposal.conditions.len());
-        if proposal.insurance_amount > 0 {
-            operation_count = operation_count.saturating_add(1);
-        }
-        if proposal.is_swap {
-            operation_count = operation_count.saturating_add(1);
-        }
 
-        let resource_fee = gas_cfg
-            .condition_cost
-            .saturating_mul(operation_count as u64);
+        // Core transfer step plus each condition, split by whether it needs the oracle.
+        let mut plain_steps: u32 = 1;
+        let mut oracle_steps: u32 = 0;
+        for cond in proposal.conditions.iter() {
+            match cond {
+                Condition::PriceAbove(..) | Condition::PriceBelow(..) => oracle_steps += 1,
+                _ => plain_steps += 1,
+            }
+        }
+        let hook_calls = storage::get_config(env)
+            .map(|c| c.pre_execution_hooks.len() + c.post_execution_hooks.len())
+            .unwrap_or(0);
+        let insurance_steps = u32::from(proposal.insurance_amount > 0);
+        let swap_steps = u32::from(proposal.is_swap);
+
+        let condition_fee = gas_cfg.condition_cost.saturating_mul(plain_steps as u64);
+        let oracle_fee = gas_cfg
+            .oracle_condition_cost
+            .saturating_mul(oracle_steps as u64);
+        let hook_fee = gas_cfg.hook_cost.saturating_mul(hook_calls as u64);
+        let swap_fee = gas_cfg.swap_cost.saturating_mul(swap_steps as u64);
+        let insurance_fee = gas_cfg
+            .insurance_cost
+            .saturating_mul(insurance_steps as u64);
+
+        let operation_count = plain_steps
+            .saturating_add(oracle_steps)
+            .saturating_add(hook_calls)
+            .saturating_add(swap_steps)
+            .saturating_add(insurance_steps);
+        let resource_fee = condition_fee
+            .saturating_add(oracle_fee)
+            .saturating_add(hook_fee)
+            .saturating_add(swap_fee)
+            .saturating_add(insurance_fee);
         let total_fee = gas_cfg.base_cost.saturating_add(resource_fee);
+        let protocol_fee =
+            Self::calculate_fee_internal(env, &proposal.proposer, &proposal.token, proposal.amount)
+                .final_fee;
 
         ExecutionFeeEstimate {
             base_fee: gas_cfg.base_cost,
             resource_fee,
             total_fee,
             operation_count,
+            condition_fee,
+            oracle_fee,
+            hook_fee,
+            swap_fee,
+            insurance_fee,
+            protocol_fee,
+        }
+    }
+
+    /// Reserve `amount` against the proposal's category for the current month.
+    ///
+    /// A no-op while no category budgets are registered. Otherwise a missing
+    /// or unregistered category fails with `MetadataValueInvalid` and an
+    /// exhausted budget with `ExceedsWeeklyLimit` (the error enum is full).
+    fn reserve_category_budget(
+        env: &Env,
+        category: &Option<Symbol>,
+        proposal_id: u64,
+        amount: i128,
+    ) -> Result<(), VaultError> {
+        let budgets = storage::get_category_budgets(env);
+        if budgets.is_empty() {
+            return Ok(());
+        }
+        let category = category.clone().ok_or(VaultError::MetadataValueInvalid)?;
+        let limit = budgets
+            .get(category.clone())
+            .ok_or(VaultError::MetadataValueInvalid)?;
+        let month = storage::get_month_number(env);
+        if storage::get_category_spent(env, &category, month) + amount > limit {
+            return Err(VaultError::ExceedsWeeklyLimit);
+        }
+        storage::add_category_spent(env, &category, month, amount);
+        storage::set_proposal_category(env, proposal_id, &category, month);
+        Ok(())
+    }
+
+    /// Move a proposal's category reservation by `delta`. Increases are held
+    /// to the category's current limit; proposals without a reservation are
+    /// left alone.
+    fn adjust_category_budget(env: &Env, proposal_id: u64, delta: i128) -> Result<(), VaultError> {
+        let Some((category, month)) = storage::get_proposal_category(env, proposal_id) else {
+            return Ok(());
+        };
+        if delta > 0 {
+            let limit = storage::get_category_budgets(env)
+                .get(category.clone())
+                .unwrap_or(0);
+            if storage::get_category_spent(env, &category, month) + delta > limit {
+                return Err(VaultError::ExceedsWeeklyLimit);
+            }
+        }
+        storage::add_category_spent(env, &category, month, delta);
+        Ok(())
+    }
+
+    /// Refuse memos outside the allowed list while `enforce_memo_whitelist`
+    /// is set. The error enum is full, so this reuses `MetadataValueInvalid`.
+    fn ensure_memo_allowed(env: &Env, config: &Config, memo: &Symbol) -> Result<(), VaultError> {
+        if config.enforce_memo_whitelist && !storage::get_allowed_memos(env).contains(memo) {
+            return Err(VaultError::MetadataValueInvalid);
+        }
+        Ok(())
+    }
+
+    /// Refuse `new_proposals` more proposals if they would take `proposer`
+    /// past `max_pending_per_proposer`. The error enum is full, so the cap
+    /// reuses `ExceedsProposalLimit`.
+    fn check_pending_cap(
+        env: &Env,
+        config: &Config,
+        proposer: &Address,
+        new_proposals: u32,
+    ) -> Result<(), VaultError> {
+        let cap = config.max_pending_per_proposer;
+        if cap > 0 && storage::get_pending_count(env, proposer) + new_proposals > cap {
+            return Err(VaultError::ExceedsProposalLimit);
+        }
+        Ok(())
+    }
+
+    fn empty_health_bucket(env: &Env) -> HealthBucket {
+        HealthBucket {
+            count: 0,
+            sample_ids: Vec::new(env),
+        }
+    }
+
+    /// Count an item in a health category, keeping its ID if the sample has room.
+    fn record_health_item(bucket: &mut HealthBucket, id: u64) {
+        bucket.count += 1;
+        if bucket.sample_ids.len() < MAX_HEALTH_SAMPLES {
+            bucket.sample_ids.push_back(id);
+        }
+    }
+
+    /// Tip for new proposals: the `GasConfig` default, clamped to the admin cap.
+    fn default_executor_tip(env: &Env) -> i128 {
+        let tip = storage::get_gas_config(env).default_executor_tip;
+        tip.clamp(0, storage::get_max_executor_tip(env))
+    }
+
+    /// Pool a pool-withdrawal proposal draws from (`None` for ordinary
+    /// proposals), failing if the pool no longer covers the amount. The caller
+    /// debits the pool once the payout has gone through.
+    fn withdrawal_pool(env: &Env, proposal: &Proposal) -> Result<Option<PoolKind>, VaultError> {
+        let pool = storage::get_pool_withdrawal(env, proposal.id);
+        if let Some(pool) = &pool {
+            if storage::get_pool_balance(env, pool, &proposal.token) < proposal.amount {
+                return Err(VaultError::InsufficientBalance);
+            }
+        }
+        Ok(pool)
+    }
+
+    /// Pay the proposal's executor tip, if any, after a successful payout.
+    fn pay_executor_tip(env: &Env, proposal: &Proposal, executor: &Address) {
+        let tip = storage::get_executor_tip(env, proposal.id);
+        if tip > 0 {
+            token::transfer(env, &proposal.token, executor, tip);
         }
     }
 
@@ -4163,6 +9974,7 @@ impl VaultDAO {
             estimate.base_fee,
             estimate.resource_fee,
             estimate.total_fee,
+            estimate.protocol_fee,
         );
         estimate
     }
@@ -4199,6 +10011,7 @@ impl VaultDAO {
         max_amount: i128,
     ) -> Result<u64, VaultError> {
         creator.require_auth();
+        Self::ensure_initialized(&env)?;
 
         // Check role - only Admin can create templates
         let role = storage::get_role(&env, &creator);
@@ -4235,6 +10048,7 @@ impl VaultDAO {
             updated_at: current_ledger,
             min_amount,
             max_amount,
+            default_tags: Vec::new(&env),
         };
 
         storage::set_template(&env, &template);
@@ -4259,6 +10073,7 @@ impl VaultDAO {
         is_active: bool,
     ) -> Result<(), VaultError> {
         admin.require_auth();
+        Self::ensure_initialized(&env)?;
 
         // Check role - only Admin can modify templates
         let role = storage::get_role(&env, &admin);
@@ -4317,8 +10132,6 @@ impl VaultDAO {
         template_id: u64,
         overrides: TemplateOverrides,
     ) -> Result<u64, VaultError> {
-        proposer.require_auth();
-
         // Get and validate template
         let template = storage::get_template(&env, template_id)?;
 
@@ -4348,108 +10161,39 @@ impl VaultDAO {
         } else {
             template.memo.clone()
         };
-        let priority = if overrides.override_priority {
-            overrides.priority
-        } else {
-            Priority::Normal
-        };
-
-        // Validate amount is within template bounds
-        if template.min_amount > 0 && amount < template.min_amount {
-            return Err(VaultError::TemplateValidationFailed);
-        }
-        if template.max_amount > 0 && amount > template.max_amount {
-            return Err(VaultError::TemplateValidationFailed);
-        }
-
-        // Load config for validation
-        let config = storage::get_config(&env)?;
-
-        // Velocity limit check
-        if !storage::check_and_update_velocity(&env, &proposer, &config.velocity_limit) {
-            return Err(VaultError::VelocityLimitExceeded);
-        }
-
-        // Validate amount
-        if amount <= 0 {
-            return Err(VaultError::InvalidAmount);
-        }
-
-        // Check per-proposal spending limit
-        if amount > config.spending_limit {
-            return Err(VaultError::ExceedsProposalLimit);
-        }
-
-        // Check daily aggregate limit
-        let today = storage::get_day_number(&env);
-        let spent_today = storage::get_daily_spent(&env, today);
-        if spent_today + amount > config.daily_limit {
-            return Err(VaultError::ExceedsDailyLimit);
-        }
-
-        // Check weekly aggregate limit
-        let week = storage::get_week_number(&env);
-        let spent_week = storage::get_weekly_spent(&env, week);
-        if spent_week + amount > config.weekly_limit {
-            return Err(VaultError::ExceedsWeeklyLimit);
-        }
-
-        // Reserve spending
-        storage::add_daily_spent(&env, today, amount);
-        storage::add_weekly_spent(&env, week, amount);
-
-        // Create proposal
-        let proposal_id = storage::increment_proposal_id(&env);
-        let current_ledger = env.ledger().sequence() as u64;
-
-        // Calculate expiry
-        let expires_at = if config.default_voting_deadline > 0 {
-            current_ledger + config.default_voting_deadline
-        } else {
-            current_ledger + 100000 // Default ~6 days
-        };
-
-        // Calculate unlock ledger for timelock
-        let unlock_ledger = if amount >= config.timelock_threshold {
-            current_ledger + config.timelock_delay
+        let priority = if overrides.override_priority {
+            overrides.priority
         } else {
-            0
+            Priority::Normal
         };
 
-        let proposal = Proposal {
-            id: proposal_id,
-            proposer: proposer.clone(),
+        // Validate amount is within template bounds
+        if template.min_amount > 0 && amount < template.min_amount {
+            return Err(VaultError::TemplateValidationFailed);
+        }
+        if template.max_amount > 0 && amount > template.max_amount {
+            return Err(VaultError::TemplateValidationFailed);
+        }
+
+        // Everything else (recipient lists, velocity, limits, insurance,
+        // timelock, snapshots) goes through the regular transfer path.
+        let proposal_id = Self::propose_transfer_internal(
+            env.clone(),
+            proposer.clone(),
             recipient,
-            token: template.token,
+            template.token.clone(),
             amount,
             memo,
-            metadata: Map::new(&env),
-            tags: Vec::new(&env),
-            approvals: Vec::new(&env),
-            abstentions: Vec::new(&env),
-            attachments: Vec::new(&env),
-            status: ProposalStatus::Pending,
             priority,
-            conditions: Vec::new(&env),
-            condition_logic: ConditionLogic::And,
-            created_at: current_ledger,
-            expires_at,
-            unlock_ledger,
-            execution_time: None,
-            insurance_amount: 0,
-            stake_amount: 0, // Template proposals don't require stake
-            gas_limit: 0,
-            gas_used: 0,
-            snapshot_ledger: current_ledger,
-            snapshot_signers: config.signers.clone(),
-            depends_on: Vec::new(&env),
-            is_swap: false,
-            voting_deadline: 0,
-        };
-
-        storage::set_proposal(&env, &proposal);
-        Self::persist_execution_fee_estimate(&env, &proposal);
-        storage::extend_instance_ttl(&env);
+            Vec::new(&env),
+            ConditionLogic::And,
+            overrides.insurance_amount,
+            Vec::new(&env),
+            None,
+            None,
+            None,
+        )?;
+        Self::apply_template_tags(&env, proposal_id, &template)?;
 
         events::emit_proposal_from_template(
             &env,
@@ -4504,10 +10248,60 @@ impl VaultDAO {
     fn is_retryable_error(err: &VaultError) -> bool {
         matches!(
             err,
-            VaultError::InsufficientBalance | VaultError::ConditionsNotMet
+            VaultError::InsufficientBalance
+                | VaultError::ConditionsNotMet
+                | VaultError::TransferFailed
         )
     }
 
+    /// Refuse calls until `initialize` has run.
+    fn ensure_initialized(env: &Env) -> Result<(), VaultError> {
+        if !storage::is_initialized(env) {
+            return Err(VaultError::NotInitialized);
+        }
+        Ok(())
+    }
+
+    /// Refuse execution while the anomaly breaker has frozen the vault. The
+    /// error enum is full, so this reuses `ConditionsNotMet`.
+    fn ensure_not_paused(env: &Env) -> Result<(), VaultError> {
+        if storage::is_paused(env) {
+            return Err(VaultError::ConditionsNotMet);
+        }
+        Ok(())
+    }
+
+    /// Log an execution failure in the rolling window and freeze the vault
+    /// once the window holds more failures than the configured threshold.
+    fn record_execution_failure(env: &Env, err: &VaultError) {
+        let freeze = storage::get_auto_freeze_config(env);
+        if freeze.threshold == 0 || storage::is_paused(env) {
+            return;
+        }
+
+        let now = env.ledger().sequence() as u64;
+        let cutoff = now.saturating_sub(freeze.window_ledgers);
+        let mut failures = Vec::new(env);
+        for (ledger, code) in storage::get_recent_failures(env).iter() {
+            if ledger >= cutoff {
+                failures.push_back((ledger, code));
+            }
+        }
+        failures.push_back((now, *err as u32));
+
+        if failures.len() > freeze.threshold {
+            let mut reasons = Vec::new(env);
+            for (_, code) in failures.iter() {
+                reasons.push_back(code);
+            }
+            storage::set_paused(env, true);
+            storage::set_recent_failures(env, &Vec::new(env));
+            events::emit_auto_freeze_triggered(env, failures.len(), &reasons);
+        } else {
+            storage::set_recent_failures(env, &failures);
+        }
+    }
+
     /// Schedule a retry for a failed proposal execution with exponential backoff.
     ///
     /// Returns Ok(()) to signal that retry was scheduled (caller should also return Ok
@@ -4526,6 +10320,7 @@ impl VaultDAO {
         });
 
         retry_state.retry_count += 1;
+        Self::record_execution_failure(env, err);
 
         if retry_state.retry_count > retry_config.max_retries {
             events::emit_retries_exhausted(env, proposal_id, retry_state.retry_count);
@@ -4540,11 +10335,13 @@ impl VaultDAO {
         retry_state.last_retry_ledger = current_ledger;
 
         storage::set_retry_state(env, proposal_id, &retry_state);
+        storage::metrics_on_retry_scheduled(env);
 
         // Map error to a u32 code for the event
         let error_code: u32 = match err {
             VaultError::InsufficientBalance => 70,
             VaultError::ConditionsNotMet => 140,
+            VaultError::TransferFailed => 71,
             _ => 0,
         };
 
@@ -4573,6 +10370,7 @@ impl VaultDAO {
     /// * `milestones` - Milestones defining progressive release
     /// * `duration_ledgers` - Duration until expiry (full refund after)
     /// * `arbitrator` - Address for dispute resolution
+    #[allow(clippy::too_many_arguments)]
     pub fn create_escrow(
         env: Env,
         funder: Address,
@@ -4582,8 +10380,40 @@ impl VaultDAO {
         milestones: Vec<Milestone>,
         duration_ledgers: u64,
         arbitrator: Address,
+    ) -> Result<u64, VaultError> {
+        Self::create_escrow_with_mode(
+            env,
+            funder,
+            recipient,
+            token_addr,
+            amount,
+            milestones,
+            duration_ledgers,
+            arbitrator,
+            MilestoneMode::Manual,
+        )
+    }
+
+    /// Create an escrow, choosing how its milestones become releasable.
+    ///
+    /// With `MilestoneMode::TimeBased` each milestone unlocks once its
+    /// `release_ledger` has passed, and `release_escrow_funds` (or
+    /// `process_due_escrows`) pays out every unlocked tranche without any
+    /// `complete_milestone` call. Disputes still freeze releases.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_escrow_with_mode(
+        env: Env,
+        funder: Address,
+        recipient: Address,
+        token_addr: Address,
+        amount: i128,
+        milestones: Vec<Milestone>,
+        duration_ledgers: u64,
+        arbitrator: Address,
+        milestone_mode: MilestoneMode,
     ) -> Result<u64, VaultError> {
         funder.require_auth();
+        Self::ensure_initialized(&env)?;
 
         // Validate inputs
         if amount <= 0 {
@@ -4608,12 +10438,15 @@ impl VaultDAO {
             return Err(VaultError::InvalidAmount);
         }
 
+        let current_ledger = env.ledger().sequence() as u64;
+        let expires_at = current_ledger + duration_ledgers;
+        Self::validate_milestone_schedule(&env, &milestones, current_ledger, expires_at)?;
+
         // Transfer tokens to vault (held in escrow)
         token::transfer_to_vault(&env, &token_addr, &funder, amount);
 
         // Create escrow record
         let escrow_id = storage::increment_escrow_id(&env);
-        let current_ledger = env.ledger().sequence() as u64;
 
         let escrow = Escrow {
             id: escrow_id,
@@ -4627,8 +10460,9 @@ impl VaultDAO {
             arbitrator,
             dispute_reason: Symbol::new(&env, ""),
             created_at: current_ledger,
-            expires_at: current_ledger + duration_ledgers,
+            expires_at,
             finalized_at: 0,
+            milestone_mode,
         };
 
         storage::set_escrow(&env, &escrow);
@@ -4648,6 +10482,34 @@ impl VaultDAO {
         Ok(escrow_id)
     }
 
+    /// Milestones must have unique IDs and release ledgers that strictly
+    /// increase within `[current_ledger, expires_at]`; anything else leaves a
+    /// milestone that can never be completed before the escrow expires.
+    fn validate_milestone_schedule(
+        env: &Env,
+        milestones: &Vec<Milestone>,
+        current_ledger: u64,
+        expires_at: u64,
+    ) -> Result<(), VaultError> {
+        let mut seen_ids = Vec::new(env);
+        let mut previous_release: Option<u64> = None;
+        for m in milestones.iter() {
+            if seen_ids.contains(m.id) {
+                return Err(VaultError::SchedulingError);
+            }
+            seen_ids.push_back(m.id);
+
+            if m.release_ledger < current_ledger || m.release_ledger > expires_at {
+                return Err(VaultError::SchedulingError);
+            }
+            if previous_release.is_some_and(|prev| m.release_ledger <= prev) {
+                return Err(VaultError::SchedulingError);
+            }
+            previous_release = Some(m.release_ledger);
+        }
+        Ok(())
+    }
+
     /// Mark a milestone as completed and verify conditions are met
     pub fn complete_milestone(
         env: Env,
@@ -4656,6 +10518,7 @@ impl VaultDAO {
         milestone_id: u64,
     ) -> Result<(), VaultError> {
         completer.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let mut escrow = storage::get_escrow(&env, escrow_id)?;
         let current_ledger = env.ledger().sequence() as u64;
@@ -4720,19 +10583,87 @@ impl VaultDAO {
 
         storage::set_escrow(&env, &escrow);
 
-        events::emit_milestone_completed(&env, escrow_id, milestone_id, &completer);
+        events::emit_milestone_completed(
+            &env,
+            escrow_id,
+            milestone_id,
+            &completer,
+            storage::get_milestone_evidence(&env, escrow_id, milestone_id).len(),
+        );
+
+        Ok(())
+    }
+
+    /// Attach deliverable evidence (an IPFS CID) to a milestone for the funder
+    /// to review before it is confirmed. Recipient only; at most
+    /// `MAX_MILESTONE_EVIDENCE` entries per milestone.
+    pub fn attach_milestone_evidence(
+        env: Env,
+        recipient: Address,
+        escrow_id: u64,
+        milestone_id: u64,
+        evidence: String,
+    ) -> Result<(), VaultError> {
+        recipient.require_auth();
+        Self::ensure_initialized(&env)?;
+
+        let escrow = storage::get_escrow(&env, escrow_id)?;
+        if recipient != escrow.recipient {
+            return Err(VaultError::Unauthorized);
+        }
+        if escrow.status != EscrowStatus::Pending && escrow.status != EscrowStatus::Active {
+            return Err(VaultError::ProposalNotPending);
+        }
+        let milestone = escrow
+            .milestones
+            .iter()
+            .find(|m| m.id == milestone_id)
+            .ok_or(VaultError::ProposalNotFound)?;
+        if milestone.is_completed {
+            return Err(VaultError::AlreadyApproved);
+        }
+        if !is_valid_ipfs_cid(&evidence) {
+            return Err(VaultError::AttachmentHashInvalid);
+        }
+
+        let mut entries = storage::get_milestone_evidence(&env, escrow_id, milestone_id);
+        if entries.len() >= MAX_MILESTONE_EVIDENCE {
+            return Err(VaultError::TooManyAttachments);
+        }
+        if entries.contains(evidence.clone()) {
+            return Err(VaultError::AlreadyApproved);
+        }
+        entries.push_back(evidence.clone());
+        storage::set_milestone_evidence(&env, escrow_id, milestone_id, &entries);
+
+        events::emit_milestone_evidence_attached(&env, escrow_id, milestone_id, &evidence);
 
         Ok(())
     }
 
+    /// Evidence attached to a milestone, in attachment order.
+    pub fn get_milestone_evidence(env: Env, escrow_id: u64, milestone_id: u64) -> Vec<String> {
+        storage::get_milestone_evidence(&env, escrow_id, milestone_id)
+    }
+
     /// Release escrowed funds based on completed milestones
     pub fn release_escrow_funds(env: Env, escrow_id: u64) -> Result<i128, VaultError> {
+        Self::ensure_initialized(&env)?;
         let mut escrow = storage::get_escrow(&env, escrow_id)?;
         let current_ledger = env.ledger().sequence() as u64;
+        let time_based = escrow.milestone_mode == MilestoneMode::TimeBased;
+        if time_based {
+            Self::complete_elapsed_milestones(&mut escrow, current_ledger);
+        }
 
-        // Only release if all milestones complete or expired
-        let can_release = escrow.status == EscrowStatus::MilestonesComplete;
-        let is_expired = current_ledger >= escrow.expires_at;
+        // Only release if all milestones complete or expired; time-based
+        // escrows also release each unlocked tranche as it comes due. Every
+        // time-based milestone has unlocked by expiry, so those never refund.
+        let can_release = escrow.status == EscrowStatus::MilestonesComplete
+            || (time_based
+                && escrow.status == EscrowStatus::Active
+                && escrow.amount_to_release() > 0);
+        let is_expired = !time_based && current_ledger >= escrow.expires_at;
 
         if !can_release && !is_expired {
             return Err(VaultError::ConditionsNotMet);
@@ -4779,6 +10710,88 @@ impl VaultDAO {
         Ok(amount_to_release)
     }
 
+    /// Mark every milestone of a time-based escrow whose release ledger has
+    /// passed as complete. Disputed and finalized escrows are left untouched.
+    fn complete_elapsed_milestones(escrow: &mut Escrow, current_ledger: u64) {
+        if escrow.status != EscrowStatus::Pending && escrow.status != EscrowStatus::Active {
+            return;
+        }
+        let mut all_complete = true;
+        let mut any_complete = false;
+        for i in 0..escrow.milestones.len() {
+            let mut m = escrow.milestones.get(i).unwrap();
+            if !m.is_completed && m.release_ledger <= current_ledger {
+                m.is_completed = true;
+                m.completion_ledger = current_ledger;
+                escrow.milestones.set(i, m.clone());
+            }
+            all_complete &= m.is_completed;
+            any_complete |= m.is_completed;
+        }
+        if all_complete {
+            escrow.status = EscrowStatus::MilestonesComplete;
+        } else if any_complete {
+            escrow.status = EscrowStatus::Active;
+        }
+    }
+
+    /// Release every escrow that is due: milestone-complete escrows and
+    /// time-based escrows with an unlocked tranche pay the recipient and
+    /// expired ones refund the funder, exactly as `release_escrow_funds` would.
+    /// Disputed escrows are left to the arbitrator.
+    ///
+    /// Permissionless keeper entrypoint; handles at most 20 escrows per call and
+    /// returns the `(escrow_id, amount)` pairs released.
+    pub fn process_due_escrows(env: Env, max_count: u32) -> Vec<(u64, i128)> {
+        let budget = max_count.min(MAX_DUE_ESCROWS_PER_CALL);
+        let now = env.ledger().sequence() as u64;
+
+        let mut due = Vec::new(&env);
+        for id in storage::get_escrows_ready_for_release(&env).iter() {
+            if due.len() >= budget {
+                break;
+            }
+            due.push_back(id);
+        }
+        for (expires_at, id) in storage::get_escrow_expiry_index(&env).iter() {
+            if due.len() >= budget || expires_at > now {
+                break;
+            }
+            if !due.contains(id) {
+                due.push_back(id);
+            }
+        }
+        for id in storage::get_time_based_escrows(&env).iter() {
+            if due.len() >= budget {
+                break;
+            }
+            let tranche_due = storage::get_escrow(&env, id).is_ok_and(|escrow| {
+                escrow
+                    .milestones
+                    .iter()
+                    .any(|m| !m.is_completed && m.release_ledger <= now)
+            });
+            if tranche_due && !due.contains(id) {
+                due.push_back(id);
+            }
+        }
+
+        let mut released = Vec::new(&env);
+        for id in due.iter() {
+            let disputed = storage::get_escrow(&env, id)
+                .map_or(true, |escrow| escrow.status == EscrowStatus::Disputed);
+            if disputed {
+                continue;
+            }
+            if let Ok(amount) = Self::release_escrow_funds(env.clone(), id) {
+                released.push_back((id, amount));
+            }
+        }
+
+        events::emit_escrows_processed(&env, released.len(), now);
+        released
+    }
+
     /// File a dispute on an escrow agreement
     pub fn dispute_escrow(
         env: Env,
@@ -4787,6 +10800,7 @@ impl VaultDAO {
         reason: Symbol,
     ) -> Result<(), VaultError> {
         disputer.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let mut escrow = storage::get_escrow(&env, escrow_id)?;
 
@@ -4807,6 +10821,7 @@ impl VaultDAO {
         escrow.dispute_reason = reason.clone();
 
         storage::set_escrow(&env, &escrow);
+        storage::metrics_on_dispute_filed(&env);
 
         events::emit_escrow_disputed(&env, escrow_id, &disputer, &reason);
 
@@ -4821,6 +10836,7 @@ impl VaultDAO {
         release_to_recipient: bool,
     ) -> Result<(), VaultError> {
         arbitrator.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let mut escrow = storage::get_escrow(&env, escrow_id)?;
 
@@ -4853,6 +10869,8 @@ impl VaultDAO {
         escrow.finalized_at = env.ledger().sequence() as u64;
 
         storage::set_escrow(&env, &escrow);
+        // A dispute is upheld when the arbitrator blocks the release and refunds the funder
+        storage::metrics_on_dispute_resolved(&env, !release_to_recipient);
 
         events::emit_escrow_dispute_resolved(&env, escrow_id, &arbitrator, release_to_recipient);
 
@@ -4874,6 +10892,97 @@ impl VaultDAO {
         storage::get_recipient_escrows(&env, &recipient)
     }
 
+    /// Get hydrated escrows for a party, optionally filtered by status.
+    ///
+    /// `start` and `limit` page over the matching escrows (at most 50 per call).
+    pub fn get_escrows_detailed(
+        env: Env,
+        party: Address,
+        as_funder: bool,
+        status_filter: Option<EscrowStatus>,
+        start: u32,
+        limit: u32,
+    ) -> Vec<Escrow> {
+        let ids = if as_funder {
+            storage::get_funder_escrows(&env, &party)
+        } else {
+            storage::get_recipient_escrows(&env, &party)
+        };
+        let limit = limit.min(MAX_ESCROW_PAGE_SIZE);
+        let mut escrows = Vec::new(&env);
+        let mut matched: u32 = 0;
+        for id in ids.iter() {
+            if escrows.len() >= limit {
+                break;
+            }
+            let Ok(escrow) = storage::get_escrow(&env, id) else {
+                continue;
+            };
+            if status_filter.as_ref().is_some_and(|s| *s != escrow.status) {
+                continue;
+            }
+            if matched >= start {
+                escrows.push_back(escrow);
+            }
+            matched += 1;
+        }
+        escrows
+    }
+
+    /// Drop released and refunded escrows older than the retention window from
+    /// a party's funder and recipient indexes. Permissionless; returns the
+    /// number of index entries removed.
+    pub fn compact_escrow_index(env: Env, party: Address) -> u32 {
+        let now = env.ledger().sequence() as u64;
+        let retention = storage::get_escrow_index_retention(&env);
+        let keep = |ids: Vec<u64>| {
+            let mut kept = Vec::new(&env);
+            for id in ids.iter() {
+                let stale = storage::get_escrow(&env, id).is_ok_and(|escrow| {
+                    matches!(
+                        escrow.status,
+                        EscrowStatus::Released | EscrowStatus::Refunded
+                    ) && escrow.finalized_at.saturating_add(retention) <= now
+                });
+                if !stale {
+                    kept.push_back(id);
+                }
+            }
+            kept
+        };
+
+        let funded = storage::get_funder_escrows(&env, &party);
+        let received = storage::get_recipient_escrows(&env, &party);
+        let kept_funded = keep(funded.clone());
+        let kept_received = keep(received.clone());
+        let removed = (funded.len() - kept_funded.len()) + (received.len() - kept_received.len());
+        if kept_funded.len() < funded.len() {
+            storage::set_funder_escrows(&env, &party, &kept_funded);
+        }
+        if kept_received.len() < received.len() {
+            storage::set_recipient_escrows(&env, &party, &kept_received);
+        }
+        removed
+    }
+
+    /// Set how long finalized escrows stay in party indexes (Admin only)
+    pub fn set_escrow_index_retention(
+        env: Env,
+        admin: Address,
+        ledgers: u64,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        Self::ensure_initialized(&env)?;
+        let role = storage::get_role(&env, &admin);
+        if role != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+        storage::set_escrow_index_retention(&env, ledgers);
+        storage::extend_instance_ttl(&env);
+        events::emit_config_updated(&env, &admin);
+        Ok(())
+    }
+
     // ============================================================================
     // Batch Transactions
     // ============================================================================
@@ -4886,6 +10995,7 @@ impl VaultDAO {
         memo: Symbol,
     ) -> Result<u64, VaultError> {
         creator.require_auth();
+        Self::ensure_initialized(&env)?;
 
         // Validate batch is not empty
         if operations.is_empty() {
@@ -4991,7 +11101,7 @@ impl VaultDAO {
             failed_ops: if success {
                 0
             } else {
-                (batch.operations.len() as u32).saturating_sub(executed_count as u32)
+                batch.operations.len().saturating_sub(executed_count as u32)
             },
         };
 
@@ -5103,6 +11213,7 @@ impl VaultDAO {
         duration: u64,
     ) -> Result<(), VaultError> {
         owner.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let config = storage::get_time_weighted_config(&env);
 
@@ -5166,6 +11277,7 @@ impl VaultDAO {
         additional_duration: u64,
     ) -> Result<(), VaultError> {
         owner.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let config = storage::get_time_weighted_config(&env);
 
@@ -5245,6 +11357,11 @@ impl VaultDAO {
             return Err(VaultError::ThresholdTooHigh);
         }
 
+        // Every tier of the active strategy must stay reachable with the new signers
+        let mut config = storage::get_config(&env)?;
+        config.threshold = new_threshold;
+        Self::ensure_strategy_reachable(&env, &config, new_signers.len())?;
+
         let id = storage::increment_recovery_id(&env);
         let current_ledger = env.ledger().sequence() as u64;
 
@@ -5310,6 +11427,7 @@ impl VaultDAO {
     /// * `owner` - Address that owns the lock
     pub fn unlock_early(env: Env, owner: Address) -> Result<i128, VaultError> {
         owner.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let config = storage::get_time_weighted_config(&env);
 
@@ -5331,7 +11449,7 @@ impl VaultDAO {
         }
 
         // Calculate penalty
-        let penalty_amount = (lock.amount * config.early_unlock_penalty_bps as i128) / 10_000;
+        let penalty_amount = math::bps_of(lock.amount, config.early_unlock_penalty_bps);
         let return_amount = lock.amount - penalty_amount;
 
         // Transfer tokens back to owner (minus penalty)
@@ -5361,6 +11479,7 @@ impl VaultDAO {
     /// * `owner` - Address that owns the lock
     pub fn unlock_tokens(env: Env, owner: Address) -> Result<i128, VaultError> {
         owner.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let config = storage::get_time_weighted_config(&env);
 
@@ -5419,6 +11538,7 @@ impl VaultDAO {
         config: types::TimeWeightedConfig,
     ) -> Result<(), VaultError> {
         admin.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let role = storage::get_role(&env, &admin);
         if role != Role::Admin {
@@ -5457,6 +11577,15 @@ impl VaultDAO {
         let mut config = storage::get_config(&env)?;
         config.signers = proposal.new_signers.clone();
         config.threshold = proposal.new_threshold;
+        let mut kinds = storage::get_signer_kinds(&env);
+        for signer in kinds.keys().iter() {
+            if !config.signers.contains(&signer) {
+                kinds.remove(signer);
+            }
+        }
+        storage::set_signer_kinds(&env, &kinds);
+        // The strategy may have changed since the recovery was initiated
+        Self::ensure_strategy_reachable(&env, &config, config.signers.len())?;
         // Reset quorum and other fields to safe defaults if they were invalid for new signers
         if config.quorum > config.signers.len() {
             config.quorum = config.signers.len();
@@ -5476,6 +11605,7 @@ impl VaultDAO {
     /// Cancel a recovery proposal (admins only)
     pub fn cancel_recovery(env: Env, admin: Address, proposal_id: u64) -> Result<(), VaultError> {
         admin.require_auth();
+        Self::ensure_initialized(&env)?;
         if storage::get_role(&env, &admin) != Role::Admin {
             return Err(VaultError::InsufficientRole);
         }
@@ -5518,6 +11648,7 @@ impl VaultDAO {
         expires_at: Option<u64>,
     ) -> Result<(), VaultError> {
         granter.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let mut permissions = storage::get_permissions(&env, &target);
 
@@ -5550,6 +11681,7 @@ impl VaultDAO {
         permission: types::Permission,
     ) -> Result<(), VaultError> {
         revoker.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let permissions = storage::get_permissions(&env, &target);
         let mut found = false;
@@ -5582,6 +11714,7 @@ impl VaultDAO {
         expires_at: u64,
     ) -> Result<(), VaultError> {
         delegator.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let delegation = types::DelegatedPermission {
             permission,
@@ -5596,6 +11729,37 @@ impl VaultDAO {
 
         Ok(())
     }
+
+    /// Open metrics views are refused while `restrict_metrics` is set.
+    ///
+    /// Only metrics, fee and volume views are gated. Proposals, comments,
+    /// configuration and other governance state stay public: every signer and
+    /// indexer must be able to read what they are voting on.
+    fn ensure_metrics_public(env: &Env) -> Result<(), VaultError> {
+        if storage::get_config(env).is_ok_and(|config| config.restrict_metrics) {
+            return Err(VaultError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// Authenticate `caller` and require `ViewMetrics`. Under `restrict_metrics`
+    /// the permission Members inherit by default does not count; they need
+    /// an explicit grant or delegation.
+    fn ensure_can_view_metrics(env: &Env, caller: &Address) -> Result<(), VaultError> {
+        caller.require_auth();
+        let permission = types::Permission::ViewMetrics;
+        let restricted = storage::get_config(env).is_ok_and(|config| config.restrict_metrics);
+        let allowed = if restricted && storage::get_role(env, caller) == Role::Member {
+            Self::has_granted_permission(env, caller, &permission)
+        } else {
+            Self::check_permission(env, caller, &permission)
+        };
+        if !allowed {
+            return Err(VaultError::InsufficientRole);
+        }
+        Ok(())
+    }
+
     /// Check if an address has a specific permission
     pub fn has_permission(env: Env, addr: Address, permission: types::Permission) -> bool {
         Self::check_permission(&env, &addr, &permission)
@@ -5603,14 +11767,19 @@ impl VaultDAO {
 
     /// Internal permission check helper
     fn check_permission(env: &Env, addr: &Address, permission: &types::Permission) -> bool {
-        let current_ledger = env.ledger().sequence() as u64;
-
         // Check role-based permissions (inheritance)
         let role = storage::get_role(env, addr);
         if Self::role_has_permission(&role, permission) {
             return true;
         }
 
+        Self::has_granted_permission(env, addr, permission)
+    }
+
+    /// Permission held through an explicit grant or delegation, ignoring roles
+    fn has_granted_permission(env: &Env, addr: &Address, permission: &types::Permission) -> bool {
+        let current_ledger = env.ledger().sequence() as u64;
+
         // Check direct permission grants
         let permissions = storage::get_permissions(env, addr);
         for p in permissions.iter() {
@@ -5654,6 +11823,7 @@ impl VaultDAO {
                     | ManageRecurring
                     | ManageEscrow
                     | ManageSubscriptions
+                    | ManageFunding
             ),
             Role::Member => matches!(permission, ViewMetrics),
         }
@@ -5757,6 +11927,8 @@ impl VaultDAO {
             return Err(VaultError::SchedulingError);
         }
 
+        Self::ensure_executor_allowed(&env, proposal_id, &caller)?;
+
         // Verify execution time has been reached
         let execution_time = proposal.execution_time.ok_or(VaultError::SchedulingError)?;
         if current_ledger < execution_time {
@@ -5765,57 +11937,45 @@ impl VaultDAO {
 
         // Verify sufficient approvals
         let config = storage::get_config(&env)?;
-        if proposal.approvals.len() < config.threshold {
+        if Self::threshold_approvals(&env, &proposal) < config.threshold {
             return Err(VaultError::ProposalNotApproved);
         }
+        if Self::second_look_pending(&env, &config, &proposal) {
+            return Err(VaultError::ConditionsNotMet);
+        }
 
-        // Attempt to execute the proposal action
-        let vault_address = env.current_contract_address();
-        let token_client = soroban_sdk::token::Client::new(&env, &proposal.token);
-
-        match token_client.try_transfer(&vault_address, &proposal.recipient, &proposal.amount) {
-            Ok(_) => {
-                // Execution successful - transition to Executed
-                proposal.status = ProposalStatus::Executed;
-                storage::set_proposal(&env, &proposal);
-
-                // Return insurance if any
-                if proposal.insurance_amount > 0 {
-                    let _ = token_client.try_transfer(
-                        &vault_address,
-                        &proposal.proposer,
-                        &proposal.insurance_amount,
-                    );
-                    events::emit_insurance_returned(
-                        &env,
-                        proposal_id,
-                        &proposal.proposer,
-                        proposal.insurance_amount,
-                    );
-                }
+        // Same payout path as `execute_proposal`: lists, conditions, fees,
+        // hooks, tip, insurance and stake refunds
+        Self::try_execute_transfer(&env, &caller, &mut proposal, current_ledger)?;
 
-                events::emit_proposal_executed(
-                    &env,
-                    proposal_id,
-                    &caller,
-                    &proposal.recipient,
-                    &proposal.token,
-                    proposal.amount,
-                    current_ledger,
-                );
+        Self::transition_status(
+            &env,
+            &mut proposal,
+            ProposalStatus::Executed,
+            &caller,
+            "execute",
+        );
+        storage::set_proposal(&env, &proposal);
+        Self::record_execution_proof(&env, &config, &proposal, current_ledger);
+        storage::mark_recipient_paid(&env, &proposal.recipient);
 
-                // Update metrics
-                let execution_time_ledgers = current_ledger.saturating_sub(proposal.created_at);
-                storage::metrics_on_execution(&env, proposal.gas_used, execution_time_ledgers);
+        events::emit_proposal_executed(
+            &env,
+            proposal_id,
+            &caller,
+            &proposal.recipient,
+            &proposal.token,
+            proposal.amount,
+            current_ledger,
+            storage::get_executor_tip(&env, proposal_id),
+            storage::get_total_outflow(&env, &proposal.token),
+        );
+        Self::notify(&env, &proposal.proposer, "execution", |prefs| {
+            prefs.notify_on_execution
+        });
+        Self::record_execution_stats(&env, &proposal, current_ledger);
 
-                Ok(())
-            }
-            Err(_) => {
-                // Execution failed - maintain Scheduled status for retry
-                storage::set_proposal(&env, &proposal);
-                Err(VaultError::InsufficientBalance)
-            }
-        }
+        Ok(())
     }
 
     /// Cancel a scheduled proposal.
@@ -5851,7 +12011,13 @@ impl VaultDAO {
         }
 
         // Transition to Cancelled
-        proposal.status = ProposalStatus::Cancelled;
+        Self::transition_status(
+            &env,
+            &mut proposal,
+            ProposalStatus::Cancelled,
+            &caller,
+            "cancel",
+        );
         storage::set_proposal(&env, &proposal);
 
         let current_ledger = env.ledger().sequence() as u64;
@@ -5970,6 +12136,7 @@ impl VaultDAO {
         milestones: Vec<FundingMilestone>,
     ) -> Result<u64, VaultError> {
         creator.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let config =
             storage::get_funding_round_config(&env).ok_or(VaultError::FundingRoundError)?;
@@ -5978,11 +12145,11 @@ impl VaultDAO {
             return Err(VaultError::FundingRoundError);
         }
 
-        if milestones.len() < config.min_milestones as u32 {
+        if milestones.len() < config.min_milestones {
             return Err(VaultError::FundingRoundError);
         }
 
-        if milestones.len() > config.max_milestones as u32 {
+        if milestones.len() > config.max_milestones {
             return Err(VaultError::FundingRoundError);
         }
 
@@ -6060,6 +12227,7 @@ impl VaultDAO {
         milestone_index: u32,
     ) -> Result<(), VaultError> {
         submitter.require_auth();
+        Self::ensure_initialized(&env)?;
 
         let mut round = storage::get_funding_round(&env, round_id)?;
 
@@ -0,0 +1,42 @@
+//! VaultDAO - Percentage Math
+//!
+//! Shared basis-point and percentage helpers for fees, insurance, staking and
+//! escrow payouts, so every caller rounds the same way.
+
+/// Basis points in 100%
+pub const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Percent in 100%
+pub const PCT_DENOMINATOR: i128 = 100;
+
+/// `amount * bps / 10_000`, truncated toward zero.
+///
+/// Truncation means fees, deposits and penalties never exceed their exact
+/// value; the fractional unit stays with the payer. The product is split so
+/// that large amounts do not overflow before the division.
+pub fn bps_of(amount: i128, bps: u32) -> i128 {
+    scale(amount, bps, BPS_DENOMINATOR)
+}
+
+/// `amount * pct / 100`, truncated toward zero like [`bps_of`].
+pub fn pct_of(amount: i128, pct: u32) -> i128 {
+    scale(amount, pct, PCT_DENOMINATOR)
+}
+
+/// Split `amount` into `parts` equal shares.
+///
+/// Returns `(share, dust)` with `share * parts + dust == amount`; the dust is
+/// what truncation left over and has the same sign as `amount`. With zero
+/// parts everything is dust.
+pub fn split_with_remainder(amount: i128, parts: u32) -> (i128, i128) {
+    if parts == 0 {
+        return (0, amount);
+    }
+    let share = amount / parts as i128;
+    (share, amount - share * parts as i128)
+}
+
+fn scale(amount: i128, numerator: u32, denominator: i128) -> i128 {
+    let numerator = numerator as i128;
+    (amount / denominator) * numerator + (amount % denominator) * numerator / denominator
+}
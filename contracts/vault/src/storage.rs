@@ -18,16 +18,21 @@
 //!
 //! 5. **Batch Operations**: Multiple related updates are batched into single storage operations.
 
-use soroban_sdk::{contracttype, Address, Env, String, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, Env, Map, String, Symbol, Vec};
 
 use crate::errors::VaultError;
 use crate::types::{
-    AuditEntry, BatchExecutionResult, BatchTransaction, Comment, Config, DelegatedPermission,
-    DexConfig, Escrow, ExecutionFeeEstimate, ExecutionSnapshot, FeeStructure, FundingRound,
-    FundingRoundConfig, GasConfig, InsuranceConfig, ListMode, NotificationPreferences,
-    PermissionGrant, Proposal, ProposalAmendment, ProposalTemplate, RecoveryProposal, Reputation,
-    RetryState, Role, RoleAssignment, StakeRecord, StakingConfig, SwapProposal, SwapResult,
-    TimeWeightedConfig, TokenLock, VaultMetrics, VelocityConfig, VotingStrategy,
+    AddressBookEntry, ApprovalLimits, AuditEntry, AutoFreezeConfig, AutoTagRule,
+    BatchExecutionResult, BatchTransaction, BundleApprovalMode, Comment, CommentAuthorStats,
+    CommentQuota, Condition, ConditionLogic, Config, DailyFlow, DelegatedPermission, DexConfig,
+    Escrow, EscrowStatus, ExecutionFeeEstimate, ExecutionProof, ExecutionSnapshot, FeeStructure,
+    FundingRound, FundingRoundConfig, GasConfig, Incident, InsuranceConfig, ListMode,
+    MilestoneMode, NotificationDigest, NotificationPreferences, PermissionGrant, PoolKind,
+    Proposal, ProposalAmendment, ProposalBundle, ProposalStatus, ProposalTemplate,
+    ProposalTimestamps, RecordKind, RecoveryProposal, Reputation, RetryState, Role, RoleAssignment,
+    SignerInvite, SignerKind, StakeRecord, StakingConfig, StatusTransition, SwapProposal,
+    SwapResult, TimeWeightedConfig, TimelockBypassConfig, TokenLock, VaultMetrics, VelocityAction,
+    VelocityConfig, VotingStrategy,
 };
 
 /// Core storage key definitions (kept minimal to avoid size limits)
@@ -46,6 +51,8 @@ pub enum DataKey {
     Proposal(u64),
     /// Next proposal ID counter -> u64
     NextProposalId,
+    /// Live number of proposals in each status -> Map<ProposalStatus, u64>
+    StatusCounts,
     /// Priority queue index (u32 priority level) -> Vec<u64>
     PriorityQueue(u32),
     /// Daily spending tracker (day number) -> i128
@@ -56,6 +63,8 @@ pub enum DataKey {
     Recurring(u64),
     /// Next recurring payment ID counter -> u64
     NextRecurringId,
+    /// Active recurring payments ordered by due ledger -> Vec<(u64, u64)>
+    RecurringDueIndex,
     /// Proposer transfer timestamps for velocity checking (Address) -> Vec<u64>
     VelocityHistory(Address),
     /// Recipient list mode
@@ -64,12 +73,40 @@ pub enum DataKey {
     Whitelist(Address),
     /// Blacklist entry
     Blacklist(Address),
+    /// Expiry ledger for a temporary list entry (list, address) -> u64
+    ListExpiry(ListMode, Address),
+    /// Enumerable index of list members (list) -> Vec<Address>
+    ListIndex(ListMode),
+    /// Whether recipient lists are re-checked at execution time -> bool
+    ExecutionListCheck,
+    /// Vault or token addresses intentionally allowed as recipients -> Vec<Address>
+    SelfTransferAllowlist,
+    /// Recipients that never require proposal insurance -> Vec<Address>
+    InsuranceExemptions,
+    /// Approval justifications for a proposal -> Map<Address, Symbol>
+    ApprovalNotes(u64),
+    /// Whether approvals of timelocked-size proposals must carry a note -> bool
+    ApprovalNoteRequired,
+    /// Whether a proposer may approve (and count toward) their own proposal -> bool
+    ProposerCanApprove,
+    /// Recipient splits for a multi-recipient proposal -> Vec<(Address, i128)>
+    SplitTransfer(u64),
+    /// Keepers allowed to execute a proposal (empty = anyone) -> Vec<Address>
+    AllowedExecutors(u64),
+    /// Tokens the vault has proposed transfers in -> Vec<Address>
+    TrackedTokens,
     /// Comment by ID
     Comment(u64),
     /// Comments for a proposal
     ProposalComments(u64),
     /// Next comment ID counter
     NextCommentId,
+    /// Reaction counts for a comment -> Map<Symbol, u32>
+    CommentReactions(u64),
+    /// Addresses that reacted to a comment -> Vec<Address>
+    CommentReactors(u64),
+    /// Maximum comments allowed per proposal -> u32
+    CommentLimit,
     /// Audit entry by ID
     AuditEntry(u64),
     /// Next audit entry ID counter
@@ -88,6 +125,8 @@ pub enum DataKey {
     Stream(u64),
     /// Next stream payment ID counter -> u64
     NextStreamId,
+    /// Stream IDs funded by a sender -> Vec<u64>
+    SenderStreams(Address),
     /// Cancellation record by proposal ID
     CancellationRecord(u64),
     /// Cancellation history
@@ -106,6 +145,8 @@ pub enum DataKey {
 pub enum FeatureKey {
     /// Insurance configuration -> InsuranceConfig
     InsuranceConfig,
+    /// USD insurance threshold -> InsuranceUsdThreshold
+    InsuranceUsdThreshold,
     /// Per-user notification preferences -> NotificationPreferences
     NotificationPrefs(Address),
     /// DEX configuration -> DexConfig
@@ -199,10 +240,263 @@ pub enum FeatureKey {
     Permissions(Address),
     /// Delegated permissions (delegatee, delegator, permission as u32) -> DelegatedPermission
     DelegatedPermission(Address, Address, u32),
+    /// Keepers allowed to trigger a recurring payment (empty = anyone) -> Vec<Address>
+    RecurringExecutors(u64),
+    /// Conditions checked on each run of a recurring payment -> (Vec<Condition>, ConditionLogic)
+    RecurringConditions(u64),
+    /// Total pulled from a contributor per (contributor, token) -> i128
+    Contribution(Address, Address),
     // Stream payment storage (nested with StreamKey)
     // Stream(StreamKey), // Feature incomplete
 }
 
+/// Proposal lifecycle policy keys (split to avoid enum size limits)
+#[contracttype]
+#[derive(Clone)]
+pub enum ProposalKey {
+    /// Ledgers an approved proposal has to execute (0 = until expiry) -> u64
+    ExecutionWindow,
+    /// Per-proposal execution window override -> u64
+    ExecutionWindowOverride(u64),
+    /// Last ledger an approved proposal may execute at -> u64
+    ExecuteBy(u64),
+    /// Most recent proposal IDs carrying a tag -> Vec<u64>
+    TagIndex(Symbol),
+    /// Upper bound on a proposal's executor tip -> i128
+    MaxExecutorTip,
+    /// Tip a proposal pays its executor -> i128
+    ExecutorTip(u64),
+    /// Signer who gave a large proposal's second confirmation -> Address
+    SecondLook(u64),
+    /// Open (non-terminal) proposals per proposer -> u32
+    PendingCount(Address),
+    /// Open proposals awaiting an expiry warning, ordered by expiry -> Vec<(u64, u64)>
+    ExpiryIndex,
+    /// Ledgers before expiry at which a proposal_expiring warning is emitted -> u64
+    ExpiryWarningWindow,
+    /// Lifecycle stage ledgers per proposal -> ProposalTimestamps
+    Timestamps(u64),
+    /// IDs of proposals currently in Approved status -> Vec<u64>
+    ApprovedIndex,
+    /// Dependencies of a proposal not yet executed -> u32
+    UnmetDependencies(u64),
+    /// Proposals waiting on this proposal's execution -> Vec<u64>
+    Dependents(u64),
+    /// Every proposal reachable through `depends_on`, fixed at creation -> Vec<u64>
+    TransitiveDeps(u64),
+    /// Longest dependency chain below a proposal -> u32
+    DependencyDepth(u64),
+    /// Memo taxonomy accepted while the memo whitelist is enforced -> Vec<Symbol>
+    AllowedMemos,
+    /// Approval evidence frozen when a proposal executes -> ExecutionProof
+    ExecutionProof(u64),
+    /// Largest proposal amount each capped signer may approve -> Map<Address, i128>
+    ApprovalCaps,
+    /// Signer approval caps frozen at proposal creation -> Map<Address, i128>
+    ApprovalCapSnapshot(u64),
+    /// Pending proposals with a voting deadline, ordered by deadline -> Vec<(u64, u64)>
+    DeadlineIndex,
+    /// Bounded log of status changes per proposal -> Vec<StatusTransition>
+    StatusHistory(u64),
+    /// Hidden recipient and amount of an unrevealed committed transfer -> BytesN<32>
+    Commitment(u64),
+    /// Token a batch proposal's insurance was locked in, when not its own -> Address
+    InsuranceToken(u64),
+    /// Threshold and quorum frozen at proposal creation -> (u32, u32)
+    VoteSnapshot(u64),
+    /// Signer stages that must approve in order -> Vec<Vec<Address>>
+    ApprovalOrder(u64),
+    /// Proposer's limits frozen at approval -> ApprovalLimits
+    ApprovalLimits(u64),
+    /// Rules tagging proposals at creation -> Vec<AutoTagRule>
+    AutoTagRules,
+}
+
+/// Treasury reporting keys (split to avoid enum size limits)
+#[contracttype]
+#[derive(Clone)]
+pub enum ReportKey {
+    /// Days between treasury checkpoints -> u64
+    CheckpointInterval,
+    /// Day number of the last emitted checkpoint -> u64
+    LastCheckpoint,
+    /// Outflow per token since the last checkpoint -> Map<Address, i128>
+    PeriodOutflow,
+    /// Proposals that reserved spending on a day, plus overflow -> (Vec<u64>, u32)
+    DailyProposals(u64),
+    /// Everything the vault has ever sent out of a token -> i128
+    TotalOutflow(Address),
+    /// Everything the vault has ever pulled in of a token -> i128
+    TotalInflow(Address),
+    /// Token movements on one day, by (token, day number) -> DailyFlow
+    DailyFlow(Address, u64),
+}
+
+/// Slashed-fund pool keys (split to avoid enum size limits)
+#[contracttype]
+#[derive(Clone)]
+pub enum PoolKey {
+    /// Whether admins may withdraw pools directly, bypassing proposals -> bool
+    DirectWithdrawal,
+    /// Pool a withdrawal proposal draws from -> PoolKind
+    Withdrawal(u64),
+    /// Insurance held for open proposals, per token -> i128
+    LockedInsurance(Address),
+    /// Stakes held for open proposals, per token -> i128
+    LockedStakes(Address),
+    /// Where slashed insurance goes -> SlashDestination
+    SlashDestination,
+}
+
+/// Spend category budget keys (split to avoid enum size limits)
+#[contracttype]
+#[derive(Clone)]
+pub enum BudgetKey {
+    /// Monthly limit per registered category -> Map<Symbol, i128>
+    CategoryBudgets,
+    /// Amount reserved against a category in a month -> i128
+    CategorySpent(Symbol, u64),
+    /// Category and month a proposal reserved against -> (Symbol, u64)
+    ProposalCategory(u64),
+}
+
+/// Execution safety keys (split to avoid enum size limits)
+#[contracttype]
+#[derive(Clone)]
+pub enum SafetyKey {
+    /// Execution frozen until an admin unpauses -> bool
+    Paused,
+    /// Anomaly breaker settings -> AutoFreezeConfig
+    AutoFreezeConfig,
+    /// Recent execution failures as (ledger, error code), oldest first -> Vec<(u64, u32)>
+    RecentFailures,
+    /// Critical-priority timelock escape hatch -> TimelockBypassConfig
+    TimelockBypass,
+    /// Token address already answered the `decimals()` probe -> bool
+    VerifiedToken(Address),
+    /// Multiple of `timelock_threshold` from which a post-timelock second
+    /// confirmation is required (0 = disabled) -> u32
+    SecondLookMultiple,
+}
+
+/// Proposal bundle keys (split to avoid enum size limits)
+#[contracttype]
+#[derive(Clone)]
+pub enum BundleKey {
+    /// Next bundle ID -> u64
+    NextId,
+    /// Bundle record -> ProposalBundle
+    Bundle(u64),
+    /// Bundle a proposal belongs to -> u64
+    Membership(u64),
+    /// Handling of unapprovable members in `approve_bundle` -> BundleApprovalMode
+    ApprovalMode,
+}
+
+/// Comment rate limiting keys (split to avoid enum size limits)
+#[contracttype]
+#[derive(Clone)]
+pub enum CommentKey {
+    /// Per-author limits -> CommentQuota
+    Quota,
+    /// Comment activity per (proposal, author) -> CommentAuthorStats
+    AuthorStats(u64, Address),
+}
+
+/// Signer metadata keys (split to avoid enum size limits)
+#[contracttype]
+#[derive(Clone)]
+pub enum SignerKey {
+    /// Non-default signer kinds -> Map<Address, SignerKind>
+    Kinds,
+    /// Ledgers an invite stays acceptable -> u64
+    InviteWindow,
+    /// Pending invite for an address -> SignerInvite
+    Invite(Address),
+}
+
+/// Payment incident keys (split to avoid enum size limits)
+#[contracttype]
+#[derive(Clone)]
+pub enum IncidentKey {
+    /// Next incident ID -> u64
+    NextId,
+    /// Incident record -> Incident
+    Incident(u64),
+    /// IDs of unresolved incidents, oldest first -> Vec<u64>
+    Open,
+}
+
+/// Vault-level integration keys (split to avoid enum size limits)
+#[contracttype]
+#[derive(Clone)]
+pub enum VaultInfoKey {
+    /// Integrator-facing metadata such as name, logo CID or forum URL -> Map<Symbol, String>
+    Metadata,
+}
+
+/// Address book keys (split to avoid enum size limits)
+#[contracttype]
+#[derive(Clone)]
+pub enum AddressBookKey {
+    /// Labeled recipients in insertion order -> Vec<AddressBookEntry>
+    Entries,
+    /// Whether book entries count as whitelisted in Whitelist mode -> bool
+    Whitelisted,
+}
+
+/// Notification bookkeeping keys (split to avoid enum size limits)
+#[contracttype]
+#[derive(Clone)]
+pub enum NotificationKey {
+    /// Unread notification digest per address -> NotificationDigest
+    Digest(Address),
+    /// Most recent comment IDs mentioning an address -> Vec<u64>
+    Mentions(Address),
+}
+
+/// Escrow bookkeeping keys (split to avoid enum size limits)
+#[contracttype]
+#[derive(Clone)]
+pub enum EscrowKey {
+    /// Ledgers a finalized escrow stays in party indexes before compaction -> u64
+    IndexRetention,
+    /// Unfinalized escrows ordered by expiry -> Vec<(u64, u64)> of (expires_at, id)
+    ExpiryIndex,
+    /// Escrows whose milestones are all complete, awaiting release -> Vec<u64>
+    ReadyForRelease,
+    /// Deliverable CIDs attached to a milestone by the recipient -> Vec<String>
+    MilestoneEvidence(u64, u64),
+    /// Unfinalized time-based escrows, oldest first -> Vec<u64>
+    TimeBased,
+}
+
+/// Subscription keys (split to avoid enum size limits)
+#[contracttype]
+#[derive(Clone)]
+pub enum SubscriptionKey {
+    /// Next subscription ID -> u64
+    NextId,
+    /// Subscription record -> Subscription
+    Subscription(u64),
+    /// Subscription IDs per service provider, never pruned -> Vec<u64>
+    ProviderSubscriptions(Address),
+    /// Lifetime renewal revenue per (provider, token) -> i128
+    ProviderRevenue(Address, Address),
+    /// Keeper-renewable subscriptions as (next_renewal_ledger, id), earliest first -> Vec<(u64, u64)>
+    DueIndex,
+}
+
+/// Proposal risk signal keys (split to avoid enum size limits)
+#[contracttype]
+#[derive(Clone)]
+pub enum RiskKey {
+    /// Recipient has received an executed payment -> bool
+    PaidRecipient(Address),
+    /// Ledger an address was last added to the whitelist -> u64
+    WhitelistedAt(Address),
+}
+
 /// TTL constants (in ledgers, ~5 seconds each)
 pub const DAY_IN_LEDGERS: u32 = 17_280; // ~24 hours
 pub const PROPOSAL_TTL: u32 = DAY_IN_LEDGERS * 7; // 7 days
@@ -334,219 +628,1448 @@ pub fn get_proposal(env: &Env, id: u64) -> Result<Proposal, VaultError> {
     Ok(proposal)
 }
 
+/// Whether `id` was issued for `kind` but its entry is gone (archived or
+/// TTL-expired). Getters report such records as `ProposalNotFound` like IDs
+/// that were never issued; this tells the two apart.
+pub fn is_record_archived(env: &Env, kind: &RecordKind, id: u64) -> bool {
+    let (next_id, present) = match kind {
+        RecordKind::Proposal => (
+            get_next_proposal_id(env),
+            env.storage().persistent().has(&DataKey::Proposal(id)),
+        ),
+        RecordKind::Comment => (
+            get_next_comment_id(env),
+            env.storage().persistent().has(&DataKey::Comment(id)),
+        ),
+        RecordKind::Stream => (
+            get_next_stream_id(env),
+            env.storage().persistent().has(&DataKey::Stream(id)),
+        ),
+        RecordKind::Escrow => (
+            get_next_escrow_id(env),
+            env.storage().persistent().has(&FeatureKey::Escrow(id)),
+        ),
+    };
+    id > 0 && id < next_id && !present
+}
+
 pub fn proposal_exists(env: &Env, id: u64) -> bool {
     env.storage().persistent().has(&DataKey::Proposal(id))
 }
 
+/// Persist a proposal record.
+///
+/// Attachments live solely under `DataKey::Attachments`; the `attachments` field is
+/// a read-side projection filled in by `get_proposal` and is never written here, so
+/// the two cannot drift.
 pub fn set_proposal(env: &Env, proposal: &Proposal) {
     let key = DataKey::Proposal(proposal.id);
-    env.storage().persistent().set(&key, proposal);
+    if proposal.attachments.is_empty() {
+        env.storage().persistent().set(&key, proposal);
+    } else {
+        let mut stored = proposal.clone();
+        stored.attachments = Vec::new(env);
+        env.storage().persistent().set(&key, &stored);
+    }
     env.storage()
         .persistent()
         .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+    // The vote snapshot has to live exactly as long as the proposal it governs
+    let snapshot_key = ProposalKey::VoteSnapshot(proposal.id);
+    if env.storage().persistent().has(&snapshot_key) {
+        env.storage()
+            .persistent()
+            .extend_ttl(&snapshot_key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+    }
 }
 
-pub fn get_next_proposal_id(env: &Env) -> u64 {
+/// IDs of proposals currently in Approved status, in approval order.
+pub fn get_approved_index(env: &Env) -> Vec<u64> {
     env.storage()
-        .instance()
-        .get(&DataKey::NextProposalId)
-        .unwrap_or(1)
+        .persistent()
+        .get(&ProposalKey::ApprovedIndex)
+        .unwrap_or_else(|| Vec::new(env))
 }
 
-pub fn increment_proposal_id(env: &Env) -> u64 {
-    let id = get_next_proposal_id(env);
+fn set_approved_index(env: &Env, index: &Vec<u64>) {
+    let key = ProposalKey::ApprovedIndex;
+    env.storage().persistent().set(&key, index);
     env.storage()
-        .instance()
-        .set(&DataKey::NextProposalId, &(id + 1));
-    id
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, PERSISTENT_TTL);
 }
 
-/// Return a page of existing proposal IDs in ascending creation order.
-///
-/// IDs are assigned sequentially starting at 1. This function scans the
-/// range `[offset+1 .. next_id)` and collects up to `limit` IDs that have
-/// a stored proposal entry, skipping any gaps left by deleted proposals.
-///
-/// # Arguments
-/// * `offset` - Number of proposals to skip (0-based).
-/// * `limit`  - Maximum number of IDs to return. Capped at 100 internally.
-///
-/// # Returns
-/// A vector of proposal IDs in ascending order, paginated by offset/limit.
-pub fn get_proposal_ids_paginated(env: &Env, offset: u64, limit: u64) -> Vec<u64> {
-    let cap: u64 = if limit > 100 { 100 } else { limit };
-    let next_id = get_next_proposal_id(env);
-    let mut ids: Vec<u64> = Vec::new(env);
-    let mut skipped: u64 = 0;
-
-    for id in 1..next_id {
-        if !env.storage().persistent().has(&DataKey::Proposal(id)) {
-            continue;
-        }
-        if skipped < offset {
-            skipped += 1;
-            continue;
-        }
-        ids.push_back(id);
-        if ids.len() as u64 >= cap {
-            break;
-        }
+pub fn add_approved(env: &Env, proposal_id: u64) {
+    let mut index = get_approved_index(env);
+    if !index.contains(proposal_id) {
+        index.push_back(proposal_id);
+        set_approved_index(env, &index);
     }
-    ids
 }
 
-// ============================================================================
-// Priority Queue
-// ============================================================================
+pub fn remove_approved(env: &Env, proposal_id: u64) {
+    let mut index = get_approved_index(env);
+    if let Some(pos) = index.first_index_of(proposal_id) {
+        index.remove(pos);
+        set_approved_index(env, &index);
+    }
+}
 
-pub fn get_priority_queue(env: &Env, priority: u32) -> Vec<u64> {
+/// Number of a proposal's dependencies that have not executed yet.
+pub fn get_unmet_dependencies(env: &Env, proposal_id: u64) -> u32 {
     env.storage()
         .persistent()
-        .get(&DataKey::PriorityQueue(priority))
-        .unwrap_or_else(|| Vec::new(env))
+        .get(&ProposalKey::UnmetDependencies(proposal_id))
+        .unwrap_or(0)
 }
 
-pub fn add_to_priority_queue(env: &Env, priority: u32, proposal_id: u64) {
-    let mut queue = get_priority_queue(env, priority);
-    queue.push_back(proposal_id);
-    let key = DataKey::PriorityQueue(priority);
-    env.storage().persistent().set(&key, &queue);
+fn set_unmet_dependencies(env: &Env, proposal_id: u64, count: u32) {
+    let key = ProposalKey::UnmetDependencies(proposal_id);
+    if count == 0 {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, &count);
     env.storage()
         .persistent()
-        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
 }
 
-pub fn remove_from_priority_queue(env: &Env, priority: u32, proposal_id: u64) {
-    let queue = get_priority_queue(env, priority);
-    let mut new_queue: Vec<u64> = Vec::new(env);
-    for i in 0..queue.len() {
-        let id = queue.get(i).unwrap();
-        if id != proposal_id {
-            new_queue.push_back(id);
+/// Count a new proposal's unexecuted dependencies and register it as a
+/// dependent of each, so execution can release it without a graph walk.
+pub fn register_dependencies(env: &Env, proposal: &Proposal) {
+    let mut unmet = 0;
+    for dependency_id in proposal.depends_on.iter() {
+        let executed = get_proposal(env, dependency_id)
+            .is_ok_and(|dependency| dependency.status == ProposalStatus::Executed);
+        if executed {
+            continue;
         }
+        unmet += 1;
+        let key = ProposalKey::Dependents(dependency_id);
+        let mut dependents: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(env));
+        dependents.push_back(proposal.id);
+        env.storage().persistent().set(&key, &dependents);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
     }
-    let key = DataKey::PriorityQueue(priority);
-    env.storage().persistent().set(&key, &new_queue);
+    set_unmet_dependencies(env, proposal.id, unmet);
+}
+
+pub fn release_dependents(env: &Env, proposal_id: u64) {
+    let key = ProposalKey::Dependents(proposal_id);
+    let dependents: Option<Vec<u64>> = env.storage().persistent().get(&key);
+    if let Some(dependents) = dependents {
+        for dependent_id in dependents.iter() {
+            let unmet = get_unmet_dependencies(env, dependent_id);
+            set_unmet_dependencies(env, dependent_id, unmet.saturating_sub(1));
+        }
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Per-signer approval caps (signers without an entry are uncapped).
+pub fn get_approval_caps(env: &Env) -> Map<Address, i128> {
     env.storage()
-        .persistent()
-        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+        .instance()
+        .get(&ProposalKey::ApprovalCaps)
+        .unwrap_or_else(|| Map::new(env))
 }
 
-// ============================================================================
-// Daily Spending
-// ============================================================================
+pub fn set_approval_caps(env: &Env, caps: &Map<Address, i128>) {
+    env.storage()
+        .instance()
+        .set(&ProposalKey::ApprovalCaps, caps);
+}
 
-/// Get current day number from ledger timestamp
-pub fn get_day_number(env: &Env) -> u64 {
-    env.ledger().timestamp() / 86400
+/// Signers registered with a non-default kind (absent signers are EOAs).
+pub fn get_signer_kinds(env: &Env) -> Map<Address, SignerKind> {
+    env.storage()
+        .instance()
+        .get(&SignerKey::Kinds)
+        .unwrap_or_else(|| Map::new(env))
 }
 
-pub fn get_daily_spent(env: &Env, day: u64) -> i128 {
+pub fn set_signer_kinds(env: &Env, kinds: &Map<Address, SignerKind>) {
+    env.storage().instance().set(&SignerKey::Kinds, kinds);
+}
+
+pub fn get_signer_kind(env: &Env, signer: &Address) -> SignerKind {
+    get_signer_kinds(env)
+        .get(signer.clone())
+        .unwrap_or(SignerKind::Eoa)
+}
+
+pub fn is_contract_signer(env: &Env, signer: &Address) -> bool {
+    get_signer_kind(env, signer) == SignerKind::Contract
+}
+
+/// Default invite lifetime: 7 days
+pub const DEFAULT_SIGNER_INVITE_WINDOW: u64 = DAY_IN_LEDGERS as u64 * 7;
+
+pub fn get_signer_invite_window(env: &Env) -> u64 {
     env.storage()
-        .temporary()
-        .get(&DataKey::DailySpent(day))
-        .unwrap_or(0)
+        .instance()
+        .get(&SignerKey::InviteWindow)
+        .unwrap_or(DEFAULT_SIGNER_INVITE_WINDOW)
 }
 
-pub fn add_daily_spent(env: &Env, day: u64, amount: i128) {
-    let current = get_daily_spent(env, day);
-    let key = DataKey::DailySpent(day);
-    env.storage().temporary().set(&key, &(current + amount));
+pub fn set_signer_invite_window(env: &Env, ledgers: u64) {
     env.storage()
-        .temporary()
-        .extend_ttl(&key, DAY_IN_LEDGERS * 2, DAY_IN_LEDGERS * 2);
+        .instance()
+        .set(&SignerKey::InviteWindow, &ledgers);
 }
 
-// ============================================================================
-// Weekly Spending
-// ============================================================================
+pub fn get_signer_invite(env: &Env, invitee: &Address) -> Option<SignerInvite> {
+    env.storage()
+        .persistent()
+        .get(&SignerKey::Invite(invitee.clone()))
+}
 
-/// Get current week number (epoch / 7 days)
-pub fn get_week_number(env: &Env) -> u64 {
-    env.ledger().timestamp() / 604800
+pub fn set_signer_invite(env: &Env, invite: &SignerInvite) {
+    let key = SignerKey::Invite(invite.invitee.clone());
+    env.storage().persistent().set(&key, invite);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
 }
 
-pub fn get_weekly_spent(env: &Env, week: u64) -> i128 {
+pub fn remove_signer_invite(env: &Env, invitee: &Address) {
     env.storage()
-        .temporary()
-        .get(&DataKey::WeeklySpent(week))
+        .persistent()
+        .remove(&SignerKey::Invite(invitee.clone()));
+}
+
+/// Approval cap that applies to `signer` on a proposal, as frozen at its
+/// creation (0 = uncapped).
+pub fn get_proposal_approval_cap(env: &Env, proposal_id: u64, signer: &Address) -> i128 {
+    let snapshot: Option<Map<Address, i128>> = env
+        .storage()
+        .persistent()
+        .get(&ProposalKey::ApprovalCapSnapshot(proposal_id));
+    snapshot
+        .and_then(|caps| caps.get(signer.clone()))
         .unwrap_or(0)
 }
 
-pub fn add_weekly_spent(env: &Env, week: u64, amount: i128) {
-    let current = get_weekly_spent(env, week);
-    let key = DataKey::WeeklySpent(week);
-    env.storage().temporary().set(&key, &(current + amount));
+pub fn snapshot_approval_caps(env: &Env, proposal_id: u64) {
+    let caps = get_approval_caps(env);
+    if caps.is_empty() {
+        return;
+    }
+    let key = ProposalKey::ApprovalCapSnapshot(proposal_id);
+    env.storage().persistent().set(&key, &caps);
     env.storage()
-        .temporary()
-        .extend_ttl(&key, DAY_IN_LEDGERS * 14, DAY_IN_LEDGERS * 14);
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
 }
 
-// ============================================================================
-// Recurring Payments
-// ============================================================================
+pub fn get_execution_proof(env: &Env, proposal_id: u64) -> Option<ExecutionProof> {
+    env.storage()
+        .persistent()
+        .get(&ProposalKey::ExecutionProof(proposal_id))
+}
 
-pub fn get_next_recurring_id(env: &Env) -> u64 {
+pub fn set_execution_proof(env: &Env, proof: &ExecutionProof) {
+    let key = ProposalKey::ExecutionProof(proof.proposal_id);
+    env.storage().persistent().set(&key, proof);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+/// Memos accepted while `Config::enforce_memo_whitelist` is set.
+pub fn get_allowed_memos(env: &Env) -> Vec<Symbol> {
     env.storage()
         .instance()
-        .get(&DataKey::NextRecurringId)
-        .unwrap_or(1)
+        .get(&ProposalKey::AllowedMemos)
+        .unwrap_or_else(|| Vec::new(env))
 }
 
-pub fn increment_recurring_id(env: &Env) -> u64 {
-    let id = get_next_recurring_id(env);
+pub fn set_allowed_memos(env: &Env, memos: &Vec<Symbol>) {
     env.storage()
         .instance()
-        .set(&DataKey::NextRecurringId, &(id + 1));
-    id
+        .set(&ProposalKey::AllowedMemos, memos);
 }
 
-pub fn set_recurring_payment(env: &Env, payment: &crate::types::RecurringPayment) {
-    let key = DataKey::Recurring(payment.id);
-    env.storage().persistent().set(&key, payment);
+/// Flattened set of a proposal's direct and indirect dependencies.
+pub fn get_transitive_dependencies(env: &Env, proposal_id: u64) -> Vec<u64> {
     env.storage()
         .persistent()
-        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+        .get(&ProposalKey::TransitiveDeps(proposal_id))
+        .unwrap_or_else(|| Vec::new(env))
 }
 
-pub fn get_recurring_payment(
-    env: &Env,
-    id: u64,
-) -> Result<crate::types::RecurringPayment, VaultError> {
+/// Length of the longest dependency chain below a proposal (0 = no dependencies).
+pub fn get_dependency_depth(env: &Env, proposal_id: u64) -> u32 {
     env.storage()
         .persistent()
-        .get(&DataKey::Recurring(id))
-        .ok_or(VaultError::ProposalNotFound)
+        .get(&ProposalKey::DependencyDepth(proposal_id))
+        .unwrap_or(0)
 }
 
-// ============================================================================
-// Recurring Payments - Listing
-// ============================================================================
+/// Record a new proposal's dependency closure. `depends_on` never changes
+/// after creation, so this is written once.
+pub fn set_dependency_closure(env: &Env, proposal_id: u64, transitive: &Vec<u64>, depth: u32) {
+    let deps_key = ProposalKey::TransitiveDeps(proposal_id);
+    env.storage().persistent().set(&deps_key, transitive);
+    env.storage()
+        .persistent()
+        .extend_ttl(&deps_key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
 
-/// Return a page of existing recurring payment IDs in ascending creation order.
-///
-/// IDs are assigned sequentially starting at 1. This function scans the
-/// range `[offset+1 .. next_id)` and collects up to `limit` IDs that have
-/// a stored recurring payment entry.
-///
-/// # Arguments
-/// * `offset` - Number of payments to skip (0-based).
-/// * `limit`  - Maximum number of IDs to return. Capped at 100 internally.
-///
-/// # Returns
-/// A vector of recurring payment IDs in ascending order, paginated by offset/limit.
-pub fn get_recurring_payment_ids_paginated(env: &Env, offset: u64, limit: u64) -> Vec<u64> {
-    let cap: u64 = if limit > 100 { 100 } else { limit };
-    let next_id = get_next_recurring_id(env);
-    let mut ids: Vec<u64> = Vec::new(env);
-    let mut skipped: u64 = 0;
+    let depth_key = ProposalKey::DependencyDepth(proposal_id);
+    env.storage().persistent().set(&depth_key, &depth);
+    env.storage()
+        .persistent()
+        .extend_ttl(&depth_key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
 
-    for id in 1..next_id {
-        if !env.storage().persistent().has(&DataKey::Recurring(id)) {
-            continue;
-        }
-        if skipped < offset {
-            skipped += 1;
+/// Lifecycle timestamps for a proposal. Proposals created before these were
+/// tracked report only `created_at`.
+pub fn get_proposal_timestamps(env: &Env, proposal: &Proposal) -> ProposalTimestamps {
+    env.storage()
+        .persistent()
+        .get(&ProposalKey::Timestamps(proposal.id))
+        .unwrap_or(ProposalTimestamps {
+            created_at: proposal.created_at,
+            ..ProposalTimestamps::default()
+        })
+}
+
+/// Maximum transitions kept in a proposal's status history
+pub const MAX_STATUS_HISTORY: u32 = 20;
+
+/// Status transitions recorded for a proposal, oldest first.
+pub fn get_status_history(env: &Env, proposal_id: u64) -> Vec<StatusTransition> {
+    env.storage()
+        .persistent()
+        .get(&ProposalKey::StatusHistory(proposal_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Append a transition, dropping the oldest once `MAX_STATUS_HISTORY` is reached.
+pub fn push_status_transition(env: &Env, proposal_id: u64, transition: &StatusTransition) {
+    let key = ProposalKey::StatusHistory(proposal_id);
+    let mut history = get_status_history(env, proposal_id);
+    if history.len() >= MAX_STATUS_HISTORY {
+        history.pop_front();
+    }
+    history.push_back(transition.clone());
+    env.storage().persistent().set(&key, &history);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
+
+pub fn get_commitment(env: &Env, proposal_id: u64) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&ProposalKey::Commitment(proposal_id))
+}
+
+pub fn set_commitment(env: &Env, proposal_id: u64, commitment: &BytesN<32>) {
+    let key = ProposalKey::Commitment(proposal_id);
+    env.storage().persistent().set(&key, commitment);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
+
+pub fn remove_commitment(env: &Env, proposal_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&ProposalKey::Commitment(proposal_id));
+}
+
+pub fn get_auto_tag_rules(env: &Env) -> Vec<AutoTagRule> {
+    env.storage()
+        .instance()
+        .get(&ProposalKey::AutoTagRules)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_auto_tag_rules(env: &Env, rules: &Vec<AutoTagRule>) {
+    env.storage()
+        .instance()
+        .set(&ProposalKey::AutoTagRules, rules);
+}
+
+/// Approval stages of a proposal, earliest first (empty = no ordering).
+pub fn get_approval_order(env: &Env, proposal_id: u64) -> Vec<Vec<Address>> {
+    env.storage()
+        .persistent()
+        .get(&ProposalKey::ApprovalOrder(proposal_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_approval_order(env: &Env, proposal_id: u64, stages: &Vec<Vec<Address>>) {
+    let key = ProposalKey::ApprovalOrder(proposal_id);
+    env.storage().persistent().set(&key, stages);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
+
+pub fn get_approval_limits(env: &Env, proposal_id: u64) -> Option<ApprovalLimits> {
+    env.storage()
+        .persistent()
+        .get(&ProposalKey::ApprovalLimits(proposal_id))
+}
+
+pub fn set_approval_limits(env: &Env, proposal_id: u64, limits: &ApprovalLimits) {
+    let key = ProposalKey::ApprovalLimits(proposal_id);
+    env.storage().persistent().set(&key, limits);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
+
+/// Threshold and quorum frozen when the proposal was created, as
+/// `(threshold, quorum)`; `None` for proposals that predate the snapshot.
+pub fn get_vote_snapshot(env: &Env, proposal_id: u64) -> Option<(u32, u32)> {
+    env.storage()
+        .persistent()
+        .get(&ProposalKey::VoteSnapshot(proposal_id))
+}
+
+pub fn set_vote_snapshot(env: &Env, proposal_id: u64, threshold: u32, quorum: u32) {
+    let key = ProposalKey::VoteSnapshot(proposal_id);
+    env.storage().persistent().set(&key, &(threshold, quorum));
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
+
+/// Token a proposal's insurance is held in; its own token unless a batch
+/// locked the insurance in a different one.
+pub fn get_insurance_token(env: &Env, proposal: &Proposal) -> Address {
+    env.storage()
+        .persistent()
+        .get(&ProposalKey::InsuranceToken(proposal.id))
+        .unwrap_or_else(|| proposal.token.clone())
+}
+
+pub fn set_insurance_token(env: &Env, proposal_id: u64, token_addr: &Address) {
+    let key = ProposalKey::InsuranceToken(proposal_id);
+    env.storage().persistent().set(&key, token_addr);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
+
+/// Stamp the ledger at which a proposal entered its new status, and feed the
+/// approval and execution durations into the vault metrics.
+pub fn record_lifecycle_timestamp(env: &Env, proposal: &Proposal, created: bool) {
+    let now = env.ledger().sequence() as u64;
+    let mut stamps = if created {
+        ProposalTimestamps {
+            created_at: proposal.created_at,
+            ..ProposalTimestamps::default()
+        }
+    } else {
+        get_proposal_timestamps(env, proposal)
+    };
+    match proposal.status {
+        ProposalStatus::Approved if stamps.approved_at == 0 => {
+            stamps.approved_at = now;
+            let mut metrics = get_metrics(env);
+            metrics.total_approval_time_ledgers = metrics
+                .total_approval_time_ledgers
+                .saturating_add(now.saturating_sub(stamps.created_at));
+            metrics.approval_time_samples = metrics.approval_time_samples.saturating_add(1);
+            set_metrics(env, &metrics);
+        }
+        ProposalStatus::Executed => {
+            stamps.executed_at = now;
+            if stamps.approved_at > 0 {
+                let mut metrics = get_metrics(env);
+                metrics.total_execution_delay_ledgers = metrics
+                    .total_execution_delay_ledgers
+                    .saturating_add(now.saturating_sub(stamps.approved_at));
+                metrics.execution_delay_samples = metrics.execution_delay_samples.saturating_add(1);
+                set_metrics(env, &metrics);
+            }
+        }
+        ProposalStatus::Rejected => stamps.rejected_at = now,
+        ProposalStatus::Cancelled => stamps.cancelled_at = now,
+        _ => {}
+    }
+    let key = ProposalKey::Timestamps(proposal.id);
+    env.storage().persistent().set(&key, &stamps);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
+
+/// Number of open (non-terminal) proposals created by `proposer`.
+pub fn get_pending_count(env: &Env, proposer: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&ProposalKey::PendingCount(proposer.clone()))
+        .unwrap_or(0)
+}
+
+pub fn adjust_pending_count(env: &Env, proposer: &Address, opened: bool) {
+    let current = get_pending_count(env, proposer);
+    let updated = if opened {
+        current + 1
+    } else {
+        current.saturating_sub(1)
+    };
+    let key = ProposalKey::PendingCount(proposer.clone());
+    env.storage().persistent().set(&key, &updated);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+/// Open proposals not yet warned about, as `(expires_at, id)` pairs, earliest first.
+pub fn get_expiry_index(env: &Env) -> Vec<(u64, u64)> {
+    env.storage()
+        .persistent()
+        .get(&ProposalKey::ExpiryIndex)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn set_expiry_index(env: &Env, index: &Vec<(u64, u64)>) {
+    let key = ProposalKey::ExpiryIndex;
+    env.storage().persistent().set(&key, index);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn insert_expiry_entry(env: &Env, expires_at: u64, proposal_id: u64) {
+    let mut index = get_expiry_index(env);
+    let at = index
+        .iter()
+        .position(|(expiry, _)| expiry > expires_at)
+        .map(|p| p as u32)
+        .unwrap_or(index.len());
+    index.insert(at, (expires_at, proposal_id));
+    set_expiry_index(env, &index);
+}
+
+pub fn remove_expiry_entry(env: &Env, proposal_id: u64) {
+    let mut index = get_expiry_index(env);
+    if let Some(pos) = index.iter().position(|(_, id)| id == proposal_id) {
+        index.remove(pos as u32);
+        set_expiry_index(env, &index);
+    }
+}
+
+/// Drop the first `count` entries of the expiry index once they have been handled.
+pub fn pop_expiry_entries(env: &Env, count: u32) {
+    if count == 0 {
+        return;
+    }
+    let index = get_expiry_index(env);
+    set_expiry_index(env, &index.slice(count.min(index.len())..));
+}
+
+/// Pending proposals with a voting deadline as `(voting_deadline, proposal_id)`,
+/// earliest first.
+pub fn get_deadline_index(env: &Env) -> Vec<(u64, u64)> {
+    env.storage()
+        .persistent()
+        .get(&ProposalKey::DeadlineIndex)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn set_deadline_index(env: &Env, index: &Vec<(u64, u64)>) {
+    let key = ProposalKey::DeadlineIndex;
+    env.storage().persistent().set(&key, index);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+/// Drop a proposal from the deadline index, e.g. once its record is gone.
+pub fn remove_deadline_entry(env: &Env, proposal_id: u64) {
+    let mut index = get_deadline_index(env);
+    if let Some(pos) = index.iter().position(|(_, id)| id == proposal_id) {
+        index.remove(pos as u32);
+        set_deadline_index(env, &index);
+    }
+}
+
+/// Place a proposal at its voting deadline in the index, or drop it once it
+/// is no longer pending or has no deadline.
+pub fn index_deadline(env: &Env, proposal: &Proposal) {
+    let mut index = get_deadline_index(env);
+    let existing = index.iter().position(|(_, id)| id == proposal.id);
+    if let Some(pos) = existing {
+        index.remove(pos as u32);
+    }
+    let tracked = proposal.status == ProposalStatus::Pending && proposal.voting_deadline > 0;
+    if tracked {
+        let at = index
+            .iter()
+            .position(|(deadline, _)| deadline > proposal.voting_deadline)
+            .map(|p| p as u32)
+            .unwrap_or(index.len());
+        index.insert(at, (proposal.voting_deadline, proposal.id));
+    }
+    if existing.is_some() || tracked {
+        set_deadline_index(env, &index);
+    }
+}
+
+pub fn get_expiry_warning_window(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&ProposalKey::ExpiryWarningWindow)
+        .unwrap_or(DAY_IN_LEDGERS as u64)
+}
+
+pub fn set_expiry_warning_window(env: &Env, ledgers: u64) {
+    env.storage()
+        .instance()
+        .set(&ProposalKey::ExpiryWarningWindow, &ledgers);
+}
+
+pub fn get_status_counts(env: &Env) -> Map<ProposalStatus, u64> {
+    env.storage()
+        .instance()
+        .get(&DataKey::StatusCounts)
+        .unwrap_or_else(|| Map::new(env))
+}
+
+/// Move one proposal between status buckets.
+///
+/// Only called by `transition_status` when the status actually changes, so
+/// moving a proposal to the status it already has (e.g. a second expiry) is a no-op.
+pub fn move_status_count(env: &Env, from: Option<ProposalStatus>, to: ProposalStatus) {
+    let mut counts = get_status_counts(env);
+    if let Some(from) = from {
+        let current = counts.get(from.clone()).unwrap_or(0);
+        counts.set(from, current.saturating_sub(1));
+    }
+    let current = counts.get(to.clone()).unwrap_or(0);
+    counts.set(to, current + 1);
+    env.storage()
+        .instance()
+        .set(&DataKey::StatusCounts, &counts);
+}
+
+pub fn get_split_transfer(env: &Env, proposal_id: u64) -> Option<Vec<(Address, i128)>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SplitTransfer(proposal_id))
+}
+
+pub fn set_split_transfer(env: &Env, proposal_id: u64, splits: &Vec<(Address, i128)>) {
+    let key = DataKey::SplitTransfer(proposal_id);
+    env.storage().persistent().set(&key, splits);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
+
+pub fn get_allowed_executors(env: &Env, proposal_id: u64) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AllowedExecutors(proposal_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_allowed_executors(env: &Env, proposal_id: u64, executors: &Vec<Address>) {
+    let key = DataKey::AllowedExecutors(proposal_id);
+    if executors.is_empty() {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, executors);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
+
+/// Vault-wide execution window after approval, in ledgers (default: 0 = none).
+pub fn get_execution_window(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&ProposalKey::ExecutionWindow)
+        .unwrap_or(0)
+}
+
+pub fn set_execution_window(env: &Env, ledgers: u64) {
+    env.storage()
+        .instance()
+        .set(&ProposalKey::ExecutionWindow, &ledgers);
+}
+
+pub fn get_execution_window_override(env: &Env, proposal_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&ProposalKey::ExecutionWindowOverride(proposal_id))
+}
+
+pub fn set_execution_window_override(env: &Env, proposal_id: u64, ledgers: u64) {
+    let key = ProposalKey::ExecutionWindowOverride(proposal_id);
+    env.storage().persistent().set(&key, &ledgers);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
+
+/// Last ledger an approved proposal may execute at (default: 0 = no window).
+pub fn get_execute_by(env: &Env, proposal_id: u64) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&ProposalKey::ExecuteBy(proposal_id))
+        .unwrap_or(0)
+}
+
+pub fn set_execute_by(env: &Env, proposal_id: u64, ledger: u64) {
+    let key = ProposalKey::ExecuteBy(proposal_id);
+    env.storage().persistent().set(&key, &ledger);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
+
+/// Largest executor tip a proposal may carry (default: 0 = tips disabled).
+pub fn get_max_executor_tip(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&ProposalKey::MaxExecutorTip)
+        .unwrap_or(0)
+}
+
+pub fn set_max_executor_tip(env: &Env, amount: i128) {
+    env.storage()
+        .instance()
+        .set(&ProposalKey::MaxExecutorTip, &amount);
+}
+
+/// Tip paid from the vault to whoever executes, in the proposal's token (default: 0 = none).
+pub fn get_executor_tip(env: &Env, proposal_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&ProposalKey::ExecutorTip(proposal_id))
+        .unwrap_or(0)
+}
+
+pub fn set_executor_tip(env: &Env, proposal_id: u64, tip: i128) {
+    let key = ProposalKey::ExecutorTip(proposal_id);
+    if tip <= 0 {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, &tip);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
+
+/// Maximum number of distinct tokens tracked for monitoring views
+pub const MAX_TRACKED_TOKENS: u32 = 20;
+
+pub fn get_tracked_tokens(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::TrackedTokens)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Record a token the vault transacts in (bounded; extra tokens are ignored).
+pub fn track_token(env: &Env, token: &Address) {
+    let mut tokens = get_tracked_tokens(env);
+    if !tokens.contains(token) && tokens.len() < MAX_TRACKED_TOKENS {
+        tokens.push_back(token.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::TrackedTokens, &tokens);
+    }
+}
+
+pub fn get_next_proposal_id(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::NextProposalId)
+        .unwrap_or(1)
+}
+
+pub fn increment_proposal_id(env: &Env) -> u64 {
+    let id = get_next_proposal_id(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::NextProposalId, &(id + 1));
+    id
+}
+
+/// Return a page of existing proposal IDs in ascending creation order.
+///
+/// IDs are assigned sequentially starting at 1. This function scans the
+/// range `[offset+1 .. next_id)` and collects up to `limit` IDs that have
+/// a stored proposal entry, skipping any gaps left by deleted proposals.
+///
+/// # Arguments
+/// * `offset` - Number of proposals to skip (0-based).
+/// * `limit`  - Maximum number of IDs to return. Capped at 100 internally.
+///
+/// # Returns
+/// A vector of proposal IDs in ascending order, paginated by offset/limit.
+pub fn get_proposal_ids_paginated(env: &Env, offset: u64, limit: u64) -> Vec<u64> {
+    let cap: u64 = if limit > 100 { 100 } else { limit };
+    let next_id = get_next_proposal_id(env);
+    let mut ids: Vec<u64> = Vec::new(env);
+    let mut skipped: u64 = 0;
+
+    for id in 1..next_id {
+        if !env.storage().persistent().has(&DataKey::Proposal(id)) {
+            continue;
+        }
+        if skipped < offset {
+            skipped += 1;
+            continue;
+        }
+        ids.push_back(id);
+        if ids.len() as u64 >= cap {
+            break;
+        }
+    }
+    ids
+}
+
+// ============================================================================
+// Priority Queue
+// ============================================================================
+
+pub fn get_priority_queue(env: &Env, priority: u32) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PriorityQueue(priority))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn add_to_priority_queue(env: &Env, priority: u32, proposal_id: u64) {
+    let mut queue = get_priority_queue(env, priority);
+    queue.push_back(proposal_id);
+    let key = DataKey::PriorityQueue(priority);
+    env.storage().persistent().set(&key, &queue);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+}
+
+pub fn remove_from_priority_queue(env: &Env, priority: u32, proposal_id: u64) {
+    let queue = get_priority_queue(env, priority);
+    let mut new_queue: Vec<u64> = Vec::new(env);
+    for i in 0..queue.len() {
+        let id = queue.get(i).unwrap();
+        if id != proposal_id {
+            new_queue.push_back(id);
+        }
+    }
+    let key = DataKey::PriorityQueue(priority);
+    env.storage().persistent().set(&key, &new_queue);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+}
+
+/// Maximum proposal IDs kept per tag; the oldest are dropped first.
+pub const MAX_TAG_INDEX_ENTRIES: u32 = 200;
+
+pub fn get_tag_index(env: &Env, tag: &Symbol) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&ProposalKey::TagIndex(tag.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn set_tag_index(env: &Env, tag: &Symbol, ids: &Vec<u64>) {
+    let key = ProposalKey::TagIndex(tag.clone());
+    if ids.is_empty() {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, ids);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+}
+
+pub fn add_to_tag_index(env: &Env, tag: &Symbol, proposal_id: u64) {
+    let mut ids = get_tag_index(env, tag);
+    if ids.contains(proposal_id) {
+        return;
+    }
+    while ids.len() >= MAX_TAG_INDEX_ENTRIES {
+        ids.pop_front();
+    }
+    ids.push_back(proposal_id);
+    set_tag_index(env, tag, &ids);
+}
+
+pub fn remove_from_tag_index(env: &Env, tag: &Symbol, proposal_id: u64) {
+    let mut ids = get_tag_index(env, tag);
+    if let Some(index) = ids.first_index_of(proposal_id) {
+        ids.remove(index);
+        set_tag_index(env, tag, &ids);
+    }
+}
+
+// ============================================================================
+// Daily Spending
+// ============================================================================
+
+/// Get current day number from ledger timestamp
+pub fn get_day_number(env: &Env) -> u64 {
+    env.ledger().timestamp() / 86400
+}
+
+pub fn get_daily_spent(env: &Env, day: u64) -> i128 {
+    env.storage()
+        .temporary()
+        .get(&DataKey::DailySpent(day))
+        .unwrap_or(0)
+}
+
+pub fn add_daily_spent(env: &Env, day: u64, amount: i128) {
+    let current = get_daily_spent(env, day);
+    let key = DataKey::DailySpent(day);
+    env.storage().temporary().set(&key, &(current + amount));
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, DAY_IN_LEDGERS * 2, DAY_IN_LEDGERS * 2);
+}
+
+/// Maximum proposal IDs kept per day; later ones only bump the overflow count
+const MAX_DAILY_PROPOSALS: u32 = 50;
+
+/// Proposal IDs that reserved spending on `day` and the number that did not fit.
+pub fn get_daily_proposals(env: &Env, day: u64) -> (Vec<u64>, u32) {
+    env.storage()
+        .temporary()
+        .get(&ReportKey::DailyProposals(day))
+        .unwrap_or_else(|| (Vec::new(env), 0))
+}
+
+fn set_daily_proposals(env: &Env, day: u64, log: &(Vec<u64>, u32)) {
+    let key = ReportKey::DailyProposals(day);
+    env.storage().temporary().set(&key, log);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, DAY_IN_LEDGERS * 2, DAY_IN_LEDGERS * 2);
+}
+
+/// Record that a proposal reserved spending on `day`. Lives as long as the
+/// day's `DailySpent` counter.
+pub fn log_daily_proposal(env: &Env, day: u64, proposal_id: u64) {
+    let (mut ids, mut overflow) = get_daily_proposals(env, day);
+    if ids.contains(proposal_id) {
+        return;
+    }
+    if ids.len() < MAX_DAILY_PROPOSALS {
+        ids.push_back(proposal_id);
+    } else {
+        overflow += 1;
+    }
+    set_daily_proposals(env, day, &(ids, overflow));
+}
+
+/// Drop a proposal whose reservation was released on `day`.
+pub fn unlog_daily_proposal(env: &Env, day: u64, proposal_id: u64) {
+    let (mut ids, mut overflow) = get_daily_proposals(env, day);
+    if let Some(pos) = ids.first_index_of(proposal_id) {
+        ids.remove(pos);
+    } else if overflow > 0 {
+        overflow -= 1;
+    } else {
+        return;
+    }
+    set_daily_proposals(env, day, &(ids, overflow));
+}
+
+// ============================================================================
+// Weekly Spending
+// ============================================================================
+
+/// Get current week number (epoch / 7 days)
+pub fn get_week_number(env: &Env) -> u64 {
+    env.ledger().timestamp() / 604800
+}
+
+pub fn get_weekly_spent(env: &Env, week: u64) -> i128 {
+    env.storage()
+        .temporary()
+        .get(&DataKey::WeeklySpent(week))
+        .unwrap_or(0)
+}
+
+pub fn add_weekly_spent(env: &Env, week: u64, amount: i128) {
+    let current = get_weekly_spent(env, week);
+    let key = DataKey::WeeklySpent(week);
+    env.storage().temporary().set(&key, &(current + amount));
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, DAY_IN_LEDGERS * 14, DAY_IN_LEDGERS * 14);
+}
+
+// ============================================================================
+// Anomaly Breaker
+// ============================================================================
+
+pub fn is_paused(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&SafetyKey::Paused)
+        .unwrap_or(false)
+}
+
+pub fn set_paused(env: &Env, paused: bool) {
+    env.storage().instance().set(&SafetyKey::Paused, &paused);
+}
+
+pub fn get_auto_freeze_config(env: &Env) -> AutoFreezeConfig {
+    env.storage()
+        .instance()
+        .get(&SafetyKey::AutoFreezeConfig)
+        .unwrap_or_default()
+}
+
+pub fn set_auto_freeze_config(env: &Env, config: &AutoFreezeConfig) {
+    env.storage()
+        .instance()
+        .set(&SafetyKey::AutoFreezeConfig, config);
+}
+
+/// Timelock bypass settings; enabled with unanimity required by default.
+pub fn get_timelock_bypass_config(env: &Env) -> TimelockBypassConfig {
+    env.storage()
+        .instance()
+        .get(&SafetyKey::TimelockBypass)
+        .unwrap_or(TimelockBypassConfig {
+            enabled: true,
+            bypass_threshold: 0,
+        })
+}
+
+pub fn set_timelock_bypass_config(env: &Env, config: &TimelockBypassConfig) {
+    env.storage()
+        .instance()
+        .set(&SafetyKey::TimelockBypass, config);
+}
+
+pub fn get_second_look_multiple(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&SafetyKey::SecondLookMultiple)
+        .unwrap_or(0)
+}
+
+pub fn set_second_look_multiple(env: &Env, multiple: u32) {
+    env.storage()
+        .instance()
+        .set(&SafetyKey::SecondLookMultiple, &multiple);
+}
+
+pub fn get_second_look(env: &Env, proposal_id: u64) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&ProposalKey::SecondLook(proposal_id))
+}
+
+pub fn set_second_look(env: &Env, proposal_id: u64, signer: &Address) {
+    let key = ProposalKey::SecondLook(proposal_id);
+    env.storage().persistent().set(&key, signer);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
+
+pub fn get_recent_failures(env: &Env) -> Vec<(u64, u32)> {
+    env.storage()
+        .instance()
+        .get(&SafetyKey::RecentFailures)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_recent_failures(env: &Env, failures: &Vec<(u64, u32)>) {
+    env.storage()
+        .instance()
+        .set(&SafetyKey::RecentFailures, failures);
+}
+
+pub fn is_token_verified(env: &Env, token_addr: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&SafetyKey::VerifiedToken(token_addr.clone()))
+        .unwrap_or(false)
+}
+
+/// Remember that a token passed the contract probe so it is not probed again.
+pub fn set_token_verified(env: &Env, token_addr: &Address) {
+    let key = SafetyKey::VerifiedToken(token_addr.clone());
+    env.storage().persistent().set(&key, &true);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+// ============================================================================
+// Category Budgets
+// ============================================================================
+
+/// Get current month number (epoch / 30 days)
+pub fn get_month_number(env: &Env) -> u64 {
+    env.ledger().timestamp() / (86400 * 30)
+}
+
+/// Registered spend categories and their monthly limits. Empty = budgets disabled.
+pub fn get_category_budgets(env: &Env) -> Map<Symbol, i128> {
+    env.storage()
+        .instance()
+        .get(&BudgetKey::CategoryBudgets)
+        .unwrap_or_else(|| Map::new(env))
+}
+
+pub fn set_category_budgets(env: &Env, budgets: &Map<Symbol, i128>) {
+    env.storage()
+        .instance()
+        .set(&BudgetKey::CategoryBudgets, budgets);
+}
+
+pub fn get_category_spent(env: &Env, category: &Symbol, month: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&BudgetKey::CategorySpent(category.clone(), month))
+        .unwrap_or(0)
+}
+
+/// Add `amount` (negative to release) to a category's monthly counter, floored at zero.
+pub fn add_category_spent(env: &Env, category: &Symbol, month: u64, amount: i128) {
+    let current = get_category_spent(env, category, month);
+    let key = BudgetKey::CategorySpent(category.clone(), month);
+    env.storage()
+        .persistent()
+        .set(&key, &(current + amount).max(0));
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn get_proposal_category(env: &Env, proposal_id: u64) -> Option<(Symbol, u64)> {
+    env.storage()
+        .persistent()
+        .get(&BudgetKey::ProposalCategory(proposal_id))
+}
+
+pub fn set_proposal_category(env: &Env, proposal_id: u64, category: &Symbol, month: u64) {
+    let key = BudgetKey::ProposalCategory(proposal_id);
+    env.storage()
+        .persistent()
+        .set(&key, &(category.clone(), month));
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
+
+// ============================================================================
+// Proposal Bundles
+// ============================================================================
+
+pub fn increment_bundle_id(env: &Env) -> u64 {
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&BundleKey::NextId)
+        .unwrap_or(1);
+    env.storage().instance().set(&BundleKey::NextId, &(id + 1));
+    id
+}
+
+pub fn get_bundle(env: &Env, id: u64) -> Result<ProposalBundle, VaultError> {
+    env.storage()
+        .persistent()
+        .get(&BundleKey::Bundle(id))
+        .ok_or(VaultError::ProposalNotFound)
+}
+
+pub fn set_bundle(env: &Env, bundle: &ProposalBundle) {
+    let key = BundleKey::Bundle(bundle.id);
+    env.storage().persistent().set(&key, bundle);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
+
+pub fn get_proposal_bundle(env: &Env, proposal_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&BundleKey::Membership(proposal_id))
+}
+
+pub fn set_proposal_bundle(env: &Env, proposal_id: u64, bundle_id: u64) {
+    let key = BundleKey::Membership(proposal_id);
+    env.storage().persistent().set(&key, &bundle_id);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
+
+/// Drop a proposal from its bundle once it can no longer be approved.
+pub fn detach_from_bundle(env: &Env, proposal_id: u64) {
+    let Some(bundle_id) = get_proposal_bundle(env, proposal_id) else {
+        return;
+    };
+    env.storage()
+        .persistent()
+        .remove(&BundleKey::Membership(proposal_id));
+    if let Ok(mut bundle) = get_bundle(env, bundle_id) {
+        if let Some(pos) = bundle.proposal_ids.first_index_of(proposal_id) {
+            bundle.proposal_ids.remove(pos);
+            set_bundle(env, &bundle);
+        }
+    }
+}
+
+pub fn get_bundle_approval_mode(env: &Env) -> BundleApprovalMode {
+    env.storage()
+        .instance()
+        .get(&BundleKey::ApprovalMode)
+        .unwrap_or(BundleApprovalMode::SkipAndReport)
+}
+
+pub fn set_bundle_approval_mode(env: &Env, mode: &BundleApprovalMode) {
+    env.storage().instance().set(&BundleKey::ApprovalMode, mode);
+}
+
+// ============================================================================
+// Recurring Payments
+// ============================================================================
+
+pub fn get_next_recurring_id(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::NextRecurringId)
+        .unwrap_or(1)
+}
+
+pub fn increment_recurring_id(env: &Env) -> u64 {
+    let id = get_next_recurring_id(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::NextRecurringId, &(id + 1));
+    id
+}
+
+pub fn set_recurring_payment(env: &Env, payment: &crate::types::RecurringPayment) {
+    let key = DataKey::Recurring(payment.id);
+    env.storage().persistent().set(&key, payment);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+}
+
+pub fn get_recurring_payment(
+    env: &Env,
+    id: u64,
+) -> Result<crate::types::RecurringPayment, VaultError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Recurring(id))
+        .ok_or(VaultError::ProposalNotFound)
+}
+
+pub fn get_recurring_executors(env: &Env, payment_id: u64) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&FeatureKey::RecurringExecutors(payment_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_recurring_executors(env: &Env, payment_id: u64, executors: &Vec<Address>) {
+    let key = FeatureKey::RecurringExecutors(payment_id);
+    if executors.is_empty() {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, executors);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+}
+
+/// Conditions a recurring payment checks on each run (empty = always pays).
+pub fn get_recurring_conditions(env: &Env, payment_id: u64) -> (Vec<Condition>, ConditionLogic) {
+    env.storage()
+        .persistent()
+        .get(&FeatureKey::RecurringConditions(payment_id))
+        .unwrap_or_else(|| (Vec::new(env), ConditionLogic::And))
+}
+
+pub fn set_recurring_conditions(
+    env: &Env,
+    payment_id: u64,
+    conditions: &Vec<Condition>,
+    logic: &ConditionLogic,
+) {
+    let key = FeatureKey::RecurringConditions(payment_id);
+    if conditions.is_empty() {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage()
+        .persistent()
+        .set(&key, &(conditions.clone(), logic.clone()));
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+}
+
+pub fn get_contribution(env: &Env, contributor: &Address, token_addr: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&FeatureKey::Contribution(
+            contributor.clone(),
+            token_addr.clone(),
+        ))
+        .unwrap_or(0)
+}
+
+/// Add to a contributor's pulled total and return the new total.
+pub fn add_contribution(
+    env: &Env,
+    contributor: &Address,
+    token_addr: &Address,
+    amount: i128,
+) -> i128 {
+    let total = get_contribution(env, contributor, token_addr).saturating_add(amount);
+    let key = FeatureKey::Contribution(contributor.clone(), token_addr.clone());
+    env.storage().persistent().set(&key, &total);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, PERSISTENT_TTL);
+    total
+}
+
+/// Active recurring payments as `(next_payment_ledger, id)` pairs, earliest first.
+pub fn get_recurring_due_index(env: &Env) -> Vec<(u64, u64)> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RecurringDueIndex)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn set_recurring_due_index(env: &Env, index: &Vec<(u64, u64)>) {
+    let key = DataKey::RecurringDueIndex;
+    env.storage().persistent().set(&key, index);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+}
+
+fn without_recurring_entry(env: &Env, id: u64) -> Vec<(u64, u64)> {
+    let mut index = Vec::new(env);
+    for (due, entry_id) in get_recurring_due_index(env).iter() {
+        if entry_id != id {
+            index.push_back((due, entry_id));
+        }
+    }
+    index
+}
+
+/// Place a payment at its due position in the index, or drop it if inactive.
+pub fn index_recurring_payment(env: &Env, payment: &crate::types::RecurringPayment) {
+    let mut index = without_recurring_entry(env, payment.id);
+    if payment.is_active {
+        let entry = (payment.next_payment_ledger, payment.id);
+        let mut pos = index.len();
+        for (i, existing) in index.iter().enumerate() {
+            if existing > entry {
+                pos = i as u32;
+                break;
+            }
+        }
+        index.insert(pos, entry);
+    }
+    set_recurring_due_index(env, &index);
+}
+
+pub fn unindex_recurring_payment(env: &Env, id: u64) {
+    let index = without_recurring_entry(env, id);
+    set_recurring_due_index(env, &index);
+}
+
+// ============================================================================
+// Payment Incidents
+// ============================================================================
+
+pub fn increment_incident_id(env: &Env) -> u64 {
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&IncidentKey::NextId)
+        .unwrap_or(1);
+    env.storage()
+        .instance()
+        .set(&IncidentKey::NextId, &(id + 1));
+    id
+}
+
+pub fn get_incident(env: &Env, id: u64) -> Result<Incident, VaultError> {
+    env.storage()
+        .persistent()
+        .get(&IncidentKey::Incident(id))
+        .ok_or(VaultError::ProposalNotFound)
+}
+
+pub fn set_incident(env: &Env, incident: &Incident) {
+    let key = IncidentKey::Incident(incident.id);
+    env.storage().persistent().set(&key, incident);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn get_open_incidents(env: &Env) -> Vec<u64> {
+    env.storage()
+        .instance()
+        .get(&IncidentKey::Open)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_open_incidents(env: &Env, ids: &Vec<u64>) {
+    env.storage().instance().set(&IncidentKey::Open, ids);
+}
+
+// ============================================================================
+// Recurring Payments - Listing
+// ============================================================================
+
+/// Return a page of existing recurring payment IDs in ascending creation order.
+///
+/// IDs are assigned sequentially starting at 1. This function scans the
+/// range `[offset+1 .. next_id)` and collects up to `limit` IDs that have
+/// a stored recurring payment entry.
+///
+/// # Arguments
+/// * `offset` - Number of payments to skip (0-based).
+/// * `limit`  - Maximum number of IDs to return. Capped at 100 internally.
+///
+/// # Returns
+/// A vector of recurring payment IDs in ascending order, paginated by offset/limit.
+pub fn get_recurring_payment_ids_paginated(env: &Env, offset: u64, limit: u64) -> Vec<u64> {
+    let cap: u64 = if limit > 100 { 100 } else { limit };
+    let next_id = get_next_recurring_id(env);
+    let mut ids: Vec<u64> = Vec::new(env);
+    let mut skipped: u64 = 0;
+
+    for id in 1..next_id {
+        if !env.storage().persistent().has(&DataKey::Recurring(id)) {
+            continue;
+        }
+        if skipped < offset {
+            skipped += 1;
             continue;
         }
         ids.push_back(id);
@@ -633,61 +2156,243 @@ pub fn extend_instance_ttl(env: &Env) {
         .extend_ttl(INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
 }
 
-// ============================================================================
-// Recipient Lists
-// ============================================================================
+// ============================================================================
+// Recipient Lists
+// ============================================================================
+
+pub fn get_list_mode(env: &Env) -> ListMode {
+    env.storage()
+        .instance()
+        .get(&DataKey::ListMode)
+        .unwrap_or(ListMode::Disabled)
+}
+
+pub fn set_list_mode(env: &Env, mode: ListMode) {
+    env.storage().instance().set(&DataKey::ListMode, &mode);
+}
+
+/// Whether recipient lists are re-validated at execution (default: true).
+pub fn get_execution_list_check(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::ExecutionListCheck)
+        .unwrap_or(true)
+}
+
+pub fn set_execution_list_check(env: &Env, enabled: bool) {
+    env.storage()
+        .instance()
+        .set(&DataKey::ExecutionListCheck, &enabled);
+}
+
+pub fn get_self_transfer_allowlist(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::SelfTransferAllowlist)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_self_transfer_allowlist(env: &Env, allowlist: &Vec<Address>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::SelfTransferAllowlist, allowlist);
+}
+
+pub fn get_approval_notes(env: &Env, proposal_id: u64) -> Map<Address, Symbol> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ApprovalNotes(proposal_id))
+        .unwrap_or_else(|| Map::new(env))
+}
+
+pub fn set_approval_note(env: &Env, proposal_id: u64, approver: &Address, note: &Symbol) {
+    let mut notes = get_approval_notes(env, proposal_id);
+    notes.set(approver.clone(), note.clone());
+    let key = DataKey::ApprovalNotes(proposal_id);
+    env.storage().persistent().set(&key, &notes);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+}
+
+/// Whether approvals at or above the timelock threshold need a note (default: false).
+pub fn get_approval_note_required(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::ApprovalNoteRequired)
+        .unwrap_or(false)
+}
+
+pub fn set_approval_note_required(env: &Env, required: bool) {
+    env.storage()
+        .instance()
+        .set(&DataKey::ApprovalNoteRequired, &required);
+}
+
+/// Whether proposers may approve their own proposals (default: true).
+pub fn get_proposer_can_approve(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::ProposerCanApprove)
+        .unwrap_or(true)
+}
+
+pub fn set_proposer_can_approve(env: &Env, allowed: bool) {
+    env.storage()
+        .instance()
+        .set(&DataKey::ProposerCanApprove, &allowed);
+}
+
+fn list_entry_key(mode: &ListMode, addr: &Address) -> DataKey {
+    match mode {
+        ListMode::Blacklist => DataKey::Blacklist(addr.clone()),
+        _ => DataKey::Whitelist(addr.clone()),
+    }
+}
 
-pub fn get_list_mode(env: &Env) -> ListMode {
+/// Ledger at which a temporary list entry lapses (0 = permanent).
+pub fn get_list_expiry(env: &Env, mode: &ListMode, addr: &Address) -> u64 {
     env.storage()
-        .instance()
-        .get(&DataKey::ListMode)
-        .unwrap_or(ListMode::Disabled)
+        .persistent()
+        .get(&DataKey::ListExpiry(mode.clone(), addr.clone()))
+        .unwrap_or(0)
 }
 
-pub fn set_list_mode(env: &Env, mode: ListMode) {
-    env.storage().instance().set(&DataKey::ListMode, &mode);
+fn is_list_entry_expired(env: &Env, mode: &ListMode, addr: &Address) -> bool {
+    let expires_at = get_list_expiry(env, mode, addr);
+    expires_at > 0 && env.ledger().sequence() as u64 >= expires_at
 }
 
-pub fn is_whitelisted(env: &Env, addr: &Address) -> bool {
+/// Whether an entry is stored for the list, regardless of expiry.
+pub fn has_list_entry(env: &Env, mode: &ListMode, addr: &Address) -> bool {
     env.storage()
         .persistent()
-        .get(&DataKey::Whitelist(addr.clone()))
+        .get(&list_entry_key(mode, addr))
         .unwrap_or(false)
 }
 
-pub fn add_to_whitelist(env: &Env, addr: &Address) {
-    let key = DataKey::Whitelist(addr.clone());
+/// Whether an address is currently on the list (expired entries count as absent).
+fn is_on_list(env: &Env, mode: &ListMode, addr: &Address) -> bool {
+    has_list_entry(env, mode, addr) && !is_list_entry_expired(env, mode, addr)
+}
+
+/// Add an address to a list. `expires_at == 0` makes the entry permanent.
+pub fn add_list_entry(env: &Env, mode: &ListMode, addr: &Address, expires_at: u64) {
+    let key = list_entry_key(mode, addr);
     env.storage().persistent().set(&key, &true);
     env.storage()
         .persistent()
         .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+
+    let expiry_key = DataKey::ListExpiry(mode.clone(), addr.clone());
+    if expires_at > 0 {
+        env.storage().persistent().set(&expiry_key, &expires_at);
+        env.storage()
+            .persistent()
+            .extend_ttl(&expiry_key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+    } else {
+        env.storage().persistent().remove(&expiry_key);
+    }
+
+    let mut index = get_list_index(env, mode);
+    if !index.contains(addr) {
+        index.push_back(addr.clone());
+        set_list_index(env, mode, &index);
+    }
+
+    if *mode == ListMode::Whitelist {
+        let key = RiskKey::WhitelistedAt(addr.clone());
+        env.storage()
+            .persistent()
+            .set(&key, &(env.ledger().sequence() as u64));
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+    }
 }
 
-pub fn remove_from_whitelist(env: &Env, addr: &Address) {
+/// Remove an address (and any expiry) from a list and its index.
+pub fn remove_list_entry(env: &Env, mode: &ListMode, addr: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&list_entry_key(mode, addr));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::ListExpiry(mode.clone(), addr.clone()));
+
+    let mut index = get_list_index(env, mode);
+    if let Some(pos) = index.first_index_of(addr) {
+        index.remove(pos);
+        set_list_index(env, mode, &index);
+    }
+
+    if *mode == ListMode::Whitelist {
+        env.storage()
+            .persistent()
+            .remove(&RiskKey::WhitelistedAt(addr.clone()));
+    }
+}
+
+/// Ledger `addr` was last added to the whitelist, if it is listed.
+pub fn get_whitelisted_at(env: &Env, addr: &Address) -> Option<u64> {
     env.storage()
         .persistent()
-        .remove(&DataKey::Whitelist(addr.clone()));
+        .get(&RiskKey::WhitelistedAt(addr.clone()))
 }
 
-pub fn is_blacklisted(env: &Env, addr: &Address) -> bool {
+/// Remove the entry if it has expired. Returns true if it was pruned.
+pub fn prune_list_entry(env: &Env, mode: &ListMode, addr: &Address) -> bool {
+    if has_list_entry(env, mode, addr) && is_list_entry_expired(env, mode, addr) {
+        remove_list_entry(env, mode, addr);
+        true
+    } else {
+        false
+    }
+}
+
+pub fn get_list_index(env: &Env, mode: &ListMode) -> Vec<Address> {
     env.storage()
         .persistent()
-        .get(&DataKey::Blacklist(addr.clone()))
-        .unwrap_or(false)
+        .get(&DataKey::ListIndex(mode.clone()))
+        .unwrap_or_else(|| Vec::new(env))
 }
 
-pub fn add_to_blacklist(env: &Env, addr: &Address) {
-    let key = DataKey::Blacklist(addr.clone());
-    env.storage().persistent().set(&key, &true);
+fn set_list_index(env: &Env, mode: &ListMode, index: &Vec<Address>) {
+    let key = DataKey::ListIndex(mode.clone());
+    env.storage().persistent().set(&key, index);
     env.storage()
         .persistent()
         .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
 }
 
+pub fn is_whitelisted(env: &Env, addr: &Address) -> bool {
+    is_on_list(env, &ListMode::Whitelist, addr)
+}
+
+pub fn add_to_whitelist(env: &Env, addr: &Address) {
+    add_list_entry(env, &ListMode::Whitelist, addr, 0);
+}
+
+pub fn remove_from_whitelist(env: &Env, addr: &Address) {
+    remove_list_entry(env, &ListMode::Whitelist, addr);
+}
+
+pub fn is_blacklisted(env: &Env, addr: &Address) -> bool {
+    is_on_list(env, &ListMode::Blacklist, addr)
+}
+
+pub fn add_to_blacklist(env: &Env, addr: &Address) {
+    add_list_entry(env, &ListMode::Blacklist, addr, 0);
+}
+
 pub fn remove_from_blacklist(env: &Env, addr: &Address) {
-    env.storage()
-        .persistent()
-        .remove(&DataKey::Blacklist(addr.clone()));
+    remove_list_entry(env, &ListMode::Blacklist, addr);
+}
+
+/// Whether `addr` passes Whitelist mode: listed explicitly, or in the
+/// address book while book entries are treated as whitelisted.
+pub fn is_whitelisted_recipient(env: &Env, addr: &Address) -> bool {
+    is_whitelisted(env, addr) || (get_address_book_whitelisted(env) && in_address_book(env, addr))
 }
 
 pub fn validate_recipient_list(env: &Env, recipient: &Address) -> Result<(), VaultError> {
@@ -695,7 +2400,7 @@ pub fn validate_recipient_list(env: &Env, recipient: &Address) -> Result<(), Vau
     match mode {
         ListMode::Disabled => Ok(()),
         ListMode::Whitelist => {
-            if !is_whitelisted(env, recipient) {
+            if !is_whitelisted_recipient(env, recipient) {
                 return Err(VaultError::RecipientNotWhitelisted);
             }
             Ok(())
@@ -709,38 +2414,137 @@ pub fn validate_recipient_list(env: &Env, recipient: &Address) -> Result<(), Vau
     }
 }
 
+// ============================================================================
+// Vault Metadata
+// ============================================================================
+
+pub fn get_vault_metadata(env: &Env) -> Map<Symbol, String> {
+    env.storage()
+        .instance()
+        .get(&VaultInfoKey::Metadata)
+        .unwrap_or_else(|| Map::new(env))
+}
+
+pub fn set_vault_metadata(env: &Env, metadata: &Map<Symbol, String>) {
+    env.storage()
+        .instance()
+        .set(&VaultInfoKey::Metadata, metadata);
+}
+
+// ============================================================================
+// Address Book
+// ============================================================================
+
+pub fn get_address_book(env: &Env) -> Vec<AddressBookEntry> {
+    env.storage()
+        .instance()
+        .get(&AddressBookKey::Entries)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_address_book(env: &Env, entries: &Vec<AddressBookEntry>) {
+    env.storage()
+        .instance()
+        .set(&AddressBookKey::Entries, entries);
+}
+
+/// Address recorded under `label`, if any.
+pub fn resolve_address_label(env: &Env, label: &Symbol) -> Option<Address> {
+    get_address_book(env)
+        .iter()
+        .find(|entry| entry.label == *label)
+        .map(|entry| entry.address)
+}
+
+fn in_address_book(env: &Env, addr: &Address) -> bool {
+    get_address_book(env)
+        .iter()
+        .any(|entry| entry.address == *addr)
+}
+
+pub fn get_address_book_whitelisted(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&AddressBookKey::Whitelisted)
+        .unwrap_or(false)
+}
+
+pub fn set_address_book_whitelisted(env: &Env, enabled: bool) {
+    env.storage()
+        .instance()
+        .set(&AddressBookKey::Whitelisted, &enabled);
+}
+
 // ============================================================================
 // Velocity Checking (Sliding Window)
 // ============================================================================
 
-pub fn check_and_update_velocity(env: &Env, addr: &Address, config: &VelocityConfig) -> bool {
+/// Timestamps of `addr`'s actions that are still inside the velocity window.
+///
+/// An action at `ts` occupies a slot until `ts + window`; at that instant it
+/// ages out. The stored history never holds more than `limit` entries.
+fn live_velocity_history(env: &Env, addr: &Address, config: &VelocityConfig) -> Vec<u64> {
     let now = env.ledger().timestamp();
-    let key = DataKey::VelocityHistory(addr.clone());
-
     let history: Vec<u64> = env
         .storage()
         .temporary()
-        .get(&key)
+        .get(&DataKey::VelocityHistory(addr.clone()))
         .unwrap_or_else(|| Vec::new(env));
 
-    let window_start = now.saturating_sub(config.window);
-
-    let mut updated_history: Vec<u64> = Vec::new(env);
+    let mut live = Vec::new(env);
     for ts in history.iter() {
-        if ts > window_start {
-            updated_history.push_back(ts);
+        if ts.saturating_add(config.window) > now {
+            live.push_back(ts);
         }
     }
+    live
+}
+
+/// Number of actions by `addr` still inside the velocity window (read-only).
+pub fn get_velocity_count(env: &Env, addr: &Address, config: &VelocityConfig) -> u32 {
+    live_velocity_history(env, addr, config).len()
+}
+
+/// `(live count, seconds until the oldest live action frees a slot)`.
+///
+/// The wait is 0 while the address is under its limit.
+pub fn get_velocity_status(env: &Env, addr: &Address, config: &VelocityConfig) -> (u32, u64) {
+    let live = live_velocity_history(env, addr, config);
+    if live.len() < config.limit {
+        return (live.len(), 0);
+    }
+    let oldest = live.first().unwrap_or(0);
+    let frees_at = oldest.saturating_add(config.window);
+    (
+        live.len(),
+        frees_at.saturating_sub(env.ledger().timestamp()),
+    )
+}
 
-    if updated_history.len() >= config.limit {
+/// Record an `action` by `addr` if it fits in the velocity window.
+///
+/// Exempt actions always pass and do not take a slot.
+pub fn check_and_update_velocity(
+    env: &Env,
+    addr: &Address,
+    config: &VelocityConfig,
+    action: VelocityAction,
+) -> bool {
+    if action.is_exempt() || is_contract_signer(env, addr) {
+        return true;
+    }
+    let mut live = live_velocity_history(env, addr, config);
+    if live.len() >= config.limit {
         return false;
     }
 
-    updated_history.push_back(now);
-    env.storage().temporary().set(&key, &updated_history);
-    env.storage()
-        .temporary()
-        .extend_ttl(&key, DAY_IN_LEDGERS, DAY_IN_LEDGERS);
+    live.push_back(env.ledger().timestamp());
+    let key = DataKey::VelocityHistory(addr.clone());
+    env.storage().temporary().set(&key, &live);
+    // Keep the history alive for the whole window (~5s per ledger), within 1..30 days
+    let window_ledgers = (config.window / 5 + 1).min(INSTANCE_TTL as u64) as u32;
+    let ttl = window_ledgers.max(DAY_IN_LEDGERS);
+    env.storage().temporary().extend_ttl(&key, ttl, ttl);
 
     true
 }
@@ -876,6 +2680,88 @@ pub fn add_comment_to_proposal(env: &Env, proposal_id: u64, comment_id: u64) {
         .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
 }
 
+/// Default maximum number of comments per proposal
+pub const DEFAULT_COMMENT_LIMIT: u32 = 100;
+
+pub fn get_comment_limit(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::CommentLimit)
+        .unwrap_or(DEFAULT_COMMENT_LIMIT)
+}
+
+pub fn set_comment_limit(env: &Env, limit: u32) {
+    env.storage().instance().set(&DataKey::CommentLimit, &limit);
+}
+
+pub fn get_comment_quota(env: &Env) -> CommentQuota {
+    env.storage()
+        .instance()
+        .get(&CommentKey::Quota)
+        .unwrap_or(CommentQuota {
+            max_per_author: 10,
+            min_gap_ledgers: 5,
+        })
+}
+
+pub fn set_comment_quota(env: &Env, quota: &CommentQuota) {
+    env.storage().instance().set(&CommentKey::Quota, quota);
+}
+
+pub fn get_comment_author_stats(
+    env: &Env,
+    proposal_id: u64,
+    author: &Address,
+) -> CommentAuthorStats {
+    env.storage()
+        .persistent()
+        .get(&CommentKey::AuthorStats(proposal_id, author.clone()))
+        .unwrap_or_default()
+}
+
+pub fn set_comment_author_stats(
+    env: &Env,
+    proposal_id: u64,
+    author: &Address,
+    stats: &CommentAuthorStats,
+) {
+    let key = CommentKey::AuthorStats(proposal_id, author.clone());
+    env.storage().persistent().set(&key, stats);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
+
+pub fn get_comment_reactions(env: &Env, comment_id: u64) -> Map<Symbol, u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CommentReactions(comment_id))
+        .unwrap_or_else(|| Map::new(env))
+}
+
+pub fn set_comment_reactions(env: &Env, comment_id: u64, reactions: &Map<Symbol, u32>) {
+    let key = DataKey::CommentReactions(comment_id);
+    env.storage().persistent().set(&key, reactions);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+}
+
+pub fn get_comment_reactors(env: &Env, comment_id: u64) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CommentReactors(comment_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_comment_reactors(env: &Env, comment_id: u64, reactors: &Vec<Address>) {
+    let key = DataKey::CommentReactors(comment_id);
+    env.storage().persistent().set(&key, reactors);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+}
+
 pub fn is_in_priority_queue(env: &Env, priority: u32, proposal_id: u64) -> bool {
     get_priority_queue(env, priority).contains(proposal_id)
 }
@@ -936,6 +2822,7 @@ pub fn set_last_audit_hash(env: &Env, hash: u64) {
 // Attachments
 // ============================================================================
 
+/// Canonical attachment list for a proposal (single source of truth).
 pub fn get_attachments(env: &Env, proposal_id: u64) -> Vec<String> {
     env.storage()
         .persistent()
@@ -951,6 +2838,26 @@ pub fn set_attachments(env: &Env, proposal_id: u64, attachments: &Vec<String>) {
         .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
 }
 
+pub fn get_milestone_evidence(env: &Env, escrow_id: u64, milestone_id: u64) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&EscrowKey::MilestoneEvidence(escrow_id, milestone_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_milestone_evidence(
+    env: &Env,
+    escrow_id: u64,
+    milestone_id: u64,
+    evidence: &Vec<String>,
+) {
+    let key = EscrowKey::MilestoneEvidence(escrow_id, milestone_id);
+    env.storage().persistent().set(&key, evidence);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+}
+
 // ============================================================================
 // Reputation (Issue: feature/reputation-system)
 // ============================================================================
@@ -962,6 +2869,12 @@ pub fn get_reputation(env: &Env, addr: &Address) -> Reputation {
         .unwrap_or_default()
 }
 
+pub fn has_reputation(env: &Env, addr: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Reputation(addr.clone()))
+}
+
 pub fn set_reputation(env: &Env, addr: &Address, rep: &Reputation) {
     let key = DataKey::Reputation(addr.clone());
     env.storage().persistent().set(&key, rep);
@@ -1024,6 +2937,22 @@ pub fn set_insurance_config(env: &Env, config: &InsuranceConfig) {
         .set(&FeatureKey::InsuranceConfig, config);
 }
 
+pub fn get_insurance_usd_threshold(env: &Env) -> crate::types::InsuranceUsdThreshold {
+    env.storage()
+        .instance()
+        .get(&FeatureKey::InsuranceUsdThreshold)
+        .unwrap_or(crate::types::InsuranceUsdThreshold {
+            min_amount_usd: 0,
+            oracle_fallback: crate::types::InsuranceOracleFallback::TokenThreshold,
+        })
+}
+
+pub fn set_insurance_usd_threshold(env: &Env, threshold: &crate::types::InsuranceUsdThreshold) {
+    env.storage()
+        .instance()
+        .set(&FeatureKey::InsuranceUsdThreshold, threshold);
+}
+
 pub fn get_insurance_pool(env: &Env, token_addr: &Address) -> i128 {
     env.storage()
         .persistent()
@@ -1053,6 +2982,122 @@ pub fn subtract_from_insurance_pool(env: &Env, token_addr: &Address, amount: i12
         .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, PERSISTENT_TTL);
 }
 
+pub fn is_direct_pool_withdrawal(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&PoolKey::DirectWithdrawal)
+        .unwrap_or(false)
+}
+
+pub fn set_direct_pool_withdrawal(env: &Env, enabled: bool) {
+    env.storage()
+        .instance()
+        .set(&PoolKey::DirectWithdrawal, &enabled);
+}
+
+pub fn get_slash_destination(env: &Env) -> crate::types::SlashDestination {
+    env.storage()
+        .instance()
+        .get(&PoolKey::SlashDestination)
+        .unwrap_or(crate::types::SlashDestination::Pool)
+}
+
+pub fn set_slash_destination(env: &Env, destination: &crate::types::SlashDestination) {
+    env.storage()
+        .instance()
+        .set(&PoolKey::SlashDestination, destination);
+}
+
+/// Insurance currently locked for open proposals in `token_addr`.
+pub fn get_locked_insurance(env: &Env, token_addr: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&PoolKey::LockedInsurance(token_addr.clone()))
+        .unwrap_or(0)
+}
+
+/// Stakes currently locked for open proposals in `token_addr`.
+pub fn get_locked_stakes(env: &Env, token_addr: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&PoolKey::LockedStakes(token_addr.clone()))
+        .unwrap_or(0)
+}
+
+fn add_locked(env: &Env, key: PoolKey, delta: i128) {
+    let current: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+    env.storage()
+        .persistent()
+        .set(&key, &current.saturating_add(delta).max(0));
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn adjust_locked_deposits(env: &Env, proposal: &Proposal, sign: i128) {
+    if proposal.insurance_amount > 0 {
+        let token_addr = get_insurance_token(env, proposal);
+        add_locked(
+            env,
+            PoolKey::LockedInsurance(token_addr),
+            sign * proposal.insurance_amount,
+        );
+    }
+    if proposal.stake_amount > 0 {
+        add_locked(
+            env,
+            PoolKey::LockedStakes(proposal.token.clone()),
+            sign * proposal.stake_amount,
+        );
+    }
+}
+
+pub fn get_pool_withdrawal(env: &Env, proposal_id: u64) -> Option<PoolKind> {
+    env.storage()
+        .persistent()
+        .get(&PoolKey::Withdrawal(proposal_id))
+}
+
+pub fn set_pool_withdrawal(env: &Env, proposal_id: u64, pool: &PoolKind) {
+    let key = PoolKey::Withdrawal(proposal_id);
+    env.storage().persistent().set(&key, pool);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
+
+/// Current balance of a slashed-fund pool for `token_addr`.
+pub fn get_pool_balance(env: &Env, pool: &PoolKind, token_addr: &Address) -> i128 {
+    match pool {
+        PoolKind::Insurance => get_insurance_pool(env, token_addr),
+        PoolKind::Stake => get_stake_pool(env, token_addr),
+    }
+}
+
+pub fn subtract_from_pool(env: &Env, pool: &PoolKind, token_addr: &Address, amount: i128) {
+    match pool {
+        PoolKind::Insurance => subtract_from_insurance_pool(env, token_addr, amount),
+        PoolKind::Stake => subtract_from_stake_pool(env, token_addr, amount),
+    }
+}
+
+pub fn get_insurance_exemptions(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::InsuranceExemptions)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_insurance_exemptions(env: &Env, exemptions: &Vec<Address>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::InsuranceExemptions, exemptions);
+}
+
+pub fn is_insurance_exempt(env: &Env, addr: &Address) -> bool {
+    get_insurance_exemptions(env).contains(addr)
+}
+
 // ============================================================================
 // Notification Preferences (Issue: feature/execution-notifications)
 // ============================================================================
@@ -1072,6 +3117,52 @@ pub fn set_notification_prefs(env: &Env, addr: &Address, prefs: &NotificationPre
         .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
 }
 
+pub fn get_notification_digest(env: &Env, addr: &Address) -> NotificationDigest {
+    env.storage()
+        .persistent()
+        .get(&NotificationKey::Digest(addr.clone()))
+        .unwrap_or_default()
+}
+
+pub fn set_notification_digest(env: &Env, addr: &Address, digest: &NotificationDigest) {
+    let key = NotificationKey::Digest(addr.clone());
+    env.storage().persistent().set(&key, digest);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+}
+
+/// Maximum comment IDs kept per mentioned address; the oldest are dropped first.
+pub const MAX_MENTION_INDEX_ENTRIES: u32 = 200;
+
+pub fn get_mentions(env: &Env, addr: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&NotificationKey::Mentions(addr.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn add_mention(env: &Env, addr: &Address, comment_id: u64) {
+    let mut ids = get_mentions(env, addr);
+    while ids.len() >= MAX_MENTION_INDEX_ENTRIES {
+        ids.pop_front();
+    }
+    ids.push_back(comment_id);
+    let key = NotificationKey::Mentions(addr.clone());
+    env.storage().persistent().set(&key, &ids);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+}
+
+/// Record one more unread notification for `addr`.
+pub fn bump_notification_digest(env: &Env, addr: &Address) {
+    let mut digest = get_notification_digest(env, addr);
+    digest.pending_count = digest.pending_count.saturating_add(1);
+    digest.last_notified_ledger = env.ledger().sequence() as u64;
+    set_notification_digest(env, addr, &digest);
+}
+
 // ============================================================================
 // DEX/AMM Integration (Issue: feature/amm-integration)
 // ============================================================================
@@ -1159,6 +3250,136 @@ pub fn set_execution_fee_estimate(env: &Env, proposal_id: u64, estimate: &Execut
         .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
 }
 
+// ============================================================================
+// Treasury Checkpoints
+// ============================================================================
+
+/// Days between treasury checkpoints (default: 1).
+pub fn get_checkpoint_interval(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&ReportKey::CheckpointInterval)
+        .unwrap_or(1)
+}
+
+pub fn set_checkpoint_interval(env: &Env, days: u64) {
+    env.storage()
+        .instance()
+        .set(&ReportKey::CheckpointInterval, &days);
+}
+
+pub fn get_last_checkpoint(env: &Env) -> Option<u64> {
+    env.storage().instance().get(&ReportKey::LastCheckpoint)
+}
+
+pub fn set_last_checkpoint(env: &Env, day: u64) {
+    env.storage()
+        .instance()
+        .set(&ReportKey::LastCheckpoint, &day);
+}
+
+pub fn get_period_outflow(env: &Env) -> Map<Address, i128> {
+    env.storage()
+        .instance()
+        .get(&ReportKey::PeriodOutflow)
+        .unwrap_or_else(|| Map::new(env))
+}
+
+pub fn is_paid_recipient(env: &Env, recipient: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&RiskKey::PaidRecipient(recipient.clone()))
+        .unwrap_or(false)
+}
+
+/// Remember that the vault has paid `recipient` at least once.
+pub fn mark_recipient_paid(env: &Env, recipient: &Address) {
+    let key = RiskKey::PaidRecipient(recipient.clone());
+    env.storage().persistent().set(&key, &true);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+/// Add an executed payment to the outflow reported by the next checkpoint.
+pub fn record_outflow(env: &Env, token_addr: &Address, amount: i128) {
+    let mut outflow = get_period_outflow(env);
+    let current = outflow.get(token_addr.clone()).unwrap_or(0);
+    outflow.set(token_addr.clone(), current.saturating_add(amount));
+    env.storage()
+        .instance()
+        .set(&ReportKey::PeriodOutflow, &outflow);
+}
+
+pub fn reset_period_outflow(env: &Env) {
+    env.storage().instance().remove(&ReportKey::PeriodOutflow);
+}
+
+pub fn get_total_outflow(env: &Env, token_addr: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&ReportKey::TotalOutflow(token_addr.clone()))
+        .unwrap_or(0)
+}
+
+pub fn get_total_inflow(env: &Env, token_addr: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&ReportKey::TotalInflow(token_addr.clone()))
+        .unwrap_or(0)
+}
+
+/// Add to the lifetime outflow of a token. Called by every transfer out of the vault.
+pub fn add_total_outflow(env: &Env, token_addr: &Address, amount: i128) {
+    let total = get_total_outflow(env, token_addr).saturating_add(amount);
+    bump_flow_counter(env, ReportKey::TotalOutflow(token_addr.clone()), total);
+    update_daily_flow(env, token_addr, |flow| {
+        flow.outflow = flow.outflow.saturating_add(amount)
+    });
+}
+
+/// Add to the lifetime inflow of a token. Called by every transfer into the vault.
+pub fn add_total_inflow(env: &Env, token_addr: &Address, amount: i128) {
+    let total = get_total_inflow(env, token_addr).saturating_add(amount);
+    bump_flow_counter(env, ReportKey::TotalInflow(token_addr.clone()), total);
+    update_daily_flow(env, token_addr, |flow| {
+        flow.inflow = flow.inflow.saturating_add(amount)
+    });
+}
+
+/// Days of flow history kept; older buckets are left to expire
+pub const FLOW_WINDOW_DAYS: u32 = 30;
+
+pub fn get_daily_flow(env: &Env, token_addr: &Address, day: u64) -> DailyFlow {
+    env.storage()
+        .temporary()
+        .get(&ReportKey::DailyFlow(token_addr.clone(), day))
+        .unwrap_or_default()
+}
+
+/// Count a proposal executed in `token_addr` towards today's flow bucket.
+pub fn record_daily_execution(env: &Env, token_addr: &Address) {
+    update_daily_flow(env, token_addr, |flow| flow.executed_proposals += 1);
+}
+
+fn update_daily_flow(env: &Env, token_addr: &Address, update: impl FnOnce(&mut DailyFlow)) {
+    let day = get_day_number(env);
+    let mut flow = get_daily_flow(env, token_addr, day);
+    update(&mut flow);
+    // Temporary storage lets buckets outside the window expire on their own
+    let key = ReportKey::DailyFlow(token_addr.clone(), day);
+    env.storage().temporary().set(&key, &flow);
+    let ttl = DAY_IN_LEDGERS * (FLOW_WINDOW_DAYS + 1);
+    env.storage().temporary().extend_ttl(&key, ttl, ttl);
+}
+
+fn bump_flow_counter(env: &Env, key: ReportKey, total: i128) {
+    env.storage().persistent().set(&key, &total);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
 // ============================================================================
 // Performance Metrics (Issue: feature/performance-metrics)
 // ============================================================================
@@ -1192,6 +3413,43 @@ pub fn metrics_on_rejection(env: &Env) {
     set_metrics(env, &metrics);
 }
 
+pub fn metrics_on_cancellation(env: &Env) {
+    let mut metrics = get_metrics(env);
+    metrics.cancelled_count = metrics.cancelled_count.saturating_add(1);
+    metrics.last_updated_ledger = env.ledger().sequence() as u64;
+    set_metrics(env, &metrics);
+}
+
+pub fn metrics_on_retry_scheduled(env: &Env) {
+    let mut metrics = get_metrics(env);
+    metrics.retry_scheduled_count = metrics.retry_scheduled_count.saturating_add(1);
+    metrics.last_updated_ledger = env.ledger().sequence() as u64;
+    set_metrics(env, &metrics);
+}
+
+pub fn metrics_on_retry_succeeded(env: &Env) {
+    let mut metrics = get_metrics(env);
+    metrics.retry_succeeded_count = metrics.retry_succeeded_count.saturating_add(1);
+    metrics.last_updated_ledger = env.ledger().sequence() as u64;
+    set_metrics(env, &metrics);
+}
+
+pub fn metrics_on_dispute_filed(env: &Env) {
+    let mut metrics = get_metrics(env);
+    metrics.disputes_filed = metrics.disputes_filed.saturating_add(1);
+    metrics.last_updated_ledger = env.ledger().sequence() as u64;
+    set_metrics(env, &metrics);
+}
+
+pub fn metrics_on_dispute_resolved(env: &Env, upheld: bool) {
+    let mut metrics = get_metrics(env);
+    if upheld {
+        metrics.disputes_upheld = metrics.disputes_upheld.saturating_add(1);
+    }
+    metrics.last_updated_ledger = env.ledger().sequence() as u64;
+    set_metrics(env, &metrics);
+}
+
 pub fn metrics_on_expiry(env: &Env) {
     let mut metrics = get_metrics(env);
     metrics.expired_count = metrics.expired_count.saturating_add(1);
@@ -1437,6 +3695,12 @@ pub fn set_retry_state(env: &Env, proposal_id: u64, state: &RetryState) {
         .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
 }
 
+pub fn remove_retry_state(env: &Env, proposal_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&FeatureKey::RetryState(proposal_id));
+}
+
 // ============================================================================
 // Streaming Payments
 // ============================================================================
@@ -1474,11 +3738,156 @@ pub fn get_streaming_payment(
         .ok_or(VaultError::ProposalNotFound)
 }
 
+pub fn get_sender_streams(env: &Env, sender: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SenderStreams(sender.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn add_sender_stream(env: &Env, sender: &Address, stream_id: u64) {
+    let mut streams = get_sender_streams(env, sender);
+    streams.push_back(stream_id);
+    let key = DataKey::SenderStreams(sender.clone());
+    env.storage().persistent().set(&key, &streams);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+/// Release `amount` from a stream's escrow, refusing to take it below zero.
+///
+/// Every claim or refund must go through here so `escrow_remaining` always
+/// matches what the vault still holds for the stream.
+pub fn debit_stream_escrow(
+    env: &Env,
+    stream: &mut crate::types::StreamingPayment,
+    amount: i128,
+) -> Result<(), VaultError> {
+    if amount < 0 || amount > stream.escrow_remaining {
+        return Err(VaultError::StreamAccountingError);
+    }
+    stream.escrow_remaining -= amount;
+    set_streaming_payment(env, stream);
+    Ok(())
+}
+
+// ============================================================================
+// Subscriptions
+// ============================================================================
+
+pub fn increment_subscription_id(env: &Env) -> u64 {
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&SubscriptionKey::NextId)
+        .unwrap_or(1);
+    env.storage()
+        .instance()
+        .set(&SubscriptionKey::NextId, &(id + 1));
+    id
+}
+
+pub fn set_subscription(env: &Env, subscription: &crate::types::Subscription) {
+    let key = SubscriptionKey::Subscription(subscription.id);
+    env.storage().persistent().set(&key, subscription);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn get_subscription(env: &Env, id: u64) -> Result<crate::types::Subscription, VaultError> {
+    env.storage()
+        .persistent()
+        .get(&SubscriptionKey::Subscription(id))
+        .ok_or(VaultError::ProposalNotFound)
+}
+
+pub fn get_provider_subscriptions(env: &Env, provider: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&SubscriptionKey::ProviderSubscriptions(provider.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Index a subscription under its service provider. Entries are never removed,
+/// so cancelled subscriptions remain part of the provider's history.
+pub fn add_provider_subscription(env: &Env, provider: &Address, subscription_id: u64) {
+    let mut ids = get_provider_subscriptions(env, provider);
+    ids.push_back(subscription_id);
+    let key = SubscriptionKey::ProviderSubscriptions(provider.clone());
+    env.storage().persistent().set(&key, &ids);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn get_provider_revenue(env: &Env, provider: &Address, token_addr: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&SubscriptionKey::ProviderRevenue(
+            provider.clone(),
+            token_addr.clone(),
+        ))
+        .unwrap_or(0)
+}
+
+/// Add a renewal payment to the provider's running revenue and return the new total.
+pub fn add_provider_revenue(
+    env: &Env,
+    provider: &Address,
+    token_addr: &Address,
+    amount: i128,
+) -> i128 {
+    let total = get_provider_revenue(env, provider, token_addr).saturating_add(amount);
+    let key = SubscriptionKey::ProviderRevenue(provider.clone(), token_addr.clone());
+    env.storage().persistent().set(&key, &total);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+    total
+}
+
+/// Keeper-renewable subscriptions as `(next_renewal_ledger, id)` pairs, earliest first.
+pub fn get_subscription_due_index(env: &Env) -> Vec<(u64, u64)> {
+    env.storage()
+        .persistent()
+        .get(&SubscriptionKey::DueIndex)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Place a subscription at its renewal position in the index. Only active,
+/// auto-renewing subscriptions are indexed; anything else is dropped.
+pub fn index_subscription(env: &Env, subscription: &crate::types::Subscription) {
+    let mut index = Vec::new(env);
+    for (due, id) in get_subscription_due_index(env).iter() {
+        if id != subscription.id {
+            index.push_back((due, id));
+        }
+    }
+    if subscription.status == crate::types::SubscriptionStatus::Active && subscription.auto_renew {
+        let entry = (subscription.next_renewal_ledger, subscription.id);
+        let mut pos = index.len();
+        for (i, existing) in index.iter().enumerate() {
+            if existing > entry {
+                pos = i as u32;
+                break;
+            }
+        }
+        index.insert(pos, entry);
+    }
+    let key = SubscriptionKey::DueIndex;
+    env.storage().persistent().set(&key, &index);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+}
+
 // ============================================================================
 // Escrow
 // ============================================================================
 
-fn get_next_escrow_id(env: &Env) -> u64 {
+pub fn get_next_escrow_id(env: &Env) -> u64 {
     env.storage()
         .instance()
         .get(&FeatureKey::NextEscrowId)
@@ -1493,8 +3902,49 @@ pub fn increment_escrow_id(env: &Env) -> u64 {
     id
 }
 
+/// Persist an escrow, keeping the keeper indexes in step with its status:
+/// unfinalized escrows are tracked by expiry, and escrows with every
+/// milestone complete are queued for release.
 pub fn set_escrow(env: &Env, escrow: &Escrow) {
     let key = FeatureKey::Escrow(escrow.id);
+    let previous: Option<Escrow> = env.storage().persistent().get(&key);
+    let previous_status = previous.map(|e| e.status);
+    if previous_status.as_ref() != Some(&escrow.status) {
+        let finalized = |status: &EscrowStatus| {
+            matches!(status, EscrowStatus::Released | EscrowStatus::Refunded)
+        };
+        let time_based = escrow.milestone_mode == MilestoneMode::TimeBased;
+        if previous_status.is_none() && !finalized(&escrow.status) {
+            insert_escrow_expiry(env, escrow.expires_at, escrow.id);
+            if time_based {
+                let mut ids = get_time_based_escrows(env);
+                ids.push_back(escrow.id);
+                set_time_based_escrows(env, &ids);
+            }
+        } else if previous_status.as_ref().is_some_and(|s| !finalized(s))
+            && finalized(&escrow.status)
+        {
+            remove_escrow_expiry(env, escrow.id);
+            if time_based {
+                let mut ids = get_time_based_escrows(env);
+                if let Some(pos) = ids.first_index_of(escrow.id) {
+                    ids.remove(pos);
+                    set_time_based_escrows(env, &ids);
+                }
+            }
+        }
+        if escrow.status == EscrowStatus::MilestonesComplete {
+            let mut ready = get_escrows_ready_for_release(env);
+            ready.push_back(escrow.id);
+            set_escrows_ready_for_release(env, &ready);
+        } else if previous_status == Some(EscrowStatus::MilestonesComplete) {
+            let mut ready = get_escrows_ready_for_release(env);
+            if let Some(pos) = ready.first_index_of(escrow.id) {
+                ready.remove(pos);
+                set_escrows_ready_for_release(env, &ready);
+            }
+        }
+    }
     env.storage().persistent().set(&key, escrow);
     env.storage()
         .persistent()
@@ -1515,16 +3965,20 @@ pub fn get_funder_escrows(env: &Env, funder: &Address) -> Vec<u64> {
         .unwrap_or_else(|| Vec::new(env))
 }
 
-pub fn add_funder_escrow(env: &Env, funder: &Address, escrow_id: u64) {
-    let mut escrows = get_funder_escrows(env, funder);
-    escrows.push_back(escrow_id);
+pub fn set_funder_escrows(env: &Env, funder: &Address, escrows: &Vec<u64>) {
     let key = FeatureKey::FunderEscrows(funder.clone());
-    env.storage().persistent().set(&key, &escrows);
+    env.storage().persistent().set(&key, escrows);
     env.storage()
         .persistent()
         .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, PERSISTENT_TTL);
 }
 
+pub fn add_funder_escrow(env: &Env, funder: &Address, escrow_id: u64) {
+    let mut escrows = get_funder_escrows(env, funder);
+    escrows.push_back(escrow_id);
+    set_funder_escrows(env, funder, &escrows);
+}
+
 pub fn get_recipient_escrows(env: &Env, recipient: &Address) -> Vec<u64> {
     env.storage()
         .persistent()
@@ -1532,16 +3986,99 @@ pub fn get_recipient_escrows(env: &Env, recipient: &Address) -> Vec<u64> {
         .unwrap_or_else(|| Vec::new(env))
 }
 
+pub fn set_recipient_escrows(env: &Env, recipient: &Address, escrows: &Vec<u64>) {
+    let key = FeatureKey::RecipientEscrows(recipient.clone());
+    env.storage().persistent().set(&key, escrows);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
 pub fn add_recipient_escrow(env: &Env, recipient: &Address, escrow_id: u64) {
     let mut escrows = get_recipient_escrows(env, recipient);
     escrows.push_back(escrow_id);
-    let key = FeatureKey::RecipientEscrows(recipient.clone());
-    env.storage().persistent().set(&key, &escrows);
+    set_recipient_escrows(env, recipient, &escrows);
+}
+
+pub fn get_escrow_expiry_index(env: &Env) -> Vec<(u64, u64)> {
+    env.storage()
+        .persistent()
+        .get(&EscrowKey::ExpiryIndex)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn set_escrow_expiry_index(env: &Env, index: &Vec<(u64, u64)>) {
+    let key = EscrowKey::ExpiryIndex;
+    env.storage().persistent().set(&key, index);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+fn insert_escrow_expiry(env: &Env, expires_at: u64, escrow_id: u64) {
+    let mut index = get_escrow_expiry_index(env);
+    let at = index
+        .iter()
+        .position(|(expiry, _)| expiry > expires_at)
+        .map(|p| p as u32)
+        .unwrap_or(index.len());
+    index.insert(at, (expires_at, escrow_id));
+    set_escrow_expiry_index(env, &index);
+}
+
+fn remove_escrow_expiry(env: &Env, escrow_id: u64) {
+    let mut index = get_escrow_expiry_index(env);
+    if let Some(pos) = index.iter().position(|(_, id)| id == escrow_id) {
+        index.remove(pos as u32);
+        set_escrow_expiry_index(env, &index);
+    }
+}
+
+pub fn get_escrows_ready_for_release(env: &Env) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&EscrowKey::ReadyForRelease)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn get_time_based_escrows(env: &Env) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&EscrowKey::TimeBased)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn set_time_based_escrows(env: &Env, ids: &Vec<u64>) {
+    let key = EscrowKey::TimeBased;
+    env.storage().persistent().set(&key, ids);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+fn set_escrows_ready_for_release(env: &Env, ready: &Vec<u64>) {
+    let key = EscrowKey::ReadyForRelease;
+    env.storage().persistent().set(&key, ready);
     env.storage()
         .persistent()
         .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, PERSISTENT_TTL);
 }
 
+/// Ledgers a released or refunded escrow stays in the party indexes before
+/// `compact_escrow_index` may drop it. Defaults to 30 days.
+pub fn get_escrow_index_retention(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&EscrowKey::IndexRetention)
+        .unwrap_or(DAY_IN_LEDGERS as u64 * 30)
+}
+
+pub fn set_escrow_index_retention(env: &Env, ledgers: u64) {
+    env.storage()
+        .instance()
+        .set(&EscrowKey::IndexRetention, &ledgers);
+}
+
 // ============================================================================
 // Batch Transactions
 // ============================================================================
@@ -103,7 +103,9 @@ fn test_audit_trail_hash_chain() {
 
     let (client, admin, signer1, user) = setup_test_environment(&env);
 
-    let token = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
 
     let proposal_id = client.propose_transfer(
         &signer1,
@@ -148,7 +150,9 @@ fn test_audit_trail_core_actions() {
 
     let (client, admin, signer1, user) = setup_test_environment(&env);
 
-    let token = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
 
     let proposal_id = client.propose_transfer(
         &signer1,
@@ -4,7 +4,7 @@ use crate::{InitConfig, VaultDAO, VaultDAOClient};
 use soroban_sdk::{
     testutils::{Address as _, Events, Ledger},
     token::StellarAssetClient,
-    Env, Vec,
+    Env, Symbol, Vec,
 };
 
 fn default_init_config(env: &Env, admin: &Address) -> InitConfig {
@@ -39,6 +39,82 @@ fn default_init_config(env: &Env, admin: &Address) -> InitConfig {
     }
 }
 
+// ============================================================================
+// Fault injection
+// ============================================================================
+//
+// Counters live in the vault's instance storage so that `token.rs` and the
+// oracle lookup can consult them from inside a contract invocation. A forced
+// failure is only consumed if the surrounding invocation commits.
+
+fn forced_transfer_key(env: &Env, token: &Address) -> (Symbol, Address) {
+    (Symbol::new(env, "force_xfer"), token.clone())
+}
+
+fn oracle_outage_key(env: &Env, asset: &Address) -> (Symbol, Address) {
+    (Symbol::new(env, "force_oracle"), asset.clone())
+}
+
+/// Make the next `count` vault transfers of `token` fail.
+pub(crate) fn set_force_transfer_failure(
+    env: &Env,
+    contract_id: &Address,
+    token: &Address,
+    count: u32,
+) {
+    env.as_contract(contract_id, || {
+        env.storage()
+            .instance()
+            .set(&forced_transfer_key(env, token), &count);
+    });
+}
+
+/// Make every price lookup for `asset` behave as if the oracle were unreachable.
+pub(crate) fn set_force_oracle_outage(env: &Env, contract_id: &Address, asset: &Address) {
+    env.as_contract(contract_id, || {
+        env.storage()
+            .instance()
+            .set(&oracle_outage_key(env, asset), &true);
+    });
+}
+
+/// Restore normal price lookups for `asset`.
+pub(crate) fn clear_force_oracle_outage(env: &Env, contract_id: &Address, asset: &Address) {
+    env.as_contract(contract_id, || {
+        env.storage()
+            .instance()
+            .remove(&oracle_outage_key(env, asset));
+    });
+}
+
+/// Drop a persistent entry as if its TTL had lapsed and it had been archived.
+pub(crate) fn evict_persistent_entry<K>(env: &Env, contract_id: &Address, key: &K)
+where
+    K: soroban_sdk::IntoVal<Env, soroban_sdk::Val>,
+{
+    env.as_contract(contract_id, || {
+        env.storage().persistent().remove(key);
+    });
+}
+
+/// Consume one forced failure for `token`, if any are pending.
+pub(crate) fn take_forced_transfer_failure(env: &Env, token: &Address) -> bool {
+    let key = forced_transfer_key(env, token);
+    let remaining: u32 = env.storage().instance().get(&key).unwrap_or(0);
+    if remaining == 0 {
+        return false;
+    }
+    env.storage().instance().set(&key, &(remaining - 1));
+    true
+}
+
+pub(crate) fn is_oracle_outage_forced(env: &Env, asset: &Address) -> bool {
+    env.storage()
+        .instance()
+        .get(&oracle_outage_key(env, asset))
+        .unwrap_or(false)
+}
+
 #[test]
 fn test_register_pre_hook() {
     let env = Env::default();
@@ -382,3 +458,656 @@ fn test_failing_hook_halts_execution() {
     client.register_pre_hook(&admin, &hook_id);
     client.execute_proposal(&admin, &proposal_id);
 }
+
+fn setup_retry_vault(env: &Env) -> (VaultDAOClient<'_>, Address, Address, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let mut config = default_init_config(env, &admin);
+    config.retry_config = RetryConfig {
+        enabled: true,
+        max_retries: 5,
+        initial_backoff_ledgers: 10,
+    };
+    client.initialize(&admin, &config);
+
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    StellarAssetClient::new(env, &token).mint(&contract_id, &1000);
+
+    (client, contract_id, admin, token)
+}
+
+#[test]
+fn test_forced_transfer_failures_back_off_then_succeed() {
+    let env = Env::default();
+    let (client, contract_id, admin, token) = setup_retry_vault(&env);
+    let recipient = Address::generate(&env);
+
+    let proposal_id = client.propose_transfer(
+        &admin,
+        &recipient,
+        &token,
+        &100i128,
+        &Symbol::new(&env, "retry"),
+        &crate::types::Priority::Normal,
+        &Vec::new(&env),
+        &crate::types::ConditionLogic::And,
+        &0i128,
+    );
+    client.approve_proposal(&admin, &proposal_id);
+
+    set_force_transfer_failure(&env, &contract_id, &token, 3);
+
+    let mut expected_backoff = 10u64;
+    for attempt in 1..=3u32 {
+        client.execute_proposal(&admin, &proposal_id);
+
+        let state = client.get_retry_state(&proposal_id).unwrap();
+        let now = env.ledger().sequence() as u64;
+        assert_eq!(state.retry_count, attempt);
+        assert_eq!(state.next_retry_ledger, now + expected_backoff);
+
+        // Retrying inside the backoff window is refused
+        let res = client.try_execute_proposal(&admin, &proposal_id);
+        assert_eq!(res.err(), Some(Ok(VaultError::RetryError)));
+
+        env.ledger()
+            .set_sequence_number(state.next_retry_ledger as u32);
+        expected_backoff *= 2;
+    }
+
+    client.execute_proposal(&admin, &proposal_id);
+
+    let proposal = client.get_proposal(&proposal_id);
+    assert_eq!(proposal.status, crate::types::ProposalStatus::Executed);
+    assert_eq!(
+        soroban_sdk::token::Client::new(&env, &token).balance(&recipient),
+        100
+    );
+    assert!(client.get_retry_state(&proposal_id).is_none());
+}
+
+#[test]
+fn test_failed_attempts_do_not_charge_execution_fee() {
+    let env = Env::default();
+    let (client, contract_id, admin, token) = setup_retry_vault(&env);
+    let recipient = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let token_client = soroban_sdk::token::Client::new(&env, &token);
+
+    client.set_fee_structure(
+        &admin,
+        &crate::types::FeeStructure {
+            tiers: Vec::new(&env),
+            base_fee_bps: 100,
+            reputation_discount_threshold: 1_000,
+            reputation_discount_percentage: 0,
+            treasury: treasury.clone(),
+            enabled: true,
+        },
+    );
+
+    let proposal_id = client.propose_transfer(
+        &admin,
+        &recipient,
+        &token,
+        &100i128,
+        &Symbol::new(&env, "fee"),
+        &crate::types::Priority::Normal,
+        &Vec::new(&env),
+        &crate::types::ConditionLogic::And,
+        &0i128,
+    );
+    client.approve_proposal(&admin, &proposal_id);
+
+    set_force_transfer_failure(&env, &contract_id, &token, 2);
+    for attempt in 1..=2u32 {
+        client.execute_proposal(&admin, &proposal_id);
+        let state = client.get_retry_state(&proposal_id).unwrap();
+        assert_eq!(state.retry_count, attempt);
+        assert_eq!(token_client.balance(&treasury), 0);
+        env.ledger()
+            .set_sequence_number(state.next_retry_ledger as u32);
+    }
+
+    // The fee is charged once, with the payout that succeeded
+    client.execute_proposal(&admin, &proposal_id);
+    assert_eq!(token_client.balance(&recipient), 100);
+    assert_eq!(token_client.balance(&treasury), 1);
+}
+
+#[test]
+fn test_forced_split_transfer_failure_rolls_back() {
+    let env = Env::default();
+    let (client, contract_id, admin, token) = setup_retry_vault(&env);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+
+    let mut splits = Vec::new(&env);
+    splits.push_back((first.clone(), 40i128));
+    splits.push_back((second.clone(), 60i128));
+    let proposal_id = client.propose_split_transfer(
+        &admin,
+        &splits,
+        &token,
+        &Symbol::new(&env, "split"),
+        &crate::types::Priority::Normal,
+        &0i128,
+    );
+    client.approve_proposal(&admin, &proposal_id);
+
+    set_force_transfer_failure(&env, &contract_id, &token, 1);
+
+    let res = client.try_execute_proposal(&admin, &proposal_id);
+    assert_eq!(res.err(), Some(Ok(VaultError::TransferFailed)));
+    assert!(client.get_retry_state(&proposal_id).is_none());
+    assert_eq!(
+        soroban_sdk::token::Client::new(&env, &token).balance(&first),
+        0
+    );
+}
+
+#[test]
+fn test_forced_oracle_outage_fails_price_conditions() {
+    let env = Env::default();
+    let (client, contract_id, admin, token) = setup_retry_vault(&env);
+    let asset = Address::generate(&env);
+
+    client.update_oracle_config(
+        &admin,
+        &crate::VaultOracleConfig {
+            address: Address::generate(&env),
+            base_symbol: Symbol::new(&env, "USD"),
+            max_staleness: 100,
+        },
+    );
+    set_force_oracle_outage(&env, &contract_id, &asset);
+
+    let res = client.try_get_asset_price(&asset);
+    assert_eq!(res.err(), Some(Ok(VaultError::RetryError)));
+
+    let mut conditions = Vec::new(&env);
+    conditions.push_back(crate::types::Condition::PriceAbove(asset.clone(), 1));
+    let proposal_id = client.propose_transfer(
+        &admin,
+        &Address::generate(&env),
+        &token,
+        &100i128,
+        &Symbol::new(&env, "oracle"),
+        &crate::types::Priority::Normal,
+        &conditions,
+        &crate::types::ConditionLogic::And,
+        &0i128,
+    );
+    client.approve_proposal(&admin, &proposal_id);
+
+    let res = client.try_execute_proposal(&admin, &proposal_id);
+    assert_eq!(res.err(), Some(Ok(VaultError::ProposalNotApproved)));
+
+    clear_force_oracle_outage(&env, &contract_id, &asset);
+    assert!(!env.as_contract(&contract_id, || is_oracle_outage_forced(&env, &asset)));
+}
+
+#[test]
+fn test_archived_records_are_reported_separately() {
+    use crate::types::RecordKind;
+
+    let env = Env::default();
+    let (client, contract_id, admin, token) = setup_retry_vault(&env);
+
+    let proposal_id = client.propose_transfer(
+        &admin,
+        &Address::generate(&env),
+        &token,
+        &100i128,
+        &Symbol::new(&env, "evict"),
+        &crate::types::Priority::Normal,
+        &Vec::new(&env),
+        &crate::types::ConditionLogic::And,
+        &0i128,
+    );
+    let comment_id = client.add_comment(
+        &admin,
+        &proposal_id,
+        &Symbol::new(&env, "hi"),
+        &0,
+        &Vec::new(&env),
+    );
+
+    // Issue a stream and an escrow ID without keeping their records.
+    let (stream_id, escrow_id) = env.as_contract(&contract_id, || {
+        (
+            storage::increment_stream_id(&env),
+            storage::increment_escrow_id(&env),
+        )
+    });
+
+    assert!(!client.is_record_archived(&RecordKind::Proposal, &proposal_id));
+    evict_persistent_entry(&env, &contract_id, &storage::DataKey::Proposal(proposal_id));
+    evict_persistent_entry(&env, &contract_id, &storage::DataKey::Comment(comment_id));
+
+    // Getters report both cases as not found
+    let res = client.try_get_proposal(&proposal_id);
+    assert_eq!(res.err(), Some(Ok(VaultError::ProposalNotFound)));
+    let res = client.try_get_stream(&stream_id);
+    assert_eq!(res.err(), Some(Ok(VaultError::ProposalNotFound)));
+    let res = client.try_get_escrow_info(&escrow_id);
+    assert_eq!(res.err(), Some(Ok(VaultError::ProposalNotFound)));
+
+    // The archive view tells issued IDs apart from unknown ones
+    for (kind, id) in [
+        (RecordKind::Proposal, proposal_id),
+        (RecordKind::Comment, comment_id),
+        (RecordKind::Stream, stream_id),
+        (RecordKind::Escrow, escrow_id),
+    ] {
+        assert!(client.is_record_archived(&kind, &id));
+        assert!(!client.is_record_archived(&kind, &(id + 1)));
+        assert!(!client.is_record_archived(&kind, &0));
+    }
+}
+
+#[test]
+fn test_metrics_track_retries_cancellations_and_disputes() {
+    let env = Env::default();
+    let (client, contract_id, admin, token) = setup_retry_vault(&env);
+    let recipient = Address::generate(&env);
+
+    let propose = |memo: &str| {
+        client.propose_transfer(
+            &admin,
+            &recipient,
+            &token,
+            &100i128,
+            &Symbol::new(&env, memo),
+            &crate::types::Priority::Normal,
+            &Vec::new(&env),
+            &crate::types::ConditionLogic::And,
+            &0i128,
+        )
+    };
+
+    // One failed attempt, then a successful retry
+    let retried = propose("retry");
+    client.approve_proposal(&admin, &retried);
+    set_force_transfer_failure(&env, &contract_id, &token, 1);
+    client.execute_proposal(&admin, &retried);
+    let state = client.get_retry_state(&retried).unwrap();
+    env.ledger()
+        .set_sequence_number(state.next_retry_ledger as u32);
+    client.execute_proposal(&admin, &retried);
+
+    let cancelled = propose("cancel");
+    client.cancel_proposal(&admin, &cancelled, &Symbol::new(&env, "dup"));
+
+    // Two disputes: one released to the recipient, one refunded to the funder
+    let arbitrator = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&admin, &100);
+    let milestones = Vec::from_array(
+        &env,
+        [crate::types::Milestone {
+            id: 1,
+            percentage: 100,
+            release_ledger: env.ledger().sequence() as u64,
+            is_completed: false,
+            completion_ledger: 0,
+        }],
+    );
+    for release in [true, false] {
+        let escrow_id = client.create_escrow(
+            &admin,
+            &recipient,
+            &token,
+            &50i128,
+            &milestones,
+            &100u64,
+            &arbitrator,
+        );
+        client.dispute_escrow(&admin, &escrow_id, &Symbol::new(&env, "late"));
+        client.resolve_escrow_dispute(&arbitrator, &escrow_id, &release);
+    }
+
+    let metrics = client.get_metrics();
+    assert_eq!(metrics.executed_count, 1);
+    assert_eq!(metrics.cancelled_count, 1);
+    assert_eq!(metrics.retry_scheduled_count, 1);
+    assert_eq!(metrics.retry_succeeded_count, 1);
+    assert_eq!(metrics.disputes_filed, 2);
+    assert_eq!(metrics.disputes_upheld, 1);
+    assert_eq!(metrics.retry_success_rate_bps(), 10_000);
+    assert_eq!(metrics.dispute_uphold_rate_bps(), 5_000);
+    assert_eq!(metrics.cancellation_rate_bps(), 5_000);
+    assert_eq!(metrics.success_rate_bps(), 5_000);
+}
+
+#[test]
+fn test_vault_health_reports_each_category() {
+    let env = Env::default();
+    let (client, contract_id, admin, token) = setup_retry_vault(&env);
+    let recipient = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&admin, &1000);
+    env.ledger().set_sequence_number(100);
+
+    let propose = |memo: &str| {
+        client.propose_transfer(
+            &admin,
+            &recipient,
+            &token,
+            &50i128,
+            &Symbol::new(&env, memo),
+            &crate::types::Priority::Normal,
+            &Vec::new(&env),
+            &crate::types::ConditionLogic::And,
+            &0i128,
+        )
+    };
+
+    let stale = propose("stale");
+
+    // Approved, then fails once so a retry is scheduled
+    let retried = propose("retry");
+    client.approve_proposal(&admin, &retried);
+    set_force_transfer_failure(&env, &contract_id, &token, 1);
+    client.execute_proposal(&admin, &retried);
+
+    let payment_id = client.schedule_payment(
+        &admin,
+        &recipient,
+        &token,
+        &10i128,
+        &Symbol::new(&env, "rent"),
+        &1000u64,
+    );
+    let stream_id = client.create_stream(&admin, &recipient, &token, &100i128, &60u64);
+    let escrow_id = client.create_escrow(
+        &admin,
+        &recipient,
+        &token,
+        &100i128,
+        &Vec::from_array(
+            &env,
+            [crate::types::Milestone {
+                id: 1,
+                percentage: 100,
+                release_ledger: env.ledger().sequence() as u64,
+                is_completed: false,
+                completion_ledger: 0,
+            }],
+        ),
+        &1000u64,
+        &Address::generate(&env),
+    );
+
+    let health = client.get_vault_health();
+    assert_eq!(health.stale_pending.count, 0);
+    assert_eq!(health.due_recurring.count, 0);
+    assert_eq!(health.vested_streams.count, 0);
+    assert_eq!(health.due_retries.count, 0);
+    assert_eq!(health.expired_escrows.count, 0);
+    assert_eq!(health.executable_approved.count, 1);
+
+    // Past half of the proposal lifetime everything else has come due too
+    env.ledger().with_mut(|li| {
+        li.sequence_number += 70_000;
+        li.timestamp += 70_000 * 5;
+    });
+
+    let health = client.get_vault_health();
+    assert_eq!(health.checked_at, 70_100);
+    assert_eq!(
+        health.stale_pending.sample_ids,
+        Vec::from_array(&env, [stale])
+    );
+    assert_eq!(
+        health.executable_approved.sample_ids,
+        Vec::from_array(&env, [retried])
+    );
+    assert_eq!(
+        health.due_retries.sample_ids,
+        Vec::from_array(&env, [retried])
+    );
+    assert_eq!(
+        health.due_recurring.sample_ids,
+        Vec::from_array(&env, [payment_id])
+    );
+    assert_eq!(
+        health.vested_streams.sample_ids,
+        Vec::from_array(&env, [stream_id])
+    );
+    assert_eq!(
+        health.expired_escrows.sample_ids,
+        Vec::from_array(&env, [escrow_id])
+    );
+}
+
+#[test]
+fn test_retry_refused_after_threshold_raised() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let signer2 = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    let mut config = default_init_config(&env, &admin);
+    config.signers = Vec::from_array(&env, [admin.clone(), signer1.clone(), signer2.clone()]);
+    config.threshold = 2;
+    config.retry_config = RetryConfig {
+        enabled: true,
+        max_retries: 5,
+        initial_backoff_ledgers: 10,
+    };
+    client.initialize(&admin, &config);
+    client.set_role(&admin, &signer1, &crate::types::Role::Treasurer);
+    client.set_role(&admin, &signer2, &crate::types::Role::Treasurer);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &token).mint(&contract_id, &1000);
+
+    let proposal_id = client.propose_transfer(
+        &admin,
+        &recipient,
+        &token,
+        &100i128,
+        &Symbol::new(&env, "retry"),
+        &crate::types::Priority::Normal,
+        &Vec::new(&env),
+        &crate::types::ConditionLogic::And,
+        &0i128,
+    );
+    client.approve_proposal(&signer1, &proposal_id);
+    client.approve_proposal(&signer2, &proposal_id);
+
+    set_force_transfer_failure(&env, &contract_id, &token, 1);
+    client.execute_proposal(&admin, &proposal_id);
+    let state = client.get_retry_state(&proposal_id).unwrap();
+
+    // Proposals created before vote snapshots follow the live threshold.
+    evict_persistent_entry(
+        &env,
+        &contract_id,
+        &storage::ProposalKey::VoteSnapshot(proposal_id),
+    );
+    client.update_threshold(&admin, &3);
+    env.ledger()
+        .set_sequence_number(state.next_retry_ledger as u32);
+    client.execute_proposal(&admin, &proposal_id);
+
+    let proposal = client.get_proposal(&proposal_id);
+    assert_eq!(proposal.status, crate::types::ProposalStatus::Rejected);
+    assert!(client.get_retry_state(&proposal_id).is_none());
+    assert_eq!(
+        soroban_sdk::token::Client::new(&env, &token).balance(&recipient),
+        0
+    );
+    assert_eq!(
+        client.try_execute_proposal(&admin, &proposal_id).err(),
+        Some(Ok(VaultError::ProposalNotApproved))
+    );
+}
+
+fn count_hook_events(env: &Env, phase: Symbol) -> u32 {
+    use soroban_sdk::IntoVal;
+    let hook: soroban_sdk::Val = soroban_sdk::symbol_short!("hook").into_val(env);
+    let phase: soroban_sdk::Val = phase.into_val(env);
+    let mut count = 0;
+    for (_, topics, _) in env.events().all().iter() {
+        if topics.len() > 1
+            && topics.get(0).unwrap().get_payload() == hook.get_payload()
+            && topics.get(1).unwrap().get_payload() == phase.get_payload()
+        {
+            count += 1;
+        }
+    }
+    count
+}
+
+#[test]
+fn test_hooks_run_once_per_execution() {
+    let env = Env::default();
+    let (client, admin, _, _, proposal_id) = setup_execution_test(&env);
+    let hook_id = env.register(mock_hook::MockHook, ());
+
+    client.register_pre_hook(&admin, &hook_id);
+    client.register_post_hook(&admin, &hook_id);
+    client.execute_proposal(&admin, &proposal_id);
+
+    assert_eq!(
+        count_hook_events(&env, soroban_sdk::symbol_short!("pre")),
+        1
+    );
+    assert_eq!(
+        count_hook_events(&env, soroban_sdk::symbol_short!("post")),
+        1
+    );
+}
+
+#[test]
+fn test_retry_execution_honors_backoff() {
+    let env = Env::default();
+    let (client, contract_id, admin, token) = setup_retry_vault(&env);
+    let recipient = Address::generate(&env);
+
+    let proposal_id = client.propose_transfer(
+        &admin,
+        &recipient,
+        &token,
+        &100i128,
+        &Symbol::new(&env, "retry"),
+        &crate::types::Priority::Normal,
+        &Vec::new(&env),
+        &crate::types::ConditionLogic::And,
+        &0i128,
+    );
+    client.approve_proposal(&admin, &proposal_id);
+
+    // Nothing to retry before a failed attempt
+    assert_eq!(
+        client.try_retry_execution(&admin, &proposal_id).err(),
+        Some(Ok(VaultError::RetryError))
+    );
+
+    set_force_transfer_failure(&env, &contract_id, &token, 1);
+    client.execute_proposal(&admin, &proposal_id);
+    let state = client.get_retry_state(&proposal_id).unwrap();
+
+    env.ledger()
+        .set_sequence_number(state.next_retry_ledger as u32 - 1);
+    assert_eq!(
+        client.try_retry_execution(&admin, &proposal_id).err(),
+        Some(Ok(VaultError::RetryError))
+    );
+
+    env.ledger()
+        .set_sequence_number(state.next_retry_ledger as u32);
+    client.retry_execution(&admin, &proposal_id);
+
+    use soroban_sdk::TryFromVal;
+    let attempted = env.events().all().iter().any(|(_, topics, _)| {
+        topics
+            .get(0)
+            .and_then(|t| Symbol::try_from_val(&env, &t).ok())
+            == Some(Symbol::new(&env, "retry_attempted"))
+    });
+    assert!(attempted);
+    assert_eq!(
+        client.get_proposal(&proposal_id).status,
+        crate::types::ProposalStatus::Executed
+    );
+    assert_eq!(
+        soroban_sdk::token::Client::new(&env, &token).balance(&recipient),
+        100
+    );
+}
+
+#[test]
+fn test_repeated_failures_freeze_until_unpaused() {
+    let env = Env::default();
+    let (client, contract_id, admin, token) = setup_retry_vault(&env);
+    let recipient = Address::generate(&env);
+
+    client.set_auto_freeze_config(
+        &admin,
+        &crate::types::AutoFreezeConfig {
+            threshold: 3,
+            window_ledgers: 100,
+        },
+    );
+
+    let mut ids = Vec::new(&env);
+    for _ in 0..5 {
+        let proposal_id = client.propose_transfer(
+            &admin,
+            &recipient,
+            &token,
+            &100i128,
+            &Symbol::new(&env, "freeze"),
+            &crate::types::Priority::Normal,
+            &Vec::new(&env),
+            &crate::types::ConditionLogic::And,
+            &0i128,
+        );
+        client.approve_proposal(&admin, &proposal_id);
+        ids.push_back(proposal_id);
+    }
+
+    // Four retryable failures inside the window trip the breaker.
+    set_force_transfer_failure(&env, &contract_id, &token, 4);
+    for i in 0..4 {
+        assert!(!client.is_paused());
+        client.execute_proposal(&admin, &ids.get(i).unwrap());
+    }
+
+    use soroban_sdk::TryFromVal;
+    let tripped = env.events().all().iter().any(|(_, topics, _)| {
+        topics
+            .get(0)
+            .and_then(|t| Symbol::try_from_val(&env, &t).ok())
+            == Some(Symbol::new(&env, "auto_freeze_triggered"))
+    });
+    assert!(tripped);
+    assert!(client.is_paused());
+
+    let last = ids.get(4).unwrap();
+    assert_eq!(
+        client.try_execute_proposal(&admin, &last).err(),
+        Some(Ok(VaultError::ConditionsNotMet))
+    );
+
+    client.unpause(&admin, &Symbol::new(&env, "rpc_outage"));
+    assert!(!client.is_paused());
+    client.execute_proposal(&admin, &last);
+    assert_eq!(
+        client.get_proposal(&last).status,
+        crate::types::ProposalStatus::Executed
+    );
+}
@@ -1,5 +1,6 @@
 use crate::types::{
-    Condition, ConditionLogic, Priority, RetryConfig, ThresholdStrategy, VelocityConfig,
+    Condition, ConditionLogic, IncidentKind, Priority, RetryConfig, ThresholdStrategy,
+    VelocityConfig,
 };
 use crate::{InitConfig, Role, VaultDAO, VaultDAOClient};
 use soroban_sdk::{
@@ -347,7 +348,7 @@ fn test_recurring_payment_execute_due_success() {
     });
 
     // Execute recurring payment - should succeed
-    client.execute_recurring_payment(&payment_id);
+    client.execute_recurring_payment(&admin, &payment_id);
 
     // Verify payment was executed
     let payment_after = client.get_recurring_payment(&payment_id);
@@ -613,7 +614,7 @@ fn test_recurring_payment_multiple_executions() {
         });
 
         // Execute recurring payment
-        client.execute_recurring_payment(&payment_id);
+        client.execute_recurring_payment(&admin, &payment_id);
 
         // Verify payment count incremented
         let payment_after = client.get_recurring_payment(&payment_id);
@@ -625,3 +626,340 @@ fn test_recurring_payment_multiple_executions() {
     let recipient_balance = balance_client.balance(&recipient);
     assert_eq!(recipient_balance, amount * 3);
 }
+
+/// Test: keepers discover due payments and bulk-execute only those.
+#[test]
+fn test_execute_due_payments_runs_only_due_ones() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &default_init_config(&env, &admin));
+
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    StellarAssetClient::new(&env, &token).mint(&contract_id, &1000);
+
+    let recipient = Address::generate(&env);
+    let memo = Symbol::new(&env, "payroll");
+
+    // Scheduled out of due order on purpose: due at 2000, 720, 1000
+    let late = client.schedule_payment(&admin, &recipient, &token, &100i128, &memo, &2000u64);
+    let first = client.schedule_payment(&admin, &recipient, &token, &100i128, &memo, &720u64);
+    let second = client.schedule_payment(&admin, &recipient, &token, &100i128, &memo, &1000u64);
+
+    assert_eq!(client.get_due_recurring_payments(&10u32).len(), 0);
+
+    env.ledger().set_sequence_number(1100);
+    let due = client.get_due_recurring_payments(&10u32);
+    assert_eq!(due.len(), 2);
+    assert_eq!(due.get(0).unwrap(), first);
+    assert_eq!(due.get(1).unwrap(), second);
+    assert_eq!(client.get_due_recurring_payments(&1u32).len(), 1);
+
+    let outcomes = client.execute_due_payments(&admin, &10u32);
+    assert_eq!(outcomes.len(), 2);
+    assert_eq!(outcomes.get(0).unwrap(), (first, true));
+    assert_eq!(outcomes.get(1).unwrap(), (second, true));
+
+    assert_eq!(client.get_recurring_payment(&first).payment_count, 1);
+    assert_eq!(client.get_recurring_payment(&second).payment_count, 1);
+    assert_eq!(client.get_recurring_payment(&late).payment_count, 0);
+    assert_eq!(
+        soroban_sdk::token::Client::new(&env, &token).balance(&recipient),
+        200
+    );
+
+    // Executed payments were rescheduled past the current ledger
+    assert_eq!(client.get_due_recurring_payments(&10u32).len(), 0);
+}
+
+/// Test: a payment that cannot be funded is skipped without aborting the run.
+#[test]
+fn test_execute_due_payments_skips_failures() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &default_init_config(&env, &admin));
+
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    StellarAssetClient::new(&env, &token).mint(&contract_id, &150);
+
+    let recipient = Address::generate(&env);
+    let memo = Symbol::new(&env, "payroll");
+    let big = client.schedule_payment(&admin, &recipient, &token, &500i128, &memo, &720u64);
+    let small = client.schedule_payment(&admin, &recipient, &token, &100i128, &memo, &800u64);
+
+    env.ledger().set_sequence_number(900);
+    let outcomes = client.execute_due_payments(&admin, &10u32);
+    assert_eq!(outcomes.get(0).unwrap(), (big, false));
+    assert_eq!(outcomes.get(1).unwrap(), (small, true));
+
+    // The unfunded payment stays due for the next keeper run
+    let due = client.get_due_recurring_payments(&10u32);
+    assert_eq!(due.len(), 1);
+    assert_eq!(due.get(0).unwrap(), big);
+}
+
+/// Test: any keeper may execute when no allow-list is set, and is credited.
+#[test]
+fn test_recurring_payment_open_executor() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &default_init_config(&env, &admin));
+
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    StellarAssetClient::new(&env, &token).mint(&contract_id, &1000);
+
+    let recipient = Address::generate(&env);
+    let memo = Symbol::new(&env, "payroll");
+    let payment_id = client.schedule_payment(&admin, &recipient, &token, &100i128, &memo, &720u64);
+    assert_eq!(client.get_recurring_executors(&payment_id).len(), 0);
+
+    let keeper = Address::generate(&env);
+    env.ledger().set_sequence_number(800);
+    client.execute_recurring_payment(&keeper, &payment_id);
+
+    assert_eq!(client.get_recurring_payment(&payment_id).payment_count, 1);
+    let rep = client.get_reputation(&keeper);
+    assert_eq!(rep.participation_count, 1);
+    assert_eq!(rep.last_participation_ledger, 800);
+    assert_eq!(client.get_metrics().executed_count, 1);
+}
+
+/// Test: an allow-list restricts execution to listed keepers and admins.
+#[test]
+fn test_recurring_payment_restricted_executors() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &default_init_config(&env, &admin));
+
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    StellarAssetClient::new(&env, &token).mint(&contract_id, &1000);
+
+    let recipient = Address::generate(&env);
+    let memo = Symbol::new(&env, "payroll");
+    let payment_id = client.schedule_payment(&admin, &recipient, &token, &100i128, &memo, &720u64);
+
+    let keeper = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let mut executors = Vec::new(&env);
+    executors.push_back(keeper.clone());
+
+    // Only the proposer or an admin can set the list.
+    let res = client.try_set_recurring_executors(&stranger, &payment_id, &executors);
+    assert_eq!(res.err(), Some(Ok(crate::VaultError::Unauthorized)));
+    client.set_recurring_executors(&admin, &payment_id, &executors);
+    assert_eq!(client.get_recurring_executors(&payment_id), executors);
+
+    env.ledger().set_sequence_number(800);
+    let res = client.try_execute_recurring_payment(&stranger, &payment_id);
+    assert_eq!(res.err(), Some(Ok(crate::VaultError::Unauthorized)));
+    let outcomes = client.execute_due_payments(&stranger, &10u32);
+    assert_eq!(outcomes.get(0).unwrap(), (payment_id, false));
+
+    client.execute_recurring_payment(&keeper, &payment_id);
+    assert_eq!(client.get_recurring_payment(&payment_id).payment_count, 1);
+
+    // Clearing the list reopens execution to anyone.
+    client.set_recurring_executors(&admin, &payment_id, &Vec::new(&env));
+    env.ledger().set_sequence_number(1600);
+    client.execute_recurring_payment(&stranger, &payment_id);
+    assert_eq!(client.get_recurring_payment(&payment_id).payment_count, 2);
+}
+
+pub(crate) mod mock_oracle {
+    use crate::types::VaultPriceData;
+    use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env};
+
+    #[contract]
+    pub struct MockOracle;
+
+    #[contractimpl]
+    impl MockOracle {
+        pub fn set_price(env: Env, price: i128) {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("price"), &price);
+        }
+
+        pub fn lastprice(env: Env, _asset: Address) -> Option<VaultPriceData> {
+            let price: i128 = env.storage().instance().get(&symbol_short!("price"))?;
+            Some(VaultPriceData {
+                price,
+                timestamp: env.ledger().sequence() as u64,
+            })
+        }
+    }
+}
+
+/// Test: a conditional payment pays only in periods where the price condition holds.
+#[test]
+fn test_recurring_payment_conditions_skip_or_pay() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &default_init_config(&env, &admin));
+
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    StellarAssetClient::new(&env, &token).mint(&contract_id, &1000);
+
+    let oracle_id = env.register(mock_oracle::MockOracle, ());
+    let oracle = mock_oracle::MockOracleClient::new(&env, &oracle_id);
+    client.update_oracle_config(
+        &admin,
+        &crate::VaultOracleConfig {
+            address: oracle_id.clone(),
+            base_symbol: Symbol::new(&env, "USD"),
+            max_staleness: 100,
+        },
+    );
+
+    let recipient = Address::generate(&env);
+    let mut conditions = Vec::new(&env);
+    conditions.push_back(Condition::PriceAbove(token.clone(), 500));
+    let payment_id = client.schedule_conditional_payment(
+        &admin,
+        &recipient,
+        &token,
+        &100i128,
+        &Symbol::new(&env, "mm_fee"),
+        &720u64,
+        &conditions,
+        &ConditionLogic::And,
+    );
+    assert_eq!(
+        client.get_recurring_conditions(&payment_id),
+        (conditions.clone(), ConditionLogic::And)
+    );
+    let balance = |who: &Address| soroban_sdk::token::Client::new(&env, &token).balance(who);
+
+    // Price below the threshold: the period is skipped, one interval at a time.
+    oracle.set_price(&400);
+    env.ledger().set_sequence_number(2000);
+    client.execute_recurring_payment(&admin, &payment_id);
+    let payment = client.get_recurring_payment(&payment_id);
+    assert_eq!(payment.payment_count, 0);
+    assert_eq!(payment.next_payment_ledger, 1440);
+    assert_eq!(balance(&recipient), 0);
+
+    let outcomes = client.execute_due_payments(&admin, &10u32);
+    assert_eq!(outcomes.get(0).unwrap(), (payment_id, false));
+    assert_eq!(
+        client
+            .get_recurring_payment(&payment_id)
+            .next_payment_ledger,
+        2160
+    );
+
+    // Price back above the threshold: the next due period pays.
+    oracle.set_price(&600);
+    env.ledger().set_sequence_number(2200);
+    client.execute_recurring_payment(&admin, &payment_id);
+    let payment = client.get_recurring_payment(&payment_id);
+    assert_eq!(payment.payment_count, 1);
+    assert_eq!(payment.next_payment_ledger, 2880);
+    assert_eq!(balance(&recipient), 100);
+}
+
+/// Test: flagging an incident stops a live recurring payment and keeps the
+/// amount already paid until it is resolved.
+#[test]
+fn test_flag_payment_incident_stops_recurring_payment() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VaultDAO, ());
+    let client = VaultDAOClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let treasurer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    client.initialize(&admin, &default_init_config(&env, &admin));
+    client.set_role(&admin, &treasurer, &Role::Treasurer);
+
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    StellarAssetClient::new(&env, &token).mint(&contract_id, &10000);
+
+    let payment_id = client.schedule_payment(
+        &treasurer,
+        &recipient,
+        &token,
+        &100i128,
+        &Symbol::new(&env, "payroll"),
+        &1000u64,
+    );
+    let first_due = client
+        .get_recurring_payment(&payment_id)
+        .next_payment_ledger;
+    env.ledger().set_sequence_number(first_due as u32);
+    client.execute_recurring_payment(&treasurer, &payment_id);
+
+    let incident_id = client.flag_payment_incident(
+        &admin,
+        &IncidentKind::RecurringPayment,
+        &payment_id,
+        &Symbol::new(&env, "wrong_addr"),
+    );
+
+    let incident = client.get_incident(&incident_id);
+    assert_eq!(incident.target_id, payment_id);
+    assert_eq!(incident.recipient, recipient);
+    assert_eq!(incident.amount_paid, 100);
+    assert_eq!(incident.resolved_at, 0);
+    assert!(!client.get_recurring_payment(&payment_id).is_active);
+    assert_eq!(client.list_open_incidents().len(), 1);
+
+    // The next period is due but the payment no longer executes.
+    env.ledger().set_sequence_number(first_due as u32 + 1000);
+    assert!(client
+        .try_execute_recurring_payment(&treasurer, &payment_id)
+        .is_err());
+
+    client.resolve_incident(&admin, &incident_id, &Symbol::new(&env, "refunded"));
+    let incident = client.get_incident(&incident_id);
+    assert_eq!(incident.resolution, Symbol::new(&env, "refunded"));
+    assert!(incident.resolved_at > 0);
+    assert_eq!(client.list_open_incidents().len(), 0);
+    assert!(client
+        .try_resolve_incident(&admin, &incident_id, &Symbol::new(&env, "again"))
+        .is_err());
+}
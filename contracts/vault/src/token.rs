@@ -1,26 +1,60 @@
 //! VaultDAO - Token Interface
 //!
 //! Client wrapper for Stellar Asset Contracts (SAC) and custom tokens.
+//! Every movement in or out of the vault goes through here and is added to
+//! the lifetime flow counters.
 
+use crate::storage;
 use soroban_sdk::{token, Address, Env};
 
 /// Transfer tokens from the vault to a recipient
 pub fn transfer(env: &Env, token_addr: &Address, to: &Address, amount: i128) {
+    #[cfg(test)]
+    if crate::test_hooks::take_forced_transfer_failure(env, token_addr) {
+        panic!("forced transfer failure");
+    }
     let client = token::Client::new(env, token_addr);
     let vault_address = env.current_contract_address();
     client.transfer(&vault_address, to, &amount);
+    storage::add_total_outflow(env, token_addr, amount);
 }
 
 /// Attempt to transfer tokens, returning an error instead of panicking on failure
 pub fn try_transfer(env: &Env, token_addr: &Address, to: &Address, amount: i128) -> Result<(), ()> {
+    #[cfg(test)]
+    if crate::test_hooks::take_forced_transfer_failure(env, token_addr) {
+        return Err(());
+    }
     let client = token::Client::new(env, token_addr);
     let vault_address = env.current_contract_address();
     match client.try_transfer(&vault_address, to, &amount) {
-        Ok(Ok(_)) => Ok(()),
+        Ok(Ok(_)) => {
+            storage::add_total_outflow(env, token_addr, amount);
+            Ok(())
+        }
         _ => Err(()),
     }
 }
 
+/// Burn tokens held by the vault, returning an error if the token refuses
+pub fn try_burn(env: &Env, token_addr: &Address, amount: i128) -> Result<(), ()> {
+    let client = token::Client::new(env, token_addr);
+    let vault_address = env.current_contract_address();
+    match client.try_burn(&vault_address, &amount) {
+        Ok(Ok(_)) => {
+            storage::add_total_outflow(env, token_addr, amount);
+            Ok(())
+        }
+        _ => Err(()),
+    }
+}
+
+/// Whether the address answers `decimals()` like a token contract
+pub fn is_token_contract(env: &Env, token_addr: &Address) -> bool {
+    let client = token::Client::new(env, token_addr);
+    matches!(client.try_decimals(), Ok(Ok(_)))
+}
+
 /// Get the vault's balance of a token
 pub fn balance(env: &Env, token_addr: &Address) -> i128 {
     let client = token::Client::new(env, token_addr);
@@ -34,4 +68,39 @@ pub fn transfer_to_vault(env: &Env, token_addr: &Address, from: &Address, amount
     let client = token::Client::new(env, token_addr);
     let vault_address = env.current_contract_address();
     client.transfer(from, &vault_address, &amount);
+    storage::add_total_inflow(env, token_addr, amount);
+}
+
+/// Pull tokens FROM a contributor INTO the vault using the vault's allowance.
+pub fn transfer_from_allowance(env: &Env, token_addr: &Address, from: &Address, amount: i128) {
+    let client = token::Client::new(env, token_addr);
+    let vault_address = env.current_contract_address();
+    client.transfer_from(&vault_address, from, &vault_address, &amount);
+    storage::add_total_inflow(env, token_addr, amount);
+}
+
+/// Attempt to pull tokens via the vault's allowance, returning an error instead
+/// of panicking when the allowance or balance falls short
+pub fn try_transfer_from_allowance(
+    env: &Env,
+    token_addr: &Address,
+    from: &Address,
+    amount: i128,
+) -> Result<(), ()> {
+    let client = token::Client::new(env, token_addr);
+    let vault_address = env.current_contract_address();
+    match client.try_transfer_from(&vault_address, from, &vault_address, &amount) {
+        Ok(Ok(_)) => {
+            storage::add_total_inflow(env, token_addr, amount);
+            Ok(())
+        }
+        _ => Err(()),
+    }
+}
+
+/// Get the allowance `from` has granted the vault for a token
+pub fn allowance(env: &Env, token_addr: &Address, from: &Address) -> i128 {
+    let client = token::Client::new(env, token_addr);
+    let vault_address = env.current_contract_address();
+    client.allowance(from, &vault_address)
 }
@@ -17,7 +17,7 @@
 //!
 //! 4. **Bit Packing**: Boolean flags are combined into a single u8 bitfield where possible.
 
-use soroban_sdk::{contracttype, Address, Env, Map, String, Symbol, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, Env, Map, String, Symbol, Vec};
 
 /// Oracle configuration for price feeds
 #[contracttype]
@@ -124,6 +124,30 @@ pub struct Config {
     /// Recovery configuration
     pub recovery_config: RecoveryConfig,
     pub staking_config: StakingConfig,
+    /// Maximum open (non-terminal) proposals per proposer (0 = unlimited)
+    pub max_pending_per_proposer: u32,
+    /// When set, metrics and volume views require an authenticated caller with
+    /// `Permission::ViewMetrics`
+    pub restrict_metrics: bool,
+    /// When set, user-supplied memos must be on the allowed memo list
+    pub enforce_memo_whitelist: bool,
+}
+
+/// Portable copy of a vault's policy, produced by `export_policy` and applied
+/// to a fresh deployment with `import_policy`
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct PolicyBundle {
+    /// Vault config (limits, timelock, velocity, thresholds, hooks...);
+    /// `signers` is empty and ignored on import
+    pub config: Config,
+    pub insurance: InsuranceConfig,
+    pub insurance_usd: InsuranceUsdThreshold,
+    pub slash_destination: SlashDestination,
+    pub staking: StakingConfig,
+    pub fees: FeeStructure,
+    pub gas: GasConfig,
+    pub list_mode: ListMode,
 }
 
 /// Audit record for a cancelled proposal
@@ -242,6 +266,7 @@ pub enum Permission {
     ManageSubscriptions = 11,
     ViewMetrics = 12,
     ManageRecovery = 13,
+    ManageFunding = 14,
 }
 
 /// Permission grant with optional expiry
@@ -308,6 +333,20 @@ pub enum ProposalStatus {
     Vetoed = 7,
 }
 
+impl ProposalStatus {
+    /// Whether the proposal can no longer be approved or executed
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            ProposalStatus::Executed
+                | ProposalStatus::Rejected
+                | ProposalStatus::Expired
+                | ProposalStatus::Cancelled
+                | ProposalStatus::Vetoed
+        )
+    }
+}
+
 /// Proposal priority level for queue ordering
 #[contracttype]
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -420,6 +459,79 @@ pub struct Proposal {
     pub voting_deadline: u64,
 }
 
+/// Proposer's reputation-adjusted limits frozen when a proposal is approved.
+///
+/// Kept under its own storage key so `Proposal` records stay decodable.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ApprovalLimits {
+    /// Whether reputation raised the proposer's limits
+    pub rep_boosted: bool,
+    /// Per-proposal limit
+    pub spending_limit: i128,
+    /// Daily limit
+    pub daily_limit: i128,
+    /// Weekly limit
+    pub weekly_limit: i128,
+}
+
+/// Optional settings for `propose_transfer_v2`.
+///
+/// Bundles everything beyond the core transfer so new creation options can be
+/// added here instead of growing the entrypoint signature. Start from
+/// `ProposalOptions::default(&env)` and override what is needed.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ProposalOptions {
+    /// Proposal urgency level
+    pub priority: Priority,
+    /// Execution conditions
+    pub conditions: Vec<Condition>,
+    /// Logic operator for combining conditions
+    pub condition_logic: ConditionLogic,
+    /// Tokens staked by the proposer as guarantee (0 = none)
+    pub insurance_amount: i128,
+    /// Proposal IDs that must execute first
+    pub depends_on: Vec<u64>,
+    /// Scheduled execution ledger (None = execute once approved)
+    pub execution_time: Option<u64>,
+    /// Initial tags, indexed like tags added later
+    pub tags: Vec<Symbol>,
+    /// Initial metadata entries
+    pub metadata: Map<Symbol, String>,
+    /// Voting period in ledgers from creation (0 = vault default)
+    pub voting_period: u64,
+    /// Keepers allowed to execute (empty = any signer)
+    pub allowed_executors: Vec<Address>,
+    /// Tip paid to the executor (None = vault default from `GasConfig`)
+    pub executor_tip: Option<i128>,
+    /// Spend category to reserve against; required once category budgets exist
+    pub category: Option<Symbol>,
+    /// Signer stages that must approve in order: a signer in a later stage can
+    /// approve only once every earlier stage has an approval (empty = any order)
+    pub approval_order: Vec<Vec<Address>>,
+}
+
+impl ProposalOptions {
+    pub fn default(env: &Env) -> Self {
+        ProposalOptions {
+            priority: Priority::Normal,
+            conditions: Vec::new(env),
+            condition_logic: ConditionLogic::And,
+            insurance_amount: 0,
+            depends_on: Vec::new(env),
+            execution_time: None,
+            tags: Vec::new(env),
+            metadata: Map::new(env),
+            voting_period: 0,
+            allowed_executors: Vec::new(env),
+            executor_tip: None,
+            category: None,
+            approval_order: Vec::new(env),
+        }
+    }
+}
+
 /// On-chain comment on a proposal
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -432,6 +544,29 @@ pub struct Comment {
     pub parent_id: u64,
     pub created_at: u64,
     pub edited_at: u64,
+    /// Ledger when the comment was deleted (0 = live). Deleted comments keep
+    /// their ID for thread integrity but their text is tombstoned.
+    pub deleted_at: u64,
+    /// Signers (or the proposer) @-mentioned in the comment
+    pub mentions: Vec<Address>,
+}
+
+/// Per-author comment limits on each proposal (Admins are exempt)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommentQuota {
+    /// Comments one author may post on a proposal (0 = unlimited)
+    pub max_per_author: u32,
+    /// Ledgers an author must wait between comments on a proposal
+    pub min_gap_ledgers: u64,
+}
+
+/// An author's comment activity on one proposal
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CommentAuthorStats {
+    pub count: u32,
+    pub last_comment_ledger: u64,
 }
 
 /// Recurring payment schedule
@@ -454,6 +589,37 @@ pub struct RecurringPayment {
     pub is_active: bool,
 }
 
+/// Kind of scheduled payment a payment incident refers to.
+///
+/// Subscriptions have no on-chain records in this build, so only recurring
+/// payments can be flagged.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IncidentKind {
+    RecurringPayment,
+}
+
+/// Record of a scheduled payment stopped for paying the wrong recipient
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Incident {
+    pub id: u64,
+    pub kind: IncidentKind,
+    /// ID of the recurring payment
+    pub target_id: u64,
+    /// Recipient the payment was sending to
+    pub recipient: Address,
+    pub token: Address,
+    /// Total paid to the recipient up to the flagged ledger
+    pub amount_paid: i128,
+    pub flagged_by: Address,
+    pub flagged_at: u64,
+    pub note: Symbol,
+    /// Ledger the incident was resolved at (0 = still open)
+    pub resolved_at: u64,
+    pub resolution: Symbol,
+}
+
 // ============================================================================
 // Streaming Payments (Issue: feature/streaming-payments)
 // ============================================================================
@@ -491,6 +657,8 @@ pub struct StreamingPayment {
     pub total_amount: i128,
     /// Total amount already claimed by recipient
     pub claimed_amount: i128,
+    /// Tokens still held in escrow for this stream (debited on every claim/refund)
+    pub escrow_remaining: i128,
     /// Ledger timestamp when the stream was created
     pub start_timestamp: u64,
     /// Ledger timestamp when the stream will finish
@@ -512,6 +680,30 @@ pub struct VelocityConfig {
     pub window: u64,
 }
 
+/// Action kinds routed through the velocity limit
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VelocityAction {
+    Propose,
+    CancelProposal,
+    RejectProposal,
+    Pause,
+    EnforceDeadlines,
+}
+
+impl VelocityAction {
+    /// Emergency cleanup actions are never throttled, whatever the caller's role.
+    pub fn is_exempt(&self) -> bool {
+        matches!(
+            self,
+            VelocityAction::CancelProposal
+                | VelocityAction::RejectProposal
+                | VelocityAction::Pause
+                | VelocityAction::EnforceDeadlines
+        )
+    }
+}
+
 /// Audit action types
 // ============================================================================
 // Reputation System (Issue: feature/reputation-system)
@@ -575,6 +767,53 @@ pub struct InsuranceConfig {
     pub slash_percentage: u32,
 }
 
+/// USD-denominated insurance threshold, stored apart from `InsuranceConfig`
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct InsuranceUsdThreshold {
+    /// Minimum proposal value in USD that requires insurance (0 = use `min_amount`).
+    /// Only applies while an oracle is configured.
+    pub min_amount_usd: i128,
+    /// What to do when the USD value cannot be read from the oracle
+    pub oracle_fallback: InsuranceOracleFallback,
+}
+
+/// How the insurance requirement is decided when the oracle price is unavailable
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InsuranceOracleFallback {
+    /// Fall back to the token-unit `min_amount` threshold
+    TokenThreshold,
+    /// Require insurance regardless of amount
+    RequireInsurance,
+    /// Refuse the proposal with the oracle error
+    Reject,
+}
+
+/// Destination of slashed insurance, kept apart from `InsuranceConfig`
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SlashDestination {
+    /// Kept in the vault's insurance pool (withdrawable by Admin)
+    Pool,
+    /// Burned via the token contract (falls back to the pool if burn is unsupported)
+    Burn,
+    /// Split equally between the admins who rejected the proposal
+    RejectingAdmins,
+    /// Split equally between the proposal's approvers
+    Approvers,
+}
+
+/// Pool of slashed funds a withdrawal proposal draws from
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PoolKind {
+    /// Slashed proposal insurance
+    Insurance = 0,
+    /// Slashed proposal stakes
+    Stake = 1,
+}
+
 // ============================================================================
 // Notification Preferences (Issue: feature/execution-notifications)
 // ============================================================================
@@ -588,6 +827,10 @@ pub struct NotificationPreferences {
     pub notify_on_execution: bool,
     pub notify_on_rejection: bool,
     pub notify_on_expiry: bool,
+    pub notify_on_mention: bool,
+    /// Address whose event topic receives these notifications instead of the
+    /// user's own (e.g. a monitoring contract)
+    pub route_to: Option<Address>,
 }
 
 impl Default for NotificationPreferences {
@@ -598,10 +841,22 @@ impl Default for NotificationPreferences {
             notify_on_execution: true,
             notify_on_rejection: true,
             notify_on_expiry: false,
+            notify_on_mention: true,
+            route_to: None,
         }
     }
 }
 
+/// Unread notification counter a relayer can poll instead of every event
+#[contracttype]
+#[derive(Clone, Debug, Default)]
+pub struct NotificationDigest {
+    /// Notifications since the address last marked them read
+    pub pending_count: u32,
+    /// Ledger of the most recent notification (0 if never notified)
+    pub last_notified_ledger: u64,
+}
+
 // ============================================================================
 // Gas Limits (Issue: feature/gas-limits)
 // ============================================================================
@@ -616,8 +871,18 @@ pub struct GasConfig {
     pub default_gas_limit: u64,
     /// Base cost charged per execution
     pub base_cost: u64,
-    /// Extra cost per execution condition
+    /// Cost per plain step: the core transfer and each non-oracle condition
     pub condition_cost: u64,
+    /// Cost per price condition (oracle cross-contract call)
+    pub oracle_condition_cost: u64,
+    /// Cost per registered pre/post execution hook call
+    pub hook_cost: u64,
+    /// Extra cost for swap proposals
+    pub swap_cost: u64,
+    /// Extra cost for handling a locked insurance deposit
+    pub insurance_cost: u64,
+    /// Executor tip applied to new proposals, in the proposal token (0 = none)
+    pub default_executor_tip: i128,
 }
 
 #[contracttype]
@@ -667,10 +932,130 @@ impl Default for GasConfig {
             default_gas_limit: 0,
             base_cost: 1_000,
             condition_cost: 500,
+            oracle_condition_cost: 2_000,
+            hook_cost: 1_500,
+            swap_cost: 2_000,
+            insurance_cost: 500,
+            default_executor_tip: 0,
         }
     }
 }
 
+/// Read-only feasibility verdict for a prospective transfer proposal.
+///
+/// Mirrors the checks in `propose_transfer` so frontends can warn users before
+/// they sign. Limits already include reputation boosts.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct PrecheckResult {
+    /// Proposer holds Treasurer or Admin role
+    pub has_role: bool,
+    /// Recipient passes the current whitelist/blacklist mode
+    pub recipient_allowed: bool,
+    /// Per-proposal spending limit after reputation boost
+    pub spending_limit: i128,
+    /// Amount is within the per-proposal spending limit
+    pub within_spending_limit: bool,
+    /// Remaining daily headroom before this proposal
+    pub daily_remaining: i128,
+    /// Remaining weekly headroom before this proposal
+    pub weekly_remaining: i128,
+    /// Proposals the proposer can still create in the current velocity window
+    pub velocity_remaining: u32,
+    /// Minimum insurance the proposer must lock
+    pub required_insurance: i128,
+    /// Stake the proposer will have locked
+    pub required_stake: i128,
+    /// Amount meets the timelock threshold
+    pub requires_timelock: bool,
+    /// All checks pass with the minimum required insurance
+    pub feasible: bool,
+}
+
+/// What kind of account a signer is.
+///
+/// Contract signers (for example another multisig) authorize through
+/// `require_auth` like any account, but are left out of reputation and
+/// velocity accounting.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SignerKind {
+    Eoa,
+    Contract,
+}
+
+/// One snapshot signer's vote on a proposal, as returned by `get_vote_summary`
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SignerVote {
+    pub signer: Address,
+    pub kind: SignerKind,
+    pub approved: bool,
+    pub abstained: bool,
+    /// Approval stage the signer belongs to, counted from 1 (0 = unstaged)
+    pub stage: u32,
+    /// Whether every earlier stage has approved, so the signer may approve
+    pub stage_open: bool,
+}
+
+/// Heuristic risk signal for a proposal, returned by `get_risk_assessment`.
+///
+/// `score` is the sum of the weights of the raised flags, from 0 (no
+/// concerns) to 100 (every flag raised).
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RiskAssessment {
+    pub score: u32,
+    /// Amount is close to the per-proposal spending limit
+    pub near_spending_limit: bool,
+    /// Amount is a large share of the vault's balance of the token
+    pub large_share_of_balance: bool,
+    /// The vault has never paid this recipient
+    pub new_recipient: bool,
+    /// Proposer's reputation is below the neutral starting score
+    pub low_reputation: bool,
+    /// No attachments and no metadata
+    pub undocumented: bool,
+    /// Expires soon, leaving little time for review
+    pub expiring_soon: bool,
+    /// Recipient was added to the whitelist recently
+    pub recently_whitelisted: bool,
+}
+
+/// Pending invitation to join the signer set, created by `invite_signer`
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SignerInvite {
+    pub invitee: Address,
+    pub invited_by: Address,
+    pub created_at: u64,
+    /// Last ledger on which the invite can be approved or accepted
+    pub expires_at: u64,
+    /// Signers who approved the invite
+    pub approvals: Vec<Address>,
+}
+
+/// Effect of a prospective signer set change on approval requirements.
+///
+/// Returned by `preview_signer_change` so admins can spot a configuration that
+/// no proposal could satisfy before applying it.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SignerChangePreview {
+    /// Number of signers after the change
+    pub signer_count: u32,
+    /// Approvals each tier of the active strategy would require after the change.
+    /// Fixed and Percentage have one entry; AmountBased lists the base threshold
+    /// followed by each tier; TimeBased lists the initial and reduced thresholds.
+    pub tier_thresholds: Vec<u32>,
+    /// Indexes into `tier_thresholds` that exceed `signer_count`
+    pub unreachable_tiers: Vec<u32>,
+    /// Configured quorum exceeds `signer_count`
+    pub quorum_unreachable: bool,
+    /// Every tier and the quorum remain reachable
+    pub reachable: bool,
+}
+
 /// Estimated execution fee breakdown for a proposal.
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -683,6 +1068,18 @@ pub struct ExecutionFeeEstimate {
     pub total_fee: u64,
     /// Number of logical operations used to derive `resource_fee`.
     pub operation_count: u32,
+    /// Part of `resource_fee` for the core transfer and non-oracle conditions.
+    pub condition_fee: u64,
+    /// Part of `resource_fee` for price (oracle) conditions.
+    pub oracle_fee: u64,
+    /// Part of `resource_fee` for execution hook calls.
+    pub hook_fee: u64,
+    /// Part of `resource_fee` for the swap step.
+    pub swap_fee: u64,
+    /// Part of `resource_fee` for insurance handling.
+    pub insurance_fee: u64,
+    /// Protocol fee from the `FeeStructure`, charged in the proposal token on execution.
+    pub protocol_fee: i128,
 }
 
 // ============================================================================
@@ -707,12 +1104,31 @@ pub struct VaultMetrics {
     pub total_gas_used: u64,
     /// Ledger when metrics were last updated
     pub last_updated_ledger: u64,
+    /// Number of proposals cancelled by their proposer
+    pub cancelled_count: u64,
+    /// Number of execution retries scheduled after a retryable failure
+    pub retry_scheduled_count: u64,
+    /// Number of proposals that executed after at least one retry
+    pub retry_succeeded_count: u64,
+    /// Number of escrow disputes filed
+    pub disputes_filed: u64,
+    /// Number of disputes resolved by refunding the funder
+    pub disputes_upheld: u64,
+    /// Cumulative ledgers from proposal creation to approval
+    pub total_approval_time_ledgers: u64,
+    /// Number of proposals included in `total_approval_time_ledgers`
+    pub approval_time_samples: u64,
+    /// Cumulative ledgers from approval to execution
+    pub total_execution_delay_ledgers: u64,
+    /// Number of proposals included in `total_execution_delay_ledgers`
+    pub execution_delay_samples: u64,
 }
 
 impl VaultMetrics {
-    /// Success rate in basis points (0-10000)
+    /// Success rate in basis points (0-10000), counting cancellations as unsuccessful outcomes
     pub fn success_rate_bps(&self) -> u32 {
-        let total = self.executed_count + self.rejected_count + self.expired_count;
+        let total =
+            self.executed_count + self.rejected_count + self.expired_count + self.cancelled_count;
         if total == 0 {
             return 0;
         }
@@ -726,6 +1142,127 @@ impl VaultMetrics {
         }
         self.total_execution_time_ledgers / self.executed_count
     }
+
+    /// Average ledgers from creation to approval (0 if none approved)
+    pub fn avg_approval_time(&self) -> u64 {
+        if self.approval_time_samples == 0 {
+            return 0;
+        }
+        self.total_approval_time_ledgers / self.approval_time_samples
+    }
+
+    /// Average ledgers from approval to execution (0 if none executed)
+    pub fn avg_execution_delay(&self) -> u64 {
+        if self.execution_delay_samples == 0 {
+            return 0;
+        }
+        self.total_execution_delay_ledgers / self.execution_delay_samples
+    }
+
+    /// Share of scheduled retries whose next attempt executed the proposal, in basis points
+    pub fn retry_success_rate_bps(&self) -> u32 {
+        if self.retry_scheduled_count == 0 {
+            return 0;
+        }
+        (self.retry_succeeded_count * 10_000 / self.retry_scheduled_count) as u32
+    }
+
+    /// Share of filed disputes that were upheld, in basis points
+    pub fn dispute_uphold_rate_bps(&self) -> u32 {
+        if self.disputes_filed == 0 {
+            return 0;
+        }
+        (self.disputes_upheld * 10_000 / self.disputes_filed) as u32
+    }
+
+    /// Share of created proposals that were cancelled, in basis points
+    pub fn cancellation_rate_bps(&self) -> u32 {
+        if self.total_proposals == 0 {
+            return 0;
+        }
+        (self.cancelled_count * 10_000 / self.total_proposals) as u32
+    }
+}
+
+/// Ledgers at which a proposal reached each lifecycle stage (0 = not reached).
+///
+/// Kept beside `Proposal` rather than in it: the proposal struct is at the
+/// contract type field limit.
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProposalTimestamps {
+    pub created_at: u64,
+    pub approved_at: u64,
+    pub executed_at: u64,
+    pub rejected_at: u64,
+    pub cancelled_at: u64,
+}
+
+/// One status change in a proposal's history
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StatusTransition {
+    pub from: ProposalStatus,
+    pub to: ProposalStatus,
+    /// Address that triggered the change (the vault itself for keepers)
+    pub actor: Address,
+    pub ledger: u64,
+    /// Entry point or cause, e.g. `approve`, `amend`, `deadline`
+    pub reason: Symbol,
+}
+
+/// Approval evidence for an executed proposal, frozen at execution.
+///
+/// `hash` is the SHA-256 of the XDR encoding of the tuple
+/// `(proposal_id, snapshot_signers, approvals, approval_ledgers, threshold,
+/// quorum, executed_at)`, so auditors can rebuild it from the other fields.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExecutionProof {
+    pub proposal_id: u64,
+    /// Signers eligible to vote, snapshotted at creation
+    pub snapshot_signers: Vec<Address>,
+    /// Approvers, in approval order
+    pub approvals: Vec<Address>,
+    /// Ledger of each approval, parallel to `approvals` (0 = not recorded)
+    pub approval_ledgers: Vec<u64>,
+    /// Approval threshold in effect at execution
+    pub threshold: u32,
+    /// Quorum in effect at execution
+    pub quorum: u32,
+    /// Ledger at which the proposal executed
+    pub executed_at: u64,
+    pub hash: BytesN<32>,
+}
+
+/// Number of items in one health category plus a sample of their IDs
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct HealthBucket {
+    /// Items found in this category (within the scan bounds)
+    pub count: u32,
+    /// Up to 10 IDs of items in this category
+    pub sample_ids: Vec<u64>,
+}
+
+/// Snapshot of everything in the vault currently waiting on an operator or keeper
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct VaultHealth {
+    /// Pending proposals that have used up more than half of their lifetime
+    pub stale_pending: HealthBucket,
+    /// Approved proposals whose timelock has passed but that are not executed yet
+    pub executable_approved: HealthBucket,
+    /// Recurring payments whose next payment ledger has been reached
+    pub due_recurring: HealthBucket,
+    /// Active streams past their end time with funds still in escrow
+    pub vested_streams: HealthBucket,
+    /// Proposals whose retry backoff has elapsed
+    pub due_retries: HealthBucket,
+    /// Unfinalized escrows past expiry with funds still locked
+    pub expired_escrows: HealthBucket,
+    /// Ledger the report was built at
+    pub checked_at: u64,
 }
 
 // ============================================================================
@@ -794,6 +1331,7 @@ pub enum AuditAction {
     UpdateLimits = 8,
     UpdateThreshold = 9,
     AbstainProposal = 10,
+    MigrateReputation = 11,
 }
 
 /// Audit trail entry with cryptographic verification
@@ -854,6 +1392,24 @@ pub struct ProposalTemplate {
     pub min_amount: i128,
     /// Maximum allowed amount (0 = no maximum)
     pub max_amount: i128,
+    /// Tags added to every proposal created from the template
+    pub default_tags: Vec<Symbol>,
+}
+
+/// Admin rule that tags matching proposals at creation.
+///
+/// A proposal matches when its amount is within `[min_amount, max_amount]`
+/// and, if `recipient` is set, it pays that recipient.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AutoTagRule {
+    pub tag: Symbol,
+    /// Smallest matching amount (0 = no minimum)
+    pub min_amount: i128,
+    /// Largest matching amount (0 = no maximum)
+    pub max_amount: i128,
+    /// Only proposals paying this recipient match (None = any recipient)
+    pub recipient: Option<Address>,
 }
 
 /// Overrides for creating a proposal from a template
@@ -876,6 +1432,19 @@ pub struct TemplateOverrides {
     pub override_priority: bool,
     /// Override priority level (only used if override_priority is true)
     pub priority: Priority,
+    /// Insurance to lock with the proposal (0 = none; required above the insurance threshold)
+    pub insurance_amount: i128,
+}
+
+/// One proposal to create in `batch_create_from_template`
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct TemplateBatchEntry {
+    pub recipient: Address,
+    /// Must be within the template's min/max bounds
+    pub amount: i128,
+    /// Memo to use instead of the template's (None = template memo)
+    pub memo_override: Option<Symbol>,
 }
 
 // ============================================================================
@@ -894,6 +1463,61 @@ pub struct RetryConfig {
     pub initial_backoff_ledgers: u64,
 }
 
+/// Anomaly breaker: freeze execution after repeated failures
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AutoFreezeConfig {
+    /// Failures tolerated within the window; one more freezes the vault (0 = disabled)
+    pub threshold: u32,
+    /// Length of the rolling failure window in ledgers
+    pub window_ledgers: u64,
+}
+
+/// Lets unanimous (or near-unanimous) Critical approvals skip the timelock
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TimelockBypassConfig {
+    /// Whether Critical proposals may clear their timelock early
+    pub enabled: bool,
+    /// Approvals needed to bypass (0 = every snapshot signer)
+    pub bypass_threshold: u32,
+}
+
+/// Labeled recipient in the vault's address book
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddressBookEntry {
+    /// Unique label proposers refer to
+    pub label: Symbol,
+    pub address: Address,
+    /// Ledger at which the entry was added
+    pub added_at: u64,
+}
+
+/// Related proposals approvable with a single vote
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ProposalBundle {
+    /// Unique bundle ID
+    pub id: u64,
+    /// Proposer who owns every member
+    pub proposer: Address,
+    /// Members still open; executed or rejected members are detached
+    pub proposal_ids: Vec<u64>,
+    /// Ledger at which the bundle was created
+    pub created_at: u64,
+}
+
+/// How `approve_bundle` handles a member the signer cannot approve
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BundleApprovalMode {
+    /// Approve what can be approved and report the skipped members
+    SkipAndReport,
+    /// Fail the whole call if any member cannot be approved
+    AllOrNothing,
+}
+
 /// Tracks retry state for a specific proposal execution
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -932,6 +1556,18 @@ pub enum SubscriptionStatus {
     Suspended = 3,
 }
 
+/// Who pays a subscription's renewals
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum SubscriptionFundingSource {
+    /// Paid from the vault's balance; creation requires Treasurer or Admin
+    Vault = 0,
+    /// Pulled from the subscriber's allowance into the vault and straight
+    /// out to the provider on each renewal; an underfunded renewal fails
+    Subscriber = 1,
+}
+
 /// Subscription record
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -949,6 +1585,7 @@ pub struct Subscription {
     pub total_payments: u32,
     pub last_payment_ledger: u64,
     pub auto_renew: bool,
+    pub funding_source: SubscriptionFundingSource,
 }
 
 /// Payment record for subscription tracking
@@ -1161,6 +1798,16 @@ pub enum EscrowStatus {
     Disputed = 5,
 }
 
+/// How an escrow's milestones become releasable
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MilestoneMode {
+    /// Each milestone needs a `complete_milestone` call
+    Manual,
+    /// A milestone is complete once its `release_ledger` has passed
+    TimeBased,
+}
+
 /// Milestone tracking unit for progressive fund release
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -1207,6 +1854,8 @@ pub struct Escrow {
     pub expires_at: u64,
     /// Ledger when escrow was released/refunded (0 if still active)
     pub finalized_at: u64,
+    /// Whether milestones need explicit completion or unlock on schedule
+    pub milestone_mode: MilestoneMode,
 }
 
 // ============================================================================
@@ -1247,7 +1896,7 @@ impl TokenLock {
         if !self.is_active {
             return 0;
         }
-        (self.amount * self.power_multiplier_bps as i128) / 10_000
+        crate::math::bps_of(self.amount, self.power_multiplier_bps)
     }
 
     /// Calculate power multiplier based on lock duration
@@ -1334,7 +1983,7 @@ impl Escrow {
                 }
             }
         }
-        (self.total_amount * completed_percentage as i128) / 100 - self.released_amount
+        crate::math::pct_of(self.total_amount, completed_percentage) - self.released_amount
     }
 }
 // ============================================================================
@@ -1614,3 +2263,108 @@ pub struct BatchExecutionResult {
     /// Number of failed operations
     pub failed_ops: u32,
 }
+
+// ============================================================================
+// Invariant Monitoring
+// ============================================================================
+
+/// Category of accounting invariant that failed
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum InvariantKind {
+    /// Pools plus locked deposits and approved payouts exceed the vault balance
+    BalanceShortfall = 0,
+    /// A proposal has more approvals than eligible snapshot signers
+    ApprovalsExceedSnapshot = 1,
+    /// Today's reserved spending exceeds the maximum (boosted) daily limit
+    DailyLimitExceeded = 2,
+    /// A retry state exists for a proposal that is not Approved
+    OrphanRetryState = 3,
+    /// Pools plus tracked outstanding insurance and stakes exceed the vault balance
+    PoolShortfall = 4,
+}
+
+/// Reconciliation of slashed-fund pools and locked deposits for one token
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PoolReport {
+    /// Slashed insurance available for withdrawal
+    pub insurance_pool: i128,
+    /// Slashed stakes available for withdrawal
+    pub stake_pool: i128,
+    /// Insurance locked for open proposals
+    pub locked_insurance_outstanding: i128,
+    /// Stakes locked for open proposals
+    pub locked_stakes_outstanding: i128,
+    /// Vault balance of the token
+    pub vault_balance: i128,
+}
+
+/// Spending reserved on one day and the proposals that reserved it
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DailyLedger {
+    /// Day number (ledger timestamp / 86400)
+    pub day: u64,
+    /// Amount reserved against the daily limit
+    pub spent: i128,
+    /// Current daily spending limit
+    pub limit: i128,
+    /// Proposals that reserved spending on this day (first 50)
+    pub proposal_ids: Vec<u64>,
+    /// Proposals beyond the recorded list
+    pub overflow_count: u32,
+}
+
+/// Kind of ID-addressed record, see `is_record_archived`
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum RecordKind {
+    Proposal,
+    Comment,
+    Stream,
+    Escrow,
+}
+
+/// Token movements recorded for one day
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DailyFlow {
+    /// Amount pulled into the vault
+    pub inflow: i128,
+    /// Amount sent out of the vault
+    pub outflow: i128,
+    /// Proposals executed in this token
+    pub executed_proposals: u32,
+}
+
+/// Token movements summed over the last few days, see `get_flow_summary`
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FlowSummary {
+    /// Amount pulled into the vault
+    pub inflow: i128,
+    /// Amount sent out of the vault
+    pub outflow: i128,
+    /// `inflow - outflow`
+    pub net: i128,
+    /// Proposals executed in this token
+    pub executed_proposals: u32,
+}
+
+/// A single invariant violation reported by `check_invariants`
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct InvariantViolation {
+    /// Which invariant failed
+    pub kind: InvariantKind,
+    /// Offending proposal ID (0 when not proposal-specific)
+    pub proposal_id: u64,
+    /// Token involved, for balance checks
+    pub token: Option<Address>,
+    /// Bound the value should respect
+    pub expected: i128,
+    /// Value actually observed
+    pub actual: i128,
+}